@@ -0,0 +1,140 @@
+/// Bit masks used by the subset and combination generators.
+///
+/// Signed integers are treated as their two's complement bit patterns.
+pub trait BitMask: Clone + Eq {
+    /// Get a mask of the same width with no bit set.
+    fn zeroed(&self) -> Self;
+
+    /// Returns true if no bit is set.
+    fn is_zero(&self) -> bool;
+
+    /// Get the number of set bits.
+    fn count_ones(&self) -> usize;
+
+    /// Get the position of the lowest set bit, or the width if no bit is set.
+    fn trailing_zeros(&self) -> usize;
+
+    /// Returns true if the bit at `pos` is set.
+    fn bit(&self, pos: usize) -> bool;
+
+    /// Set the bit at `pos` to `value`.
+    fn set_bit(&mut self, pos: usize, value: bool);
+
+    /// Bitwise and.
+    fn and(&self, other: &Self) -> Self;
+
+    /// Bitwise or.
+    fn or(&self, other: &Self) -> Self;
+
+    /// Bitwise xor.
+    fn xor(&self, other: &Self) -> Self;
+
+    /// Logical right shift by `n` bits.
+    fn shr(&self, n: usize) -> Self;
+
+    /// Add `other` to the mask as an unsigned number, wrapping on overflow.
+    fn wrapping_add(&self, other: &Self) -> Self;
+
+    /// Subtract one from the mask as an unsigned number, wrapping on overflow.
+    fn wrapping_dec(&self) -> Self;
+}
+
+macro_rules! impl_bit_mask {
+    ($($t:ty, $u:ty);*) => ($(
+        impl BitMask for $t {
+            #[inline]
+            fn zeroed(&self) -> Self {
+                0
+            }
+
+            #[inline]
+            fn is_zero(&self) -> bool {
+                *self == 0
+            }
+
+            #[inline]
+            fn count_ones(&self) -> usize {
+                <$t>::count_ones(*self) as usize
+            }
+
+            #[inline]
+            fn trailing_zeros(&self) -> usize {
+                <$t>::trailing_zeros(*self) as usize
+            }
+
+            #[inline]
+            fn bit(&self, pos: usize) -> bool {
+                pos < <$t>::BITS as usize && (*self >> pos) & 1 == 1
+            }
+
+            #[inline]
+            fn set_bit(&mut self, pos: usize, value: bool) {
+                if value {
+                    *self |= 1 << pos;
+                } else {
+                    *self &= !(1 << pos);
+                }
+            }
+
+            #[inline]
+            fn and(&self, other: &Self) -> Self {
+                *self & *other
+            }
+
+            #[inline]
+            fn or(&self, other: &Self) -> Self {
+                *self | *other
+            }
+
+            #[inline]
+            fn xor(&self, other: &Self) -> Self {
+                *self ^ *other
+            }
+
+            #[inline]
+            fn shr(&self, n: usize) -> Self {
+                (*self as $u).checked_shr(n as u32).unwrap_or(0) as $t
+            }
+
+            #[inline]
+            fn wrapping_add(&self, other: &Self) -> Self {
+                <$t>::wrapping_add(*self, *other)
+            }
+
+            #[inline]
+            fn wrapping_dec(&self) -> Self {
+                <$t>::wrapping_sub(*self, 1)
+            }
+        }
+    )*)
+}
+
+impl_bit_mask! {
+    u8, u8; u16, u16; u32, u32; u64, u64; u128, u128; usize, usize;
+    i8, u8; i16, u16; i32, u32; i64, u64; i128, u128; isize, usize
+}
+
+#[cfg(test)]
+mod tests {
+    use super::BitMask;
+
+    #[test]
+    fn primitive() {
+        let mut x = 0b1011_0100u8;
+        assert_eq!(4, BitMask::count_ones(&x));
+        assert_eq!(2, BitMask::trailing_zeros(&x));
+        assert_eq!(8, BitMask::trailing_zeros(&x.zeroed()));
+        assert!(x.bit(7) && !x.bit(8) && !x.bit(100));
+        assert_eq!(0b0101_1010, BitMask::shr(&x, 1));
+        assert_eq!(0, BitMask::shr(&x, 8));
+        assert_eq!(0b1011_0011, x.wrapping_dec());
+        assert_eq!(255, 0u8.wrapping_dec());
+        x.set_bit(0, true);
+        x.set_bit(7, false);
+        assert_eq!(0b0011_0101, x);
+
+        let y = -128i8;
+        assert_eq!(0b0100_0000, BitMask::shr(&y, 1));
+        assert_eq!(127, y.wrapping_dec());
+    }
+}
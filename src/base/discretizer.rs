@@ -0,0 +1,276 @@
+use super::search::dichotomy;
+use std::cmp::Ordering;
+
+/// Discretizer which keeps the sorted unique values and maps between values and ranks.
+pub struct Discretizer<T, F = fn(&T, &T) -> Ordering> {
+    table: Vec<T>,
+    compare: F,
+}
+
+impl<T: Ord + Clone> Discretizer<T> {
+    /// Creates a Discretizer from `values` with their natural order.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rsalgo::base::Discretizer;
+    ///
+    /// let d = Discretizer::new(&[10, 30, 20, 10]);
+    /// assert_eq!(3, d.len());
+    /// ```
+    pub fn new(values: &[T]) -> Self {
+        Self::with_comparator(values, T::cmp)
+    }
+}
+
+impl<T, F> Discretizer<T, F>
+where
+    T: Clone,
+    F: Fn(&T, &T) -> Ordering,
+{
+    /// Creates a Discretizer from `values` ordered by `compare`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rsalgo::base::Discretizer;
+    ///
+    /// let d = Discretizer::with_comparator(&[0.5, -1.0, 0.5], |a, b| a.partial_cmp(b).unwrap());
+    /// assert_eq!(Some(1), d.rank(&0.5));
+    /// ```
+    pub fn with_comparator(values: &[T], compare: F) -> Self {
+        let mut table = values.to_vec();
+        table.sort_by(|a, b| compare(a, b));
+        table.dedup_by(|a, b| compare(a, b) == Ordering::Equal);
+        Discretizer { table, compare }
+    }
+}
+
+impl<T, F> Discretizer<T, F>
+where
+    F: Fn(&T, &T) -> Ordering,
+{
+    /// Returns the number of distinct values.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rsalgo::base::Discretizer;
+    ///
+    /// let d = Discretizer::new(&[1, 1, 2]);
+    /// assert_eq!(2, d.len());
+    /// ```
+    pub fn len(&self) -> usize {
+        self.table.len()
+    }
+
+    /// Returns true if there is no value.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rsalgo::base::Discretizer;
+    ///
+    /// let d: Discretizer<u32> = Discretizer::new(&[]);
+    /// assert!(d.is_empty());
+    /// ```
+    pub fn is_empty(&self) -> bool {
+        self.table.is_empty()
+    }
+
+    /// Returns the sorted distinct values.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rsalgo::base::Discretizer;
+    ///
+    /// let d = Discretizer::new(&[3, 1, 3, 2]);
+    /// assert_eq!([1, 2, 3], d.values());
+    /// ```
+    pub fn values(&self) -> &[T] {
+        &self.table
+    }
+
+    /// Get the rank of `value`, or None if it is not in the table.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rsalgo::base::Discretizer;
+    ///
+    /// let d = Discretizer::new(&[10, 30, 20]);
+    /// assert_eq!(Some(2), d.rank(&30));
+    /// assert_eq!(None, d.rank(&25));
+    /// ```
+    pub fn rank(&self, value: &T) -> Option<usize> {
+        let pos = self.lower_rank(value);
+        match self.table.get(pos) {
+            Some(item) if (self.compare)(item, value) == Ordering::Equal => Some(pos),
+            _ => None,
+        }
+    }
+
+    /// Get the value of `rank`, or None if `rank` is out of range.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rsalgo::base::Discretizer;
+    ///
+    /// let d = Discretizer::new(&[10, 30, 20]);
+    /// assert_eq!(Some(&20), d.value(1));
+    /// assert_eq!(None, d.value(3));
+    /// ```
+    pub fn value(&self, rank: usize) -> Option<&T> {
+        self.table.get(rank)
+    }
+
+    /// Get the number of values less than `value`, which is the rank of the first value not less than `value`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rsalgo::base::Discretizer;
+    ///
+    /// let d = Discretizer::new(&[10, 30, 20]);
+    /// assert_eq!(2, d.lower_rank(&25));
+    /// assert_eq!(1, d.lower_rank(&20));
+    /// ```
+    pub fn lower_rank(&self, value: &T) -> usize {
+        dichotomy(0, self.len(), |pos| {
+            (self.compare)(&self.table[pos], value) != Ordering::Less
+        })
+        .unwrap_or_else(|| self.len())
+    }
+
+    /// Get the number of values not greater than `value`, which is the rank of the first value greater than `value`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rsalgo::base::Discretizer;
+    ///
+    /// let d = Discretizer::new(&[10, 30, 20]);
+    /// assert_eq!(2, d.upper_rank(&25));
+    /// assert_eq!(2, d.upper_rank(&20));
+    /// ```
+    pub fn upper_rank(&self, value: &T) -> usize {
+        dichotomy(0, self.len(), |pos| {
+            (self.compare)(&self.table[pos], value) == Ordering::Greater
+        })
+        .unwrap_or_else(|| self.len())
+    }
+
+    /// Get the rank of the greatest value not greater than `value`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rsalgo::base::Discretizer;
+    ///
+    /// let d = Discretizer::new(&[10, 30, 20]);
+    /// assert_eq!(Some(1), d.floor_rank(&25));
+    /// assert_eq!(None, d.floor_rank(&5));
+    /// ```
+    pub fn floor_rank(&self, value: &T) -> Option<usize> {
+        self.upper_rank(value).checked_sub(1)
+    }
+
+    /// Get the rank of the least value not less than `value`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rsalgo::base::Discretizer;
+    ///
+    /// let d = Discretizer::new(&[10, 30, 20]);
+    /// assert_eq!(Some(2), d.ceil_rank(&25));
+    /// assert_eq!(None, d.ceil_rank(&35));
+    /// ```
+    pub fn ceil_rank(&self, value: &T) -> Option<usize> {
+        let rank = self.lower_rank(value);
+        if rank < self.len() {
+            Some(rank)
+        } else {
+            None
+        }
+    }
+
+    /// Build a vector with the rank of each item in `values`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rsalgo::base::Discretizer;
+    ///
+    /// let d = Discretizer::new(&[10, 30, 20]);
+    /// assert_eq!(vec![2, 0, 0, 1], d.compress(&[30, 10, 10, 20]));
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panic when a value is not in the table.
+    pub fn compress(&self, values: &[T]) -> Vec<usize> {
+        values
+            .iter()
+            .map(|value| {
+                self.rank(value)
+                    .expect("The value is not in the discretizer.")
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Discretizer;
+    use rand::Rng;
+
+    #[test]
+    fn rank_value() {
+        const LEN: usize = 128;
+        let mut rng = rand::thread_rng();
+        let data: Vec<i32> = (0..LEN).map(|_| rng.gen_range(-50, 50)).collect();
+        let d = Discretizer::new(&data);
+
+        let ranks = d.compress(&data);
+        for (value, &rank) in data.iter().zip(&ranks) {
+            assert_eq!(Some(value), d.value(rank));
+        }
+
+        for value in -60..60 {
+            let less = d.values().iter().filter(|&&x| x < value).count();
+            let not_greater = d.values().iter().filter(|&&x| x <= value).count();
+            assert_eq!(less, d.lower_rank(&value));
+            assert_eq!(not_greater, d.upper_rank(&value));
+            assert_eq!(data.contains(&value), d.rank(&value).is_some());
+
+            match d.floor_rank(&value) {
+                Some(rank) => assert_eq!(
+                    d.values().iter().filter(|&&x| x <= value).max(),
+                    d.value(rank)
+                ),
+                None => assert!(data.iter().all(|&x| x > value)),
+            }
+            match d.ceil_rank(&value) {
+                Some(rank) => assert_eq!(
+                    d.values().iter().filter(|&&x| x >= value).min(),
+                    d.value(rank)
+                ),
+                None => assert!(data.iter().all(|&x| x < value)),
+            }
+        }
+    }
+
+    #[test]
+    fn comparator() {
+        let data = [2.5, -1.0, 0.0, 2.5, 1.5];
+        let d = Discretizer::with_comparator(&data, |a: &f64, b: &f64| b.partial_cmp(a).unwrap());
+
+        assert_eq!([2.5, 1.5, 0.0, -1.0], d.values());
+        assert_eq!(vec![0, 3, 2, 0, 1], d.compress(&data));
+        assert_eq!(Some(1), d.ceil_rank(&2.0));
+        assert_eq!(Some(0), d.floor_rank(&2.0));
+    }
+}
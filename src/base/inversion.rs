@@ -1,4 +1,6 @@
-use super::search;
+use super::{sort, Discretizer};
+use crate::ds::FenwickTree;
+use std::cmp::Ordering;
 
 /// Get the number of inversion pairs.
 ///
@@ -9,56 +11,42 @@ use super::search;
 /// assert_eq!(9, rsalgo::base::inversion_pairs_count(&slice));
 /// ```
 pub fn inversion_pairs_count<T: PartialEq + Ord + Clone>(slice: &[T]) -> usize {
-    let mut ans = 0;
-    let mut temp = vec![0; slice.len()];
-    merge_sort(
-        search::discretization(slice).as_mut_slice(),
-        0,
-        slice.len(),
-        &mut temp,
-        &mut ans,
-    );
-    ans
+    sort::merge_sort(&mut slice.to_vec())
 }
 
-fn merge_sort(a: &mut [usize], l: usize, r: usize, temp: &mut [usize], pair: &mut usize) {
-    if l + 1 >= r {
-        return;
-    }
-    let mid = (l + r) / 2;
-    merge_sort(a, l, mid, temp, pair);
-    merge_sort(a, mid, r, temp, pair);
-    let (mut ll, mut rr, mut cur) = (l, mid, 0);
-
-    while ll < mid && rr < r {
-        if a[ll] <= a[rr] {
-            temp[cur] = a[ll];
-            ll += 1;
-        } else {
-            *pair += mid - ll;
-            temp[cur] = a[rr];
-            rr += 1;
-        }
-        cur += 1;
-    }
-
-    while ll < mid {
-        temp[cur] = a[ll];
-        cur += 1;
-        ll += 1;
-    }
-
-    while rr < r {
-        temp[cur] = a[rr];
-        cur += 1;
-        rr += 1;
+/// Get the number of inversion pairs, ranking values by a prebuilt discretizer.
+///
+/// # Examples
+///
+/// ```
+/// use rsalgo::base::{inversion_pairs_count_with, Discretizer};
+///
+/// let slice = [1, 3, 5, 3, 2, 4, 7, 9, 6, 6];
+/// let d = Discretizer::new(&slice);
+/// assert_eq!(9, inversion_pairs_count_with(&d, &slice));
+/// ```
+///
+/// # Panics
+///
+/// Panic when a value is not in the discretizer.
+pub fn inversion_pairs_count_with<T, F>(discretizer: &Discretizer<T, F>, slice: &[T]) -> usize
+where
+    F: Fn(&T, &T) -> Ordering,
+{
+    let mut ft: FenwickTree<usize> = FenwickTree::new(discretizer.len());
+    let mut ans = 0;
+    for (i, rank) in discretizer.compress(slice).into_iter().enumerate() {
+        ans += i - ft.prefix_sum(rank + 1);
+        ft.add(rank, 1);
     }
-
-    a[l..r].clone_from_slice(&temp[0..(r - l)]);
+    ans
 }
 
 #[cfg(test)]
 mod tests {
+    use crate::base::Discretizer;
+    use rand::Rng;
+
     #[test]
     fn inversion_pairs_count() {
         assert_eq!(
@@ -66,4 +54,28 @@ mod tests {
             super::inversion_pairs_count(&[1, 3, 5, 3, 2, 4, 7, 9, 6, 6])
         );
     }
+
+    #[test]
+    fn inversion_pairs_count_with() {
+        let mut rng = rand::thread_rng();
+        let data: Vec<i32> = (0..200).map(|_| rng.gen_range(-20, 20)).collect();
+        let d = Discretizer::new(&data);
+
+        assert_eq!(
+            super::inversion_pairs_count(&data),
+            super::inversion_pairs_count_with(&d, &data)
+        );
+
+        let reversed = Discretizer::with_comparator(&data, |a: &i32, b: &i32| b.cmp(a));
+        let mut sorted = data.clone();
+        sorted.sort();
+        assert_eq!(0, super::inversion_pairs_count_with(&d, &sorted));
+        assert_eq!(
+            super::inversion_pairs_count_with(&d, &data),
+            super::inversion_pairs_count_with(
+                &reversed,
+                &data.iter().rev().cloned().collect::<Vec<_>>()
+            )
+        );
+    }
 }
@@ -0,0 +1,456 @@
+//! Mo's algorithm for offline range queries
+//!
+//! The state of a window is maintained by adding and removing elements at both ends, and the
+//! queries are ordered to keep the total number of moves small, about O(n sqrt q).
+
+use std::ops::Range;
+
+/// State of a window over a sequence, moved by Mo's algorithm.
+pub trait MoState {
+    type Answer;
+
+    /// Adds the element at `index` into the window.
+    fn add(&mut self, index: usize);
+
+    /// Removes the element at `index` from the window.
+    fn remove(&mut self, index: usize);
+
+    /// Gets the answer of the current window.
+    fn answer(&mut self) -> Self::Answer;
+
+    /// Adds the element at `index` to the left end of the window.
+    fn add_left(&mut self, index: usize) {
+        self.add(index);
+    }
+
+    /// Adds the element at `index` to the right end of the window.
+    fn add_right(&mut self, index: usize) {
+        self.add(index);
+    }
+
+    /// Removes the element at `index` from the left end of the window.
+    fn remove_left(&mut self, index: usize) {
+        self.remove(index);
+    }
+
+    /// Removes the element at `index` from the right end of the window.
+    fn remove_right(&mut self, index: usize) {
+        self.remove(index);
+    }
+}
+
+/// State of a window with point updates, moved by Mo's algorithm with updates.
+pub trait MoUpdateState: MoState {
+    /// Applies the `time`-th (0-indexed) update while the window is `window`.
+    fn apply(&mut self, time: usize, window: &Range<usize>);
+
+    /// Reverts the `time`-th (0-indexed) update while the window is `window`.
+    fn revert(&mut self, time: usize, window: &Range<usize>);
+}
+
+fn sqrt(n: usize) -> usize {
+    (n as f64).sqrt() as usize
+}
+
+/// Orders `queries` by blocks of their starts, with the ends alternately ascending and descending
+/// in adjacent blocks.
+///
+/// # Examples
+///
+/// ```
+/// use rsalgo::base::mo::block_order;
+///
+/// assert_eq!(vec![1, 0, 2], block_order(&[0..3, 0..1, 2..3]));
+/// ```
+pub fn block_order(queries: &[Range<usize>]) -> Vec<usize> {
+    let n = queries.iter().map(|q| q.end).max().unwrap_or(0);
+    let block = (n / sqrt(queries.len()).max(1)).max(1);
+    let mut order: Vec<usize> = (0..queries.len()).collect();
+    order.sort_by_key(|&i| {
+        let b = queries[i].start / block;
+        let end = if b % 2 == 0 {
+            queries[i].end
+        } else {
+            n - queries[i].end
+        };
+        (b, end)
+    });
+    order
+}
+
+/// Gets the position of `(x, y)` on the Hilbert curve over a `2^log * 2^log` grid.
+fn hilbert_index(x: usize, y: usize, log: u32) -> u128 {
+    let (mut x, mut y, mut res) = (x as u128, y as u128, 0);
+    let n = 1u128 << log;
+    for s in (0..log).rev().map(|i| 1u128 << i) {
+        let (rx, ry) = (x & s != 0, y & s != 0);
+        res += s * s * ((3 * rx as u128) ^ ry as u128);
+        if !ry {
+            if rx {
+                x = n - 1 - x;
+                y = n - 1 - y;
+            }
+            std::mem::swap(&mut x, &mut y);
+        }
+    }
+    res
+}
+
+/// Orders `queries` along the Hilbert curve over the plane of starts and ends, which often moves
+/// less than `block_order`.
+///
+/// # Examples
+///
+/// ```
+/// use rsalgo::base::mo::hilbert_order;
+///
+/// let order = hilbert_order(&[0..3, 0..1, 2..3]);
+/// assert_eq!(3, order.len());
+/// ```
+pub fn hilbert_order(queries: &[Range<usize>]) -> Vec<usize> {
+    let n = queries.iter().map(|q| q.end).max().unwrap_or(0);
+    let log = (usize::BITS - n.leading_zeros()).max(1);
+    let mut order: Vec<usize> = (0..queries.len()).collect();
+    order.sort_by_cached_key(|&i| hilbert_index(queries[i].start, queries[i].end, log));
+    order
+}
+
+/// Moves the window of `state` from `from` to `to`, expanding before shrinking.
+fn move_window<S: MoState>(state: &mut S, from: &Range<usize>, to: &Range<usize>) {
+    let (mut l, mut r) = (from.start, from.end);
+    while l > to.start {
+        l -= 1;
+        state.add_left(l);
+    }
+    while r < to.end {
+        state.add_right(r);
+        r += 1;
+    }
+    while l < to.start {
+        state.remove_left(l);
+        l += 1;
+    }
+    while r > to.end {
+        r -= 1;
+        state.remove_right(r);
+    }
+}
+
+/// Answers `queries` by moving the window of `state` through them in `order`, starting from an
+/// empty window at 0, returns the answers in the original order of queries.
+///
+/// # Examples
+///
+/// ```
+/// use rsalgo::base::mo::{hilbert_order, solve_in_order, MoState};
+///
+/// struct Sum<'a>(&'a [i32], i32);
+///
+/// impl MoState for Sum<'_> {
+///     type Answer = i32;
+///     fn add(&mut self, index: usize) {
+///         self.1 += self.0[index];
+///     }
+///     fn remove(&mut self, index: usize) {
+///         self.1 -= self.0[index];
+///     }
+///     fn answer(&mut self) -> i32 {
+///         self.1
+///     }
+/// }
+///
+/// let queries = [0..2, 1..3];
+/// let mut state = Sum(&[1, 2, 3], 0);
+/// let order = hilbert_order(&queries);
+/// assert_eq!(vec![3, 5], solve_in_order(&mut state, &queries, &order));
+/// ```
+pub fn solve_in_order<S: MoState>(
+    state: &mut S,
+    queries: &[Range<usize>],
+    order: &[usize],
+) -> Vec<S::Answer> {
+    let mut res: Vec<Option<S::Answer>> = (0..queries.len()).map(|_| None).collect();
+    let mut window = 0..0;
+    for &i in order {
+        assert!(queries[i].start <= queries[i].end, "The range is invalid.");
+        move_window(state, &window, &queries[i]);
+        window = queries[i].clone();
+        res[i] = Some(state.answer());
+    }
+    move_window(state, &window, &(0..0));
+    res.into_iter()
+        .map(|answer| answer.expect("The order misses some queries."))
+        .collect()
+}
+
+/// Answers `queries` by Mo's algorithm in `block_order`, returns the answers in order.
+///
+/// # Examples
+///
+/// ```
+/// use rsalgo::base::mo::{solve, MoState};
+///
+/// // Number of distinct values in a range.
+/// struct Distinct<'a> {
+///     values: &'a [usize],
+///     count: Vec<usize>,
+///     distinct: usize,
+/// }
+///
+/// impl MoState for Distinct<'_> {
+///     type Answer = usize;
+///     fn add(&mut self, index: usize) {
+///         self.count[self.values[index]] += 1;
+///         if self.count[self.values[index]] == 1 {
+///             self.distinct += 1;
+///         }
+///     }
+///     fn remove(&mut self, index: usize) {
+///         self.count[self.values[index]] -= 1;
+///         if self.count[self.values[index]] == 0 {
+///             self.distinct -= 1;
+///         }
+///     }
+///     fn answer(&mut self) -> usize {
+///         self.distinct
+///     }
+/// }
+///
+/// let values = [1, 2, 1, 3];
+/// let mut state = Distinct { values: &values, count: vec![0; 4], distinct: 0 };
+/// assert_eq!(vec![2, 3], solve(&mut state, &[0..3, 1..4]));
+/// ```
+pub fn solve<S: MoState>(state: &mut S, queries: &[Range<usize>]) -> Vec<S::Answer> {
+    solve_in_order(state, queries, &block_order(queries))
+}
+
+/// Answers `queries` with point updates by Mo's algorithm with updates in O(n^(5/3)), where
+/// each query is a range and the number of updates applied before it.
+///
+/// Returns the answers in order, and all updates are reverted at the end.
+///
+/// # Examples
+///
+/// ```
+/// use rsalgo::base::mo::{solve_with_updates, MoState, MoUpdateState};
+/// use std::ops::Range;
+///
+/// // Range sum, where the `t`-th update sets `values[updates[t].0]` to `updates[t].1`.
+/// struct Sum {
+///     values: Vec<i32>,
+///     updates: Vec<(usize, i32)>,
+///     sum: i32,
+/// }
+///
+/// impl Sum {
+///     fn swap(&mut self, time: usize, window: &Range<usize>) {
+///         let (pos, value) = &mut self.updates[time];
+///         if window.contains(pos) {
+///             self.sum += *value - self.values[*pos];
+///         }
+///         std::mem::swap(&mut self.values[*pos], value);
+///     }
+/// }
+///
+/// impl MoState for Sum {
+///     type Answer = i32;
+///     fn add(&mut self, index: usize) {
+///         self.sum += self.values[index];
+///     }
+///     fn remove(&mut self, index: usize) {
+///         self.sum -= self.values[index];
+///     }
+///     fn answer(&mut self) -> i32 {
+///         self.sum
+///     }
+/// }
+///
+/// impl MoUpdateState for Sum {
+///     fn apply(&mut self, time: usize, window: &Range<usize>) {
+///         self.swap(time, window);
+///     }
+///     fn revert(&mut self, time: usize, window: &Range<usize>) {
+///         self.swap(time, window);
+///     }
+/// }
+///
+/// let mut state = Sum { values: vec![1, 2, 3], updates: vec![(1, 10)], sum: 0 };
+/// assert_eq!(vec![6, 14], solve_with_updates(&mut state, &[(0..3, 0), (0..3, 1)]));
+/// ```
+pub fn solve_with_updates<S: MoUpdateState>(
+    state: &mut S,
+    queries: &[(Range<usize>, usize)],
+) -> Vec<S::Answer> {
+    let n = queries.iter().map(|q| q.0.end).max().unwrap_or(0);
+    let block = ((n as f64).powf(2.0 / 3.0) as usize).max(1);
+    let mut order: Vec<usize> = (0..queries.len()).collect();
+    order.sort_by_key(|&i| {
+        let (range, time) = &queries[i];
+        (range.start / block, range.end / block, *time)
+    });
+
+    let mut res: Vec<Option<S::Answer>> = (0..queries.len()).map(|_| None).collect();
+    let (mut window, mut time) = (0..0, 0);
+    for &i in &order {
+        let (range, target) = &queries[i];
+        assert!(range.start <= range.end, "The range is invalid.");
+        move_window(state, &window, range);
+        window = range.clone();
+        while time < *target {
+            state.apply(time, &window);
+            time += 1;
+        }
+        while time > *target {
+            time -= 1;
+            state.revert(time, &window);
+        }
+        res[i] = Some(state.answer());
+    }
+    while time > 0 {
+        time -= 1;
+        state.revert(time, &window);
+    }
+    move_window(state, &window, &(0..0));
+    res.into_iter().map(Option::unwrap).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        block_order, hilbert_order, solve, solve_in_order, solve_with_updates, MoState,
+        MoUpdateState,
+    };
+    use rand::Rng;
+    use std::collections::HashSet;
+    use std::ops::Range;
+
+    /// Number of distinct values, with updates setting `values[updates[t].0]` to `updates[t].1`.
+    struct Distinct {
+        values: Vec<usize>,
+        updates: Vec<(usize, usize)>,
+        count: Vec<usize>,
+        distinct: usize,
+    }
+
+    impl Distinct {
+        fn new(values: &[usize], updates: &[(usize, usize)], sigma: usize) -> Self {
+            Distinct {
+                values: values.to_vec(),
+                updates: updates.to_vec(),
+                count: vec![0; sigma],
+                distinct: 0,
+            }
+        }
+
+        fn swap(&mut self, time: usize, window: &Range<usize>) {
+            let pos = self.updates[time].0;
+            if window.contains(&pos) {
+                self.remove(pos);
+            }
+            std::mem::swap(&mut self.values[pos], &mut self.updates[time].1);
+            if window.contains(&pos) {
+                self.add(pos);
+            }
+        }
+    }
+
+    impl MoState for Distinct {
+        type Answer = usize;
+
+        fn add(&mut self, index: usize) {
+            self.count[self.values[index]] += 1;
+            if self.count[self.values[index]] == 1 {
+                self.distinct += 1;
+            }
+        }
+
+        fn remove(&mut self, index: usize) {
+            self.count[self.values[index]] -= 1;
+            if self.count[self.values[index]] == 0 {
+                self.distinct -= 1;
+            }
+        }
+
+        fn answer(&mut self) -> usize {
+            self.distinct
+        }
+    }
+
+    impl MoUpdateState for Distinct {
+        fn apply(&mut self, time: usize, window: &Range<usize>) {
+            self.swap(time, window);
+        }
+
+        fn revert(&mut self, time: usize, window: &Range<usize>) {
+            self.swap(time, window);
+        }
+    }
+
+    fn distinct(values: &[usize]) -> usize {
+        values.iter().collect::<HashSet<_>>().len()
+    }
+
+    fn random_ranges(len: usize, count: usize) -> Vec<Range<usize>> {
+        let mut rng = rand::thread_rng();
+        (0..count)
+            .map(|_| {
+                let l = rng.gen_range(0, len + 1);
+                l..rng.gen_range(l, len + 1)
+            })
+            .collect()
+    }
+
+    #[test]
+    fn mo() {
+        let mut rng = rand::thread_rng();
+        for &(len, count) in &[(0, 5), (1, 10), (50, 0), (50, 100), (300, 500)] {
+            let values: Vec<usize> = (0..len).map(|_| rng.gen_range(0, 20)).collect();
+            let queries = random_ranges(len, count);
+            let expected: Vec<usize> = queries
+                .iter()
+                .map(|q| distinct(&values[q.clone()]))
+                .collect();
+
+            let mut state = Distinct::new(&values, &[], 20);
+            assert_eq!(expected, solve(&mut state, &queries));
+            assert_eq!(0, state.distinct);
+            for order in &[block_order(&queries), hilbert_order(&queries)] {
+                let mut sorted = order.clone();
+                sorted.sort();
+                assert_eq!((0..count).collect::<Vec<_>>(), sorted);
+                assert_eq!(expected, solve_in_order(&mut state, &queries, order));
+            }
+        }
+    }
+
+    #[test]
+    fn mo_with_updates() {
+        let mut rng = rand::thread_rng();
+        for &(len, count) in &[(1, 10), (50, 100), (200, 300)] {
+            let values: Vec<usize> = (0..len).map(|_| rng.gen_range(0, 20)).collect();
+            let updates: Vec<(usize, usize)> = (0..count)
+                .map(|_| (rng.gen_range(0, len), rng.gen_range(0, 20)))
+                .collect();
+            let queries: Vec<(Range<usize>, usize)> = random_ranges(len, count)
+                .into_iter()
+                .map(|q| (q, rng.gen_range(0, count + 1)))
+                .collect();
+
+            let mut versions = vec![values.clone()];
+            for &(pos, value) in &updates {
+                let mut next = versions.last().unwrap().clone();
+                next[pos] = value;
+                versions.push(next);
+            }
+            let expected: Vec<usize> = queries
+                .iter()
+                .map(|(q, t)| distinct(&versions[*t][q.clone()]))
+                .collect();
+
+            let mut state = Distinct::new(&values, &updates, 20);
+            assert_eq!(expected, solve_with_updates(&mut state, &queries));
+            assert_eq!(values, state.values);
+            assert_eq!(0, state.distinct);
+        }
+    }
+}
@@ -1,10 +1,25 @@
 //! Basic algorithms
 
+mod bit_mask;
+pub use bit_mask::BitMask;
+
 mod subset_generator;
-pub use subset_generator::SubsetGenerator;
+pub use subset_generator::{
+    CombinationGenerator, GrayCodeGenerator, SubsetGenerator, SupersetGenerator,
+};
+
+mod permutation;
+pub use permutation::*;
 
 mod search;
 pub use search::*;
 
+mod discretizer;
+pub use discretizer::Discretizer;
+
+pub mod sort;
+
+pub mod mo;
+
 mod inversion;
 pub use inversion::*;
@@ -0,0 +1,349 @@
+use crate::ds::FenwickTree;
+use std::cmp::Ordering;
+
+/// Rearrange `slice` into the next permutation in lexicographic order, return false and
+/// rearrange it into the first permutation if it is the last one.
+///
+/// # Examples
+///
+/// ```
+/// let mut slice = [1, 2, 2];
+///
+/// assert!(rsalgo::base::next_permutation(&mut slice));
+/// assert_eq!([2, 1, 2], slice);
+/// ```
+pub fn next_permutation<T: Ord>(slice: &mut [T]) -> bool {
+    next_permutation_by(slice, T::cmp)
+}
+
+/// Rearrange `slice` into the next permutation in lexicographic order defined by `compare`.
+///
+/// # Examples
+///
+/// ```
+/// let mut slice = [3, 1, 2];
+///
+/// assert!(rsalgo::base::next_permutation_by(&mut slice, |a, b| b.cmp(a)));
+/// assert_eq!([2, 3, 1], slice);
+/// ```
+pub fn next_permutation_by<T, F>(slice: &mut [T], compare: F) -> bool
+where
+    F: FnMut(&T, &T) -> Ordering,
+{
+    let mut compare = compare;
+    let len = slice.len();
+    let pivot = match (1..len)
+        .rev()
+        .find(|&i| compare(&slice[i - 1], &slice[i]) == Ordering::Less)
+    {
+        Some(i) => i - 1,
+        None => {
+            slice.reverse();
+            return false;
+        }
+    };
+    let swap = (pivot + 1..len)
+        .rev()
+        .find(|&i| compare(&slice[pivot], &slice[i]) == Ordering::Less)
+        .unwrap();
+    slice.swap(pivot, swap);
+    slice[pivot + 1..].reverse();
+    true
+}
+
+/// Rearrange `slice` into the previous permutation in lexicographic order, return false and
+/// rearrange it into the last permutation if it is the first one.
+///
+/// # Examples
+///
+/// ```
+/// let mut slice = [2, 1, 2];
+///
+/// assert!(rsalgo::base::prev_permutation(&mut slice));
+/// assert_eq!([1, 2, 2], slice);
+/// ```
+pub fn prev_permutation<T: Ord>(slice: &mut [T]) -> bool {
+    next_permutation_by(slice, |a, b| b.cmp(a))
+}
+
+/// Rearrange `slice` into the previous permutation in lexicographic order defined by `compare`.
+///
+/// # Examples
+///
+/// ```
+/// let mut slice = [2, 3, 1];
+///
+/// assert!(rsalgo::base::prev_permutation_by(&mut slice, |a, b| b.cmp(a)));
+/// assert_eq!([3, 1, 2], slice);
+/// ```
+pub fn prev_permutation_by<T, F>(slice: &mut [T], compare: F) -> bool
+where
+    F: FnMut(&T, &T) -> Ordering,
+{
+    let mut compare = compare;
+    next_permutation_by(slice, |a, b| compare(b, a))
+}
+
+/// Generator of permutations with repetition, which are all sequences of length `k` over `0..n`
+/// in lexicographic order.
+pub struct RepeatedPermutationGenerator {
+    n: usize,
+    cur: Option<Vec<usize>>,
+}
+
+impl RepeatedPermutationGenerator {
+    /// Create from the number of symbols `n` and the length `k`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let mut gen = rsalgo::base::RepeatedPermutationGenerator::new(2, 2);
+    ///
+    /// assert_eq!(Some(vec![0, 0]), gen.next());
+    /// assert_eq!(Some(vec![0, 1]), gen.next());
+    /// assert_eq!(Some(vec![1, 0]), gen.next());
+    /// assert_eq!(Some(vec![1, 1]), gen.next());
+    /// assert_eq!(None, gen.next());
+    /// ```
+    pub fn new(n: usize, k: usize) -> Self {
+        RepeatedPermutationGenerator {
+            n,
+            cur: if n > 0 || k == 0 {
+                Some(vec![0; k])
+            } else {
+                None
+            },
+        }
+    }
+}
+
+impl Iterator for RepeatedPermutationGenerator {
+    type Item = Vec<usize>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let cur = self.cur.take()?;
+        let mut next = cur.clone();
+        if let Some(pos) = next.iter().rposition(|&x| x + 1 < self.n) {
+            next[pos] += 1;
+            for x in &mut next[pos + 1..] {
+                *x = 0;
+            }
+            self.cur = Some(next);
+        }
+        Some(cur)
+    }
+}
+
+/// Get the rank of a permutation of `0..n` in lexicographic order.
+///
+/// # Examples
+///
+/// ```
+/// assert_eq!(0, rsalgo::base::permutation_rank(&[0, 1, 2]));
+/// assert_eq!(3, rsalgo::base::permutation_rank(&[1, 2, 0]));
+/// ```
+///
+/// # Panics
+///
+/// Panic when the rank overflows `usize`.
+pub fn permutation_rank(permutation: &[usize]) -> usize {
+    let n = permutation.len();
+    let mut used: FenwickTree<usize> = FenwickTree::new(n);
+    let mut rank: usize = 0;
+    for (i, &x) in permutation.iter().enumerate() {
+        let smaller = x - used.prefix_sum(x);
+        rank = rank
+            .checked_mul(n - i)
+            .and_then(|r| r.checked_add(smaller))
+            .expect("The rank overflows.");
+        used.add(x, 1);
+    }
+    rank
+}
+
+/// Get the permutation of `0..n` with `rank` in lexicographic order.
+///
+/// # Examples
+///
+/// ```
+/// assert_eq!(vec![1, 2, 0], rsalgo::base::permutation_unrank(3, 3));
+/// ```
+///
+/// # Panics
+///
+/// Panic when `rank` is not less than `n!`.
+pub fn permutation_unrank(n: usize, rank: usize) -> Vec<usize> {
+    let mut digits = vec![0; n];
+    let mut rank = rank;
+    for (i, digit) in digits.iter_mut().enumerate().rev() {
+        let base = n - i;
+        *digit = rank % base;
+        rank /= base;
+    }
+    assert_eq!(0, rank, "The rank is out of range.");
+
+    let mut rest: Vec<usize> = (0..n).collect();
+    digits.into_iter().map(|d| rest.remove(d)).collect()
+}
+
+/// Get the binomial coefficient `C(n, k)`, or None if it overflows `usize`.
+fn binomial(n: usize, k: usize) -> Option<usize> {
+    if k > n {
+        return Some(0);
+    }
+    let k = k.min(n - k);
+    let mut res: usize = 1;
+    for i in 0..k {
+        let next = res as u128 * (n - i) as u128 / (i + 1) as u128;
+        if next > usize::MAX as u128 {
+            return None;
+        }
+        res = next as usize;
+    }
+    Some(res)
+}
+
+/// Get the rank of a combination, given as ascending elements, in colexicographic order.
+///
+/// The order is the same as the one of `CombinationGenerator`.
+///
+/// # Examples
+///
+/// ```
+/// assert_eq!(0, rsalgo::base::combination_rank(&[0, 1]));
+/// assert_eq!(3, rsalgo::base::combination_rank(&[0, 3]));
+/// ```
+///
+/// # Panics
+///
+/// Panic when the rank overflows `usize`.
+pub fn combination_rank(combination: &[usize]) -> usize {
+    combination
+        .iter()
+        .enumerate()
+        .map(|(i, &c)| binomial(c, i + 1).expect("The rank overflows."))
+        .fold(0, |acc, x| acc.checked_add(x).expect("The rank overflows."))
+}
+
+/// Get the combination with `k` elements and `rank` in colexicographic order, as ascending elements.
+///
+/// # Examples
+///
+/// ```
+/// assert_eq!(vec![0, 3], rsalgo::base::combination_unrank(2, 3));
+/// ```
+pub fn combination_unrank(k: usize, rank: usize) -> Vec<usize> {
+    let mut res = vec![0; k];
+    let mut rank = rank;
+    for i in (1..=k).rev() {
+        // `C(c, 1) = c`, and `C(rank + i, i) > rank` bounds the search otherwise.
+        let c = if i == 1 {
+            rank
+        } else {
+            super::dichotomy_last(i - 1, rank.saturating_add(i), |c| {
+                binomial(c, i).is_some_and(|b| b <= rank)
+            })
+            .unwrap()
+        };
+        rank -= binomial(c, i).unwrap();
+        res[i - 1] = c;
+    }
+    res
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::base::{BitMask, CombinationGenerator};
+    use rand::Rng;
+
+    #[test]
+    fn permutation() {
+        let mut slice = [0, 1, 1, 2, 3];
+        let mut all = vec![slice.to_vec()];
+        while super::next_permutation(&mut slice) {
+            all.push(slice.to_vec());
+        }
+        assert_eq!(60, all.len());
+        assert_eq!([0, 1, 1, 2, 3], slice);
+        for w in all.windows(2) {
+            assert!(w[0] < w[1]);
+        }
+
+        let mut slice = [3, 2, 1, 1, 0];
+        let mut count = 1;
+        while super::prev_permutation(&mut slice) {
+            count += 1;
+        }
+        assert_eq!(60, count);
+        assert_eq!([3, 2, 1, 1, 0], slice);
+
+        let mut empty: [i32; 0] = [];
+        assert!(!super::next_permutation(&mut empty));
+    }
+
+    #[test]
+    fn repeated_permutation() {
+        let all: Vec<_> = super::RepeatedPermutationGenerator::new(3, 4).collect();
+        assert_eq!(81, all.len());
+        for w in all.windows(2) {
+            assert!(w[0] < w[1]);
+        }
+        assert_eq!(
+            vec![Vec::<usize>::new()],
+            super::RepeatedPermutationGenerator::new(0, 0).collect::<Vec<_>>()
+        );
+        assert_eq!(0, super::RepeatedPermutationGenerator::new(0, 2).count());
+    }
+
+    #[test]
+    fn permutation_rank() {
+        let mut slice: Vec<usize> = (0..6).collect();
+        let mut rank = 0;
+        loop {
+            assert_eq!(rank, super::permutation_rank(&slice));
+            assert_eq!(slice, super::permutation_unrank(6, rank));
+            rank += 1;
+            if !super::next_permutation(&mut slice) {
+                break;
+            }
+        }
+
+        let last: Vec<usize> = (0..20).rev().collect();
+        assert_eq!(2_432_902_008_176_639_999, super::permutation_rank(&last));
+        assert_eq!(
+            last,
+            super::permutation_unrank(20, 2_432_902_008_176_639_999)
+        );
+    }
+
+    #[test]
+    fn combination_rank() {
+        for k in 0..=6 {
+            for (rank, mask) in CombinationGenerator::new(0b11_1111_1111usize, k).enumerate() {
+                let mut elements = Vec::new();
+                let mut rest = mask;
+                while rest != 0 {
+                    elements.push(BitMask::trailing_zeros(&rest));
+                    rest &= rest - 1;
+                }
+                assert_eq!(rank, super::combination_rank(&elements));
+                assert_eq!(elements, super::combination_unrank(k, rank));
+            }
+        }
+    }
+
+    #[test]
+    fn combination_unrank_large() {
+        assert_eq!(vec![1 << 40], super::combination_unrank(1, 1 << 40));
+        assert_eq!(vec![usize::MAX], super::combination_unrank(1, usize::MAX));
+        let mut rng = rand::thread_rng();
+        for k in 1..=8 {
+            for _ in 0..100 {
+                let rank = rng.gen::<usize>() >> rng.gen_range(0, 64);
+                let combination = super::combination_unrank(k, rank);
+                assert!(combination.windows(2).all(|w| w[0] < w[1]));
+                assert_eq!(rank, super::combination_rank(&combination));
+            }
+        }
+    }
+}
@@ -1,30 +1,149 @@
-/// Dichotomy in interval `[lower_bound, upper_bound)`, return the first value that makes checker true.
+/// Primitive integers which can be bisected without overflow.
+pub trait Bisect: Copy + Ord {
+    /// Get the midpoint of `lower` and `upper` rounded towards negative infinity.
+    fn midpoint(lower: Self, upper: Self) -> Self;
+
+    /// Get the next value, `self` must not be the maximum value.
+    fn successor(self) -> Self;
+
+    /// Get the previous value, `self` must not be the minimum value.
+    fn predecessor(self) -> Self;
+}
+
+macro_rules! impl_bisect {
+    ($($t:ty)*) => ($(
+        impl Bisect for $t {
+            #[inline]
+            fn midpoint(lower: Self, upper: Self) -> Self {
+                (lower & upper) + ((lower ^ upper) >> 1)
+            }
+
+            #[inline]
+            fn successor(self) -> Self {
+                self + 1
+            }
+
+            #[inline]
+            fn predecessor(self) -> Self {
+                self - 1
+            }
+        }
+    )*)
+}
+
+impl_bisect! { u8 u16 u32 u64 u128 usize i8 i16 i32 i64 i128 isize }
+
+/// Dichotomy in interval `[lower, upper]`, return the first value that makes checker true.
 ///
 /// # Examples
 ///
 /// ```
-/// assert_eq!(Some(5), rsalgo::base::dichotomy(0, 10, |val| val >= 5));
+/// use rsalgo::base::dichotomy_inclusive;
+///
+/// assert_eq!(Some(200), dichotomy_inclusive(0u8, 255, |val| val >= 200));
+/// assert_eq!(Some(i64::MAX), dichotomy_inclusive(0, i64::MAX, |val| val == i64::MAX));
 /// ```
-pub fn dichotomy<TC: Fn(isize) -> bool>(
-    lower: isize,
-    upper: isize,
-    checker: TC,
-) -> Option<isize> {
+pub fn dichotomy_inclusive<T, TC>(lower: T, upper: T, checker: TC) -> Option<T>
+where
+    T: Bisect,
+    TC: FnMut(T) -> bool,
+{
+    let mut checker = checker;
     let mut lower = lower;
     let mut upper = upper;
-    let mut ans = None;
+
+    if lower > upper || !checker(upper) {
+        return None;
+    }
 
     while lower < upper {
-        let mid = (lower + upper) / 2;
+        let mid = T::midpoint(lower, upper);
+        if checker(mid) {
+            upper = mid;
+        } else {
+            lower = mid.successor();
+        }
+    }
+
+    Some(upper)
+}
+
+/// Dichotomy in interval `[lower, upper)`, return the first value that makes checker true.
+///
+/// # Examples
+///
+/// ```
+/// assert_eq!(Some(5), rsalgo::base::dichotomy(0, 10, |val| val >= 5));
+/// assert_eq!(Some(1 << 100), rsalgo::base::dichotomy(0, u128::MAX, |val| val >= 1 << 100));
+/// ```
+pub fn dichotomy<T, TC>(lower: T, upper: T, checker: TC) -> Option<T>
+where
+    T: Bisect,
+    TC: FnMut(T) -> bool,
+{
+    if lower < upper {
+        dichotomy_inclusive(lower, upper.predecessor(), checker)
+    } else {
+        None
+    }
+}
+
+/// Dichotomy in interval `[lower, upper)`, return the last value that makes checker true.
+///
+/// The checker should be true on a prefix of the interval.
+///
+/// # Examples
+///
+/// ```
+/// assert_eq!(Some(3), rsalgo::base::dichotomy_last(0u64, 100, |val| val * val <= 10));
+/// ```
+pub fn dichotomy_last<T, TC>(lower: T, upper: T, checker: TC) -> Option<T>
+where
+    T: Bisect,
+    TC: FnMut(T) -> bool,
+{
+    let mut checker = checker;
+    if lower >= upper || !checker(lower) {
+        return None;
+    }
+    match dichotomy(lower.successor(), upper, |val| !checker(val)) {
+        Some(val) => Some(val.predecessor()),
+        None => Some(upper.predecessor()),
+    }
+}
+
+/// Dichotomy in real interval `[lower, upper]`, return the approximate first value that makes checker true.
+///
+/// Stop when the interval is not longer than `eps` or after `iterations` steps.
+///
+/// # Examples
+///
+/// ```
+/// let sqrt2 = rsalgo::base::dichotomy_real(0.0, 2.0, 1e-9, 100, |val| val * val >= 2.0);
+///
+/// assert!((sqrt2 - 2f64.sqrt()).abs() < 1e-8);
+/// ```
+pub fn dichotomy_real<TC>(lower: f64, upper: f64, eps: f64, iterations: usize, checker: TC) -> f64
+where
+    TC: FnMut(f64) -> bool,
+{
+    let mut checker = checker;
+    let mut lower = lower;
+    let mut upper = upper;
+
+    for _ in 0..iterations {
+        if upper - lower <= eps {
+            break;
+        }
+        let mid = lower / 2.0 + upper / 2.0;
         if checker(mid) {
-            ans = Some(mid);
             upper = mid;
         } else {
-            lower = mid + 1;
+            lower = mid;
         }
     }
 
-    ans
+    upper
 }
 
 /// Get the position of the lower bound of value in the slice.
@@ -37,10 +156,7 @@ pub fn dichotomy<TC: Fn(isize) -> bool>(
 /// assert_eq!(Some(5), rsalgo::base::lower_bound(&slice, &5));
 /// ```
 pub fn lower_bound<T: PartialOrd>(slice: &[T], value: &T) -> Option<usize> {
-    match dichotomy(0, slice.len() as isize, |pos| &slice[pos as usize] >= value) {
-        Some(val) => Some(val as usize),
-        None => None,
-    }
+    dichotomy(0, slice.len(), |pos| &slice[pos] >= value)
 }
 
 /// Get the position of the upper bound of value in the slice.
@@ -53,10 +169,7 @@ pub fn lower_bound<T: PartialOrd>(slice: &[T], value: &T) -> Option<usize> {
 /// assert_eq!(Some(6), rsalgo::base::upper_bound(&slice, &5));
 /// ```
 pub fn upper_bound<T: PartialOrd>(slice: &[T], value: &T) -> Option<usize> {
-    match dichotomy(0, slice.len() as isize, |pos| &slice[pos as usize] > value) {
-        Some(val) => Some(val as usize),
-        None => None,
-    }
+    dichotomy(0, slice.len(), |pos| &slice[pos] > value)
 }
 
 /// Get the position interval that those values equal to the value.
@@ -71,7 +184,7 @@ pub fn upper_bound<T: PartialOrd>(slice: &[T], value: &T) -> Option<usize> {
 pub fn equal_range<T: PartialOrd>(slice: &[T], value: &T) -> Option<(usize, usize)> {
     match (lower_bound(slice, value), upper_bound(slice, value)) {
         (Some(first), Some(last)) => Some((first, last)),
-        (Some(first), None) => Some((first, slice.len() as usize)),
+        (Some(first), None) => Some((first, slice.len())),
         (None, Some(last)) => Some((0, last)),
         (None, None) => None,
     }
@@ -87,14 +200,7 @@ pub fn equal_range<T: PartialOrd>(slice: &[T], value: &T) -> Option<(usize, usiz
 /// assert_eq!([0, 1, 2, 1, 3], rsalgo::base::discretization(&slice).as_slice());
 /// ```
 pub fn discretization<T: PartialEq + Ord + Clone>(slice: &[T]) -> Vec<usize> {
-    let mut temp = slice.to_vec();
-    temp.sort();
-    temp.dedup();
-    let mut ans: Vec<usize> = Vec::new();
-    for item in slice {
-        ans.push(lower_bound(&temp, &item).unwrap());
-    }
-    ans
+    super::Discretizer::new(slice).compress(slice)
 }
 
 #[cfg(test)]
@@ -105,6 +211,60 @@ mod tests {
     fn dichotomy() {
         assert_eq!(Some(5), super::dichotomy(0, 10, |val| val >= 5));
         assert_eq!(None, super::dichotomy(0, 10, |val| val > 10));
+        assert_eq!(None, super::dichotomy(10, 0, |_| true));
+
+        assert_eq!(Some(254), super::dichotomy(0u8, 255, |val| val >= 254));
+        assert_eq!(None, super::dichotomy(0u8, 255, |val| val == 255));
+        assert_eq!(
+            Some(255),
+            super::dichotomy_inclusive(0u8, 255, |val| val == 255)
+        );
+        assert_eq!(
+            Some(-128),
+            super::dichotomy_inclusive(-128i8, 127, |_| true)
+        );
+        assert_eq!(Some(100), super::dichotomy(-128i8, 127, |val| val >= 100));
+        assert_eq!(
+            Some(i128::MIN + 1),
+            super::dichotomy(i128::MIN, i128::MAX, |val| val > i128::MIN)
+        );
+        assert_eq!(
+            Some(u64::MAX - 1),
+            super::dichotomy(0, u64::MAX, |val| val == u64::MAX - 1)
+        );
+    }
+
+    #[test]
+    fn dichotomy_last() {
+        assert_eq!(Some(9), super::dichotomy_last(0, 10, |_| true));
+        assert_eq!(None, super::dichotomy_last(0, 10, |_| false));
+        assert_eq!(
+            Some(126),
+            super::dichotomy_last(-128i8, 127, |val| val < 127)
+        );
+        assert_eq!(
+            Some(4_294_967_295),
+            super::dichotomy_last(0u64, u64::MAX, |val| val <= u64::from(u32::MAX))
+        );
+
+        let mut rng = rand::thread_rng();
+        for _ in 0..100 {
+            let n: usize = rng.gen_range(0, 1_000_000_000);
+            let root = super::dichotomy_last(0, n + 1, |val| val * val <= n).unwrap();
+            assert!(root * root <= n && (root + 1) * (root + 1) > n);
+        }
+    }
+
+    #[test]
+    fn dichotomy_real() {
+        let root = super::dichotomy_real(0.0, 10.0, 1e-9, 1000, |val| val * val * val >= 10.0);
+        assert!((root - 10f64.cbrt()).abs() < 1e-8);
+
+        let limited = super::dichotomy_real(0.0, 1.0, 0.0, 1, |val| val >= 0.3);
+        assert_eq!(0.5, limited);
+
+        let wide = super::dichotomy_real(-f64::MAX, f64::MAX, 1.0, 2000, |val| val >= 1e300);
+        assert!((wide - 1e300).abs() <= 1e285);
     }
 
     #[test]
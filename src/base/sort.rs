@@ -0,0 +1,764 @@
+//! Sorting algorithms
+
+use std::cmp::Ordering;
+
+/// Rearrange `slice` so that the item at position `i` is the item originally at `order[i]`.
+fn apply_permutation<T>(slice: &mut [T], order: &[usize]) {
+    let mut visited = vec![false; slice.len()];
+    for start in 0..slice.len() {
+        if visited[start] {
+            continue;
+        }
+        let mut cur = start;
+        loop {
+            visited[cur] = true;
+            let next = order[cur];
+            if next == start {
+                break;
+            }
+            slice.swap(cur, next);
+            cur = next;
+        }
+    }
+}
+
+/// Stable merge sort on the positions of `slice`, returns the sorted positions and
+/// adds the number of earlier greater items of each item to `counts`.
+fn merge_sort_order<T, F>(slice: &[T], compare: &mut F, counts: &mut [usize]) -> Vec<usize>
+where
+    F: FnMut(&T, &T) -> Ordering,
+{
+    fn sort<T, F>(
+        slice: &[T],
+        order: &mut [usize],
+        temp: &mut [usize],
+        compare: &mut F,
+        counts: &mut [usize],
+    ) where
+        F: FnMut(&T, &T) -> Ordering,
+    {
+        let len = order.len();
+        if len <= 1 {
+            return;
+        }
+        let mid = len / 2;
+        sort(slice, &mut order[..mid], temp, compare, counts);
+        sort(slice, &mut order[mid..], temp, compare, counts);
+        let (mut ll, mut rr, mut cur) = (0, mid, 0);
+
+        while ll < mid && rr < len {
+            if compare(&slice[order[ll]], &slice[order[rr]]) != Ordering::Greater {
+                temp[cur] = order[ll];
+                ll += 1;
+            } else {
+                counts[order[rr]] += mid - ll;
+                temp[cur] = order[rr];
+                rr += 1;
+            }
+            cur += 1;
+        }
+
+        while ll < mid {
+            temp[cur] = order[ll];
+            cur += 1;
+            ll += 1;
+        }
+
+        while rr < len {
+            temp[cur] = order[rr];
+            cur += 1;
+            rr += 1;
+        }
+
+        order.clone_from_slice(&temp[..len]);
+    }
+
+    let mut order: Vec<usize> = (0..slice.len()).collect();
+    let mut temp = vec![0; slice.len()];
+    sort(slice, &mut order, &mut temp, compare, counts);
+    order
+}
+
+/// Stable merge sort, return the number of inversion pairs of the original slice.
+///
+/// # Examples
+///
+/// ```
+/// let mut slice = [1, 3, 5, 3, 2, 4, 7, 9, 6, 6];
+///
+/// assert_eq!(9, rsalgo::base::sort::merge_sort(&mut slice));
+/// assert_eq!([1, 2, 3, 3, 4, 5, 6, 6, 7, 9], slice);
+/// ```
+pub fn merge_sort<T: Ord>(slice: &mut [T]) -> usize {
+    merge_sort_by(slice, T::cmp)
+}
+
+/// Stable merge sort with a comparator, return the number of inversion pairs of the original slice.
+///
+/// # Examples
+///
+/// ```
+/// let mut slice = [1, 3, 2];
+///
+/// assert_eq!(2, rsalgo::base::sort::merge_sort_by(&mut slice, |a, b| b.cmp(a)));
+/// assert_eq!([3, 2, 1], slice);
+/// ```
+pub fn merge_sort_by<T, F>(slice: &mut [T], compare: F) -> usize
+where
+    F: FnMut(&T, &T) -> Ordering,
+{
+    let mut compare = compare;
+    let mut counts = vec![0; slice.len()];
+    let order = merge_sort_order(slice, &mut compare, &mut counts);
+    apply_permutation(slice, &order);
+    counts.into_iter().sum()
+}
+
+/// Stable merge sort with a key function, return the number of inversion pairs of the original slice.
+///
+/// # Examples
+///
+/// ```
+/// let mut slice = [-3i32, 1, -2];
+///
+/// assert_eq!(2, rsalgo::base::sort::merge_sort_by_key(&mut slice, |x| x.abs()));
+/// assert_eq!([1, -2, -3], slice);
+/// ```
+pub fn merge_sort_by_key<T, K, F>(slice: &mut [T], key: F) -> usize
+where
+    K: Ord,
+    F: FnMut(&T) -> K,
+{
+    let mut key = key;
+    merge_sort_by(slice, |a, b| key(a).cmp(&key(b)))
+}
+
+/// Get the number of earlier items which are greater than each item.
+///
+/// # Examples
+///
+/// ```
+/// let slice = [3, 1, 2, 3, 0];
+///
+/// assert_eq!(vec![0, 1, 1, 0, 4], rsalgo::base::sort::inversion_counts(&slice));
+/// ```
+pub fn inversion_counts<T: Ord>(slice: &[T]) -> Vec<usize> {
+    inversion_counts_by(slice, T::cmp)
+}
+
+/// Get the number of earlier items which are greater than each item under a comparator.
+///
+/// # Examples
+///
+/// ```
+/// let slice = [3, 1, 2];
+///
+/// assert_eq!(vec![0, 0, 1], rsalgo::base::sort::inversion_counts_by(&slice, |a, b| b.cmp(a)));
+/// ```
+pub fn inversion_counts_by<T, F>(slice: &[T], compare: F) -> Vec<usize>
+where
+    F: FnMut(&T, &T) -> Ordering,
+{
+    let mut compare = compare;
+    let mut counts = vec![0; slice.len()];
+    merge_sort_order(slice, &mut compare, &mut counts);
+    counts
+}
+
+/// Stable counting sort by a small non-negative key.
+///
+/// # Examples
+///
+/// ```
+/// let mut slice = [(2, 'a'), (0, 'b'), (2, 'c'), (1, 'd')];
+///
+/// rsalgo::base::sort::counting_sort_by_key(&mut slice, |x| x.0);
+/// assert_eq!([(0, 'b'), (1, 'd'), (2, 'a'), (2, 'c')], slice);
+/// ```
+pub fn counting_sort_by_key<T, F>(slice: &mut [T], key: F)
+where
+    F: FnMut(&T) -> usize,
+{
+    let keys: Vec<usize> = slice.iter().map(key).collect();
+    let bound = keys.iter().max().map_or(0, |&k| k + 1);
+    let order = counting_order((0..slice.len()).collect(), bound, |i| keys[i]);
+    apply_permutation(slice, &order);
+}
+
+/// Stable counting sort on positions, `key` must be less than `bound`.
+fn counting_order<F>(order: Vec<usize>, bound: usize, key: F) -> Vec<usize>
+where
+    F: Fn(usize) -> usize,
+{
+    let mut start = vec![0; bound + 1];
+    for &i in &order {
+        start[key(i) + 1] += 1;
+    }
+    for k in 0..bound {
+        start[k + 1] += start[k];
+    }
+    let mut res = vec![0; order.len()];
+    for &i in &order {
+        let k = key(i);
+        res[start[k]] = i;
+        start[k] += 1;
+    }
+    res
+}
+
+/// Keys which can be sorted by bytes, in the same order as their natural order.
+pub trait RadixKey: Copy {
+    /// The number of bytes of the key.
+    const BYTES: usize;
+
+    /// Get the `i`-th least significant byte.
+    fn radix_byte(self, i: usize) -> u8;
+}
+
+macro_rules! impl_radix_key_unsigned {
+    ($($t:ty)*) => ($(
+        impl RadixKey for $t {
+            const BYTES: usize = std::mem::size_of::<$t>();
+
+            #[inline]
+            fn radix_byte(self, i: usize) -> u8 {
+                (self >> (i * 8)) as u8
+            }
+        }
+    )*)
+}
+
+macro_rules! impl_radix_key_signed {
+    ($($t:ty, $u:ty);*) => ($(
+        impl RadixKey for $t {
+            const BYTES: usize = std::mem::size_of::<$t>();
+
+            #[inline]
+            fn radix_byte(self, i: usize) -> u8 {
+                ((self as $u) ^ (1 << (Self::BYTES * 8 - 1))).radix_byte(i)
+            }
+        }
+    )*)
+}
+
+impl_radix_key_unsigned! { u8 u16 u32 u64 u128 usize }
+impl_radix_key_signed! { i8, u8; i16, u16; i32, u32; i64, u64; i128, u128; isize, usize }
+
+/// Stable LSD radix sort for integers.
+///
+/// # Examples
+///
+/// ```
+/// let mut slice = [3, -1, 2, i32::MIN, 0];
+///
+/// rsalgo::base::sort::radix_sort(&mut slice);
+/// assert_eq!([i32::MIN, -1, 0, 2, 3], slice);
+/// ```
+pub fn radix_sort<T: RadixKey>(slice: &mut [T]) {
+    radix_sort_by_key(slice, |&x| x)
+}
+
+/// Stable LSD radix sort by an integer key.
+///
+/// # Examples
+///
+/// ```
+/// let mut slice = ["ccc", "a", "bb"];
+///
+/// rsalgo::base::sort::radix_sort_by_key(&mut slice, |s| s.len());
+/// assert_eq!(["a", "bb", "ccc"], slice);
+/// ```
+pub fn radix_sort_by_key<T, K, F>(slice: &mut [T], key: F)
+where
+    K: RadixKey,
+    F: FnMut(&T) -> K,
+{
+    let keys: Vec<K> = slice.iter().map(key).collect();
+    let mut order: Vec<usize> = (0..slice.len()).collect();
+    for byte in 0..K::BYTES {
+        let first = match keys.first() {
+            Some(k) => k.radix_byte(byte),
+            None => return,
+        };
+        if keys.iter().all(|k| k.radix_byte(byte) == first) {
+            continue;
+        }
+        order = counting_order(order, 256, |i| keys[i].radix_byte(byte) as usize);
+    }
+    apply_permutation(slice, &order);
+}
+
+/// Stable MSD radix sort for byte strings.
+///
+/// # Examples
+///
+/// ```
+/// let mut slice = ["banana", "apple", "app", "cherry"];
+///
+/// rsalgo::base::sort::msd_radix_sort(&mut slice);
+/// assert_eq!(["app", "apple", "banana", "cherry"], slice);
+/// ```
+pub fn msd_radix_sort<T: AsRef<[u8]>>(slice: &mut [T]) {
+    msd_radix_sort_by_key(slice, |x| x.as_ref())
+}
+
+/// Stable MSD radix sort by a byte string key.
+///
+/// # Examples
+///
+/// ```
+/// let mut slice = [(2, "b"), (1, "ab"), (0, "b")];
+///
+/// rsalgo::base::sort::msd_radix_sort_by_key(&mut slice, |x| x.1.as_bytes());
+/// assert_eq!([(1, "ab"), (2, "b"), (0, "b")], slice);
+/// ```
+pub fn msd_radix_sort_by_key<T, F>(slice: &mut [T], key: F)
+where
+    F: Fn(&T) -> &[u8],
+{
+    const SMALL: usize = 16;
+
+    let keys: Vec<&[u8]> = slice.iter().map(key).collect();
+    let mut order: Vec<usize> = (0..slice.len()).collect();
+    // Ranges of `order` left to sort, with the depth of the byte to bucket them by.
+    let mut stack = vec![(0, order.len(), 0)];
+    while let Some((start, end, mut depth)) = stack.pop() {
+        let order = &mut order[start..end];
+        if order.len() <= SMALL {
+            order.sort_by(|&a, &b| keys[a][depth..].cmp(&keys[b][depth..]));
+            continue;
+        }
+        // Skip the bytes shared by all keys, without bucketing them one by one.
+        let first = keys[order[0]];
+        while depth < first.len()
+            && order
+                .iter()
+                .all(|&i| keys[i].get(depth) == first.get(depth))
+        {
+            depth += 1;
+        }
+        // Bucket 0 holds the keys which end before `depth`.
+        let bucket = |i: usize| keys[i].get(depth).map_or(0, |&b| b as usize + 1);
+        let sorted = counting_order(order.to_vec(), 257, bucket);
+        order.copy_from_slice(&sorted);
+
+        let mut lo = 0;
+        while lo < order.len() {
+            let b = bucket(order[lo]);
+            let mut hi = lo + 1;
+            while hi < order.len() && bucket(order[hi]) == b {
+                hi += 1;
+            }
+            if b != 0 {
+                stack.push((start + lo, start + hi, depth + 1));
+            }
+            lo = hi;
+        }
+    }
+    apply_permutation(slice, &order);
+}
+
+/// Unstable introsort, which is a quick sort falling back to heap sort.
+///
+/// # Examples
+///
+/// ```
+/// let mut slice = [5, 2, 9, 1, 5, 6];
+///
+/// rsalgo::base::sort::intro_sort(&mut slice);
+/// assert_eq!([1, 2, 5, 5, 6, 9], slice);
+/// ```
+pub fn intro_sort<T: Ord>(slice: &mut [T]) {
+    intro_sort_by(slice, T::cmp)
+}
+
+/// Unstable introsort with a key function.
+///
+/// # Examples
+///
+/// ```
+/// let mut slice = [-5i32, 2, -1];
+///
+/// rsalgo::base::sort::intro_sort_by_key(&mut slice, |x| x.abs());
+/// assert_eq!([-1, 2, -5], slice);
+/// ```
+pub fn intro_sort_by_key<T, K, F>(slice: &mut [T], key: F)
+where
+    K: Ord,
+    F: FnMut(&T) -> K,
+{
+    let mut key = key;
+    intro_sort_by(slice, |a, b| key(a).cmp(&key(b)))
+}
+
+/// Unstable introsort with a comparator.
+///
+/// # Examples
+///
+/// ```
+/// let mut slice = [5, 2, 9, 1, 5, 6];
+///
+/// rsalgo::base::sort::intro_sort_by(&mut slice, |a, b| b.cmp(a));
+/// assert_eq!([9, 6, 5, 5, 2, 1], slice);
+/// ```
+pub fn intro_sort_by<T, F>(slice: &mut [T], compare: F)
+where
+    F: FnMut(&T, &T) -> Ordering,
+{
+    const SMALL: usize = 16;
+
+    fn insertion_sort<T, F>(slice: &mut [T], less: &mut F)
+    where
+        F: FnMut(&T, &T) -> bool,
+    {
+        for i in 1..slice.len() {
+            let mut j = i;
+            while j > 0 && less(&slice[j], &slice[j - 1]) {
+                slice.swap(j, j - 1);
+                j -= 1;
+            }
+        }
+    }
+
+    fn heap_sort<T, F>(slice: &mut [T], less: &mut F)
+    where
+        F: FnMut(&T, &T) -> bool,
+    {
+        fn sift_down<T, F>(slice: &mut [T], mut node: usize, less: &mut F)
+        where
+            F: FnMut(&T, &T) -> bool,
+        {
+            loop {
+                let mut child = 2 * node + 1;
+                if child >= slice.len() {
+                    break;
+                }
+                if child + 1 < slice.len() && less(&slice[child], &slice[child + 1]) {
+                    child += 1;
+                }
+                if !less(&slice[node], &slice[child]) {
+                    break;
+                }
+                slice.swap(node, child);
+                node = child;
+            }
+        }
+
+        for i in (0..slice.len() / 2).rev() {
+            sift_down(slice, i, less);
+        }
+        for end in (1..slice.len()).rev() {
+            slice.swap(0, end);
+            sift_down(&mut slice[..end], 0, less);
+        }
+    }
+
+    fn sort<T, F>(slice: &mut [T], limit: usize, less: &mut F)
+    where
+        F: FnMut(&T, &T) -> bool,
+    {
+        let len = slice.len();
+        if len <= SMALL {
+            insertion_sort(slice, less);
+            return;
+        }
+        if limit == 0 {
+            heap_sort(slice, less);
+            return;
+        }
+
+        // Move the median of three to the end as the pivot.
+        let (a, b, c) = (0, len / 2, len - 1);
+        if less(&slice[b], &slice[a]) {
+            slice.swap(a, b);
+        }
+        if less(&slice[c], &slice[b]) {
+            slice.swap(b, c);
+            if less(&slice[b], &slice[a]) {
+                slice.swap(a, b);
+            }
+        }
+        slice.swap(b, c);
+
+        let mut store = 0;
+        for i in 0..c {
+            if less(&slice[i], &slice[c]) {
+                slice.swap(i, store);
+                store += 1;
+            }
+        }
+        slice.swap(store, c);
+
+        let (left, right) = slice.split_at_mut(store);
+        sort(left, limit - 1, less);
+        sort(&mut right[1..], limit - 1, less);
+    }
+
+    let mut compare = compare;
+    let limit = 2 * (usize::BITS - slice.len().leading_zeros()) as usize;
+    sort(slice, limit, &mut |a, b| compare(a, b) == Ordering::Less);
+}
+
+/// Iterator merging several sorted sequences into one sorted sequence.
+///
+/// Equal items are yielded in the order of their sequences.
+pub struct KWayMerge<I: Iterator, F> {
+    sources: Vec<I>,
+    heap: Vec<(I::Item, usize)>,
+    compare: F,
+}
+
+impl<I: Iterator, F> KWayMerge<I, F>
+where
+    F: FnMut(&I::Item, &I::Item) -> Ordering,
+{
+    fn less(&mut self, a: usize, b: usize) -> bool {
+        let (x, y) = (&self.heap[a], &self.heap[b]);
+        match (self.compare)(&x.0, &y.0) {
+            Ordering::Equal => x.1 < y.1,
+            order => order == Ordering::Less,
+        }
+    }
+
+    fn sift_up(&mut self, mut node: usize) {
+        while node > 0 {
+            let parent = (node - 1) / 2;
+            if !self.less(node, parent) {
+                break;
+            }
+            self.heap.swap(node, parent);
+            node = parent;
+        }
+    }
+
+    fn sift_down(&mut self, mut node: usize) {
+        loop {
+            let mut child = 2 * node + 1;
+            if child >= self.heap.len() {
+                break;
+            }
+            if child + 1 < self.heap.len() && self.less(child + 1, child) {
+                child += 1;
+            }
+            if !self.less(child, node) {
+                break;
+            }
+            self.heap.swap(node, child);
+            node = child;
+        }
+    }
+
+    fn pull(&mut self, source: usize) {
+        if let Some(item) = self.sources[source].next() {
+            self.heap.push((item, source));
+            let last = self.heap.len() - 1;
+            self.sift_up(last);
+        }
+    }
+}
+
+impl<I: Iterator, F> Iterator for KWayMerge<I, F>
+where
+    F: FnMut(&I::Item, &I::Item) -> Ordering,
+{
+    type Item = I::Item;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.heap.is_empty() {
+            return None;
+        }
+        let (item, source) = self.heap.swap_remove(0);
+        self.sift_down(0);
+        self.pull(source);
+        Some(item)
+    }
+}
+
+/// Merge several sorted sequences.
+///
+/// # Examples
+///
+/// ```
+/// let merged: Vec<_> = rsalgo::base::sort::k_way_merge(vec![vec![1, 4], vec![2, 3], vec![0, 5]]).collect();
+///
+/// assert_eq!(vec![0, 1, 2, 3, 4, 5], merged);
+/// ```
+pub fn k_way_merge<S>(sources: S) -> impl Iterator<Item = <S::Item as IntoIterator>::Item>
+where
+    S: IntoIterator,
+    S::Item: IntoIterator,
+    <S::Item as IntoIterator>::Item: Ord,
+{
+    k_way_merge_by(sources, Ord::cmp)
+}
+
+/// Merge several sequences sorted by a comparator.
+///
+/// # Examples
+///
+/// ```
+/// let merged: Vec<_> = rsalgo::base::sort::k_way_merge_by(vec![vec![4, 1], vec![3, 2]], |a, b| b.cmp(a)).collect();
+///
+/// assert_eq!(vec![4, 3, 2, 1], merged);
+/// ```
+pub fn k_way_merge_by<S, F>(
+    sources: S,
+    compare: F,
+) -> KWayMerge<<S::Item as IntoIterator>::IntoIter, F>
+where
+    S: IntoIterator,
+    S::Item: IntoIterator,
+    F: FnMut(&<S::Item as IntoIterator>::Item, &<S::Item as IntoIterator>::Item) -> Ordering,
+{
+    let sources: Vec<_> = sources.into_iter().map(|s| s.into_iter()).collect();
+    let mut res = KWayMerge {
+        heap: Vec::with_capacity(sources.len()),
+        sources,
+        compare,
+    };
+    for source in 0..res.sources.len() {
+        res.pull(source);
+    }
+    res
+}
+
+#[cfg(test)]
+mod tests {
+    use rand::Rng;
+
+    fn random_pairs(len: usize, bound: i64) -> Vec<(i64, usize)> {
+        let mut rng = rand::thread_rng();
+        (0..len)
+            .map(|i| (rng.gen_range(-bound, bound), i))
+            .collect()
+    }
+
+    fn expected(data: &[(i64, usize)]) -> Vec<(i64, usize)> {
+        let mut res = data.to_vec();
+        res.sort_by_key(|x| x.0);
+        res
+    }
+
+    #[test]
+    fn merge_sort() {
+        for &len in &[0, 1, 2, 17, 300] {
+            let data = random_pairs(len, 20);
+
+            let mut sorted = data.clone();
+            let pairs = super::merge_sort_by_key(&mut sorted, |x| x.0);
+            assert_eq!(expected(&data), sorted);
+
+            let counts = super::inversion_counts_by(&data, |a, b| a.0.cmp(&b.0));
+            for j in 0..len {
+                let exp = (0..j).filter(|&i| data[i].0 > data[j].0).count();
+                assert_eq!(exp, counts[j]);
+            }
+            assert_eq!(counts.iter().sum::<usize>(), pairs);
+        }
+    }
+
+    #[test]
+    fn counting_sort() {
+        let data: Vec<(i64, usize)> = random_pairs(500, 30)
+            .into_iter()
+            .map(|(k, i)| (k + 30, i))
+            .collect();
+        let mut sorted = data.clone();
+        super::counting_sort_by_key(&mut sorted, |x| x.0 as usize);
+        assert_eq!(expected(&data), sorted);
+    }
+
+    #[test]
+    fn radix_sort() {
+        let mut rng = rand::thread_rng();
+        let mut data: Vec<i64> = (0..500).map(|_| rng.gen()).collect();
+        data.extend(&[i64::MIN, i64::MAX, 0, -1]);
+        let mut sorted = data.clone();
+        super::radix_sort(&mut sorted);
+        data.sort();
+        assert_eq!(data, sorted);
+
+        let data = random_pairs(500, 1000);
+        let mut sorted = data.clone();
+        super::radix_sort_by_key(&mut sorted, |x| x.0);
+        assert_eq!(expected(&data), sorted);
+
+        let mut data: Vec<u128> = (0..300).map(|_| rng.gen()).collect();
+        let mut sorted = data.clone();
+        super::radix_sort(&mut sorted);
+        data.sort();
+        assert_eq!(data, sorted);
+    }
+
+    #[test]
+    fn msd_radix_sort() {
+        let mut rng = rand::thread_rng();
+        let data: Vec<(Vec<u8>, usize)> = (0..500)
+            .map(|i| {
+                let len = rng.gen_range(0, 6);
+                ((0..len).map(|_| rng.gen_range(0, 3)).collect(), i)
+            })
+            .collect();
+        let mut sorted = data.clone();
+        super::msd_radix_sort_by_key(&mut sorted, |x| &x.0);
+        let mut exp = data.clone();
+        exp.sort_by(|a, b| a.0.cmp(&b.0));
+        assert_eq!(exp, sorted);
+    }
+
+    #[test]
+    fn msd_radix_sort_long_prefix() {
+        let mut rng = rand::thread_rng();
+        let prefix = vec![7u8; 100_000];
+        let data: Vec<(Vec<u8>, usize)> = (0..100)
+            .map(|i| {
+                let mut key = prefix[..rng.gen_range(99_998, 100_001)].to_vec();
+                key.extend((0..rng.gen_range(0, 3)).map(|_| rng.gen_range(6, 9)));
+                (key, i)
+            })
+            .collect();
+        let mut sorted = data.clone();
+        super::msd_radix_sort_by_key(&mut sorted, |x| &x.0);
+        let mut exp = data.clone();
+        exp.sort_by(|a, b| a.0.cmp(&b.0));
+        assert_eq!(exp, sorted);
+    }
+
+    #[test]
+    fn intro_sort() {
+        let mut rng = rand::thread_rng();
+        for &len in &[0, 1, 5, 16, 17, 1000] {
+            let mut data: Vec<i32> = (0..len).map(|_| rng.gen_range(-50, 50)).collect();
+            let mut sorted = data.clone();
+            super::intro_sort(&mut sorted);
+            data.sort();
+            assert_eq!(data, sorted);
+        }
+
+        let mut same = vec![1; 1000];
+        super::intro_sort(&mut same);
+        assert_eq!(vec![1; 1000], same);
+
+        let mut descending: Vec<i32> = (0..1000).rev().collect();
+        super::intro_sort(&mut descending);
+        assert_eq!((0..1000).collect::<Vec<_>>(), descending);
+    }
+
+    #[test]
+    fn k_way_merge() {
+        let mut rng = rand::thread_rng();
+        let lists: Vec<Vec<(i64, usize)>> = (0..7)
+            .map(|i| {
+                let mut list = random_pairs(rng.gen_range(0, 50), 10);
+                list.sort_by_key(|x| x.0);
+                list.into_iter().map(|(k, _)| (k, i)).collect()
+            })
+            .collect();
+        let merged: Vec<_> = super::k_way_merge_by(lists.clone(), |a, b| a.0.cmp(&b.0)).collect();
+        let all: Vec<_> = lists.into_iter().flatten().collect();
+        assert_eq!(expected(&all), merged);
+
+        let empty: Vec<Vec<i32>> = Vec::new();
+        assert_eq!(None, super::k_way_merge(empty).next());
+    }
+}
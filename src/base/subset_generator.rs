@@ -1,10 +1,12 @@
+use super::BitMask;
+
 /// Subset sequence generator
-pub struct SubsetGenerator {
-    set: usize,
-    full: usize,
+pub struct SubsetGenerator<M = usize> {
+    set: M,
+    full: M,
 }
 
-impl SubsetGenerator {
+impl<M: BitMask> SubsetGenerator<M> {
     /// Create from the fullset and the current subset.
     ///
     /// # Examples
@@ -14,7 +16,7 @@ impl SubsetGenerator {
     ///
     /// assert_eq!(Some(0b010), gen.next());
     /// ```
-    pub fn new(subset: usize, fullset: usize) -> Self {
+    pub fn new(subset: M, fullset: M) -> Self {
         SubsetGenerator {
             set: subset,
             full: fullset,
@@ -22,20 +24,186 @@ impl SubsetGenerator {
     }
 }
 
-impl Iterator for SubsetGenerator {
-    type Item = usize;
+impl<M: BitMask> Iterator for SubsetGenerator<M> {
+    type Item = M;
 
     fn next(&mut self) -> Option<Self::Item> {
-        if self.set == 0 {
+        if self.set.is_zero() {
             return None;
         }
-        self.set = (self.set - 1) & self.full;
-        Some(self.set)
+        self.set = self.set.wrapping_dec().and(&self.full);
+        Some(self.set.clone())
+    }
+}
+
+/// Generator of all supersets of a set within a universe, in descending order.
+pub struct SupersetGenerator<M = usize> {
+    set: M,
+    rest: M,
+    cur: Option<M>,
+}
+
+impl<M: BitMask> SupersetGenerator<M> {
+    /// Create from the set and the universe, `set` must be a subset of `universe`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let gen = rsalgo::base::SupersetGenerator::new(0b0010, 0b1011);
+    ///
+    /// assert_eq!(vec![0b1011, 0b1010, 0b0011, 0b0010], gen.collect::<Vec<_>>());
+    /// ```
+    pub fn new(set: M, universe: M) -> Self {
+        let rest = universe.xor(&set);
+        SupersetGenerator {
+            set,
+            cur: Some(rest.clone()),
+            rest,
+        }
+    }
+}
+
+impl<M: BitMask> Iterator for SupersetGenerator<M> {
+    type Item = M;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let cur = self.cur.take()?;
+        if !cur.is_zero() {
+            self.cur = Some(cur.wrapping_dec().and(&self.rest));
+        }
+        Some(cur.or(&self.set))
+    }
+}
+
+/// Positions of the set bits of `mask`.
+fn bit_positions<M: BitMask>(mask: &M) -> Vec<usize> {
+    let mut res = Vec::with_capacity(mask.count_ones());
+    let mut rest = mask.clone();
+    while !rest.is_zero() {
+        res.push(rest.trailing_zeros());
+        rest = rest.and(&rest.wrapping_dec());
+    }
+    res
+}
+
+/// Map the `j`-th bit of `compact` to the bit at `positions[j]`.
+fn deposit<M: BitMask>(compact: &M, positions: &[usize]) -> M {
+    let mut res = compact.zeroed();
+    let mut rest = compact.clone();
+    while !rest.is_zero() {
+        res.set_bit(positions[rest.trailing_zeros()], true);
+        rest = rest.and(&rest.wrapping_dec());
+    }
+    res
+}
+
+/// Generator of all subsets with `k` elements of a set, in ascending order by Gosper's hack.
+pub struct CombinationGenerator<M = usize> {
+    positions: Vec<usize>,
+    cur: Option<M>,
+    last: M,
+}
+
+impl<M: BitMask> CombinationGenerator<M> {
+    /// Create from the set and the number of elements to choose.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let gen = rsalgo::base::CombinationGenerator::new(0b10110, 2);
+    ///
+    /// assert_eq!(vec![0b00110, 0b10010, 0b10100], gen.collect::<Vec<_>>());
+    /// ```
+    pub fn new(set: M, k: usize) -> Self {
+        let positions = bit_positions(&set);
+        let (mut first, mut last) = (set.zeroed(), set.zeroed());
+        if k <= positions.len() {
+            for i in 0..k {
+                first.set_bit(i, true);
+                last.set_bit(positions.len() - 1 - i, true);
+            }
+        }
+        CombinationGenerator {
+            cur: if k <= positions.len() {
+                Some(first)
+            } else {
+                None
+            },
+            positions,
+            last,
+        }
+    }
+}
+
+impl<M: BitMask> Iterator for CombinationGenerator<M> {
+    type Item = M;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let cur = self.cur.take()?;
+        if cur != self.last {
+            let low = cur.xor(&cur.and(&cur.wrapping_dec()));
+            let ripple = cur.wrapping_add(&low);
+            let ones = ripple.xor(&cur).shr(cur.trailing_zeros() + 2);
+            self.cur = Some(ripple.or(&ones));
+        }
+        Some(deposit(&cur, &self.positions))
+    }
+}
+
+/// Generator of all subsets of a set in Gray code order, adjacent subsets differ in one element.
+pub struct GrayCodeGenerator<M = usize> {
+    positions: Vec<usize>,
+    cur: Option<M>,
+    odd: bool,
+}
+
+impl<M: BitMask> GrayCodeGenerator<M> {
+    /// Create from the set, starting from the empty subset.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let gen = rsalgo::base::GrayCodeGenerator::new(0b101);
+    ///
+    /// assert_eq!(vec![0b000, 0b001, 0b101, 0b100], gen.collect::<Vec<_>>());
+    /// ```
+    pub fn new(set: M) -> Self {
+        GrayCodeGenerator {
+            positions: bit_positions(&set),
+            cur: Some(set.zeroed()),
+            odd: false,
+        }
+    }
+}
+
+impl<M: BitMask> Iterator for GrayCodeGenerator<M> {
+    type Item = M;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let cur = self.cur.take()?;
+        // Flip the lowest element on odd steps, otherwise the element next to the lowest chosen one.
+        let flip = if !self.odd {
+            0
+        } else {
+            let low = cur.trailing_zeros();
+            self.positions.binary_search(&low).unwrap() + 1
+        };
+        if let Some(&pos) = self.positions.get(flip) {
+            let mut next = cur.clone();
+            next.set_bit(pos, !cur.bit(pos));
+            self.cur = Some(next);
+        }
+        self.odd = !self.odd;
+        Some(cur)
     }
 }
 
 #[cfg(test)]
 mod tests {
+    use super::{CombinationGenerator, GrayCodeGenerator, SubsetGenerator, SupersetGenerator};
+    use crate::base::BitMask;
+    use crate::ds::BitSet;
+
     #[test]
     fn next_subset() {
         let mut gen = super::SubsetGenerator::new(0b110, 0b110);
@@ -43,5 +211,108 @@ mod tests {
         assert_eq!(Some(0b010), gen.next());
         assert_eq!(Some(0b000), gen.next());
         assert_eq!(None, gen.next());
+
+        let high = 1u128 << 100 | 1 << 70;
+        let subsets: Vec<u128> = SubsetGenerator::new(high, high).collect();
+        assert_eq!(vec![1 << 100, 1 << 70, 0], subsets);
+    }
+
+    #[test]
+    fn superset() {
+        let universe = 0b1101_1010usize;
+        let set = 0b0100_0010;
+        let supersets: Vec<_> = SupersetGenerator::new(set, universe).collect();
+        assert_eq!(1 << 3, supersets.len());
+        for w in supersets.windows(2) {
+            assert!(w[0] > w[1]);
+        }
+        for s in supersets {
+            assert_eq!(set, s & set);
+            assert_eq!(s, s & universe);
+        }
+
+        assert_eq!(vec![0], SupersetGenerator::new(0, 0).collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn combination() {
+        let set = 0b1011_0111_0110u32;
+        for k in 0..=10 {
+            let combinations: Vec<_> = CombinationGenerator::new(set, k).collect();
+            let expected: Vec<_> = (0..=set)
+                .filter(|&s| s & set == s && s.count_ones() as usize == k)
+                .collect();
+            assert_eq!(expected, combinations);
+        }
+
+        assert_eq!(
+            vec![u64::MAX],
+            CombinationGenerator::new(u64::MAX, 64).collect::<Vec<_>>()
+        );
+        let top: Vec<_> = CombinationGenerator::new(u64::MAX, 63).collect();
+        assert_eq!(64, top.len());
+        assert_eq!(Some(&(u64::MAX << 1)), top.last());
+
+        let wide = 1u128 << 127 | 1 << 90 | 1 << 3;
+        assert_eq!(
+            vec![1 << 90 | 1 << 3, 1 << 127 | 1 << 3, 1 << 127 | 1 << 90],
+            CombinationGenerator::new(wide, 2).collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn gray_code() {
+        let set = 0b1101_0110u16;
+        let codes: Vec<_> = GrayCodeGenerator::new(set).collect();
+        assert_eq!(1 << 5, codes.len());
+        assert_eq!(0, codes[0]);
+        for w in codes.windows(2) {
+            assert_eq!(1, BitMask::count_ones(&(w[0] ^ w[1])));
+        }
+        let mut sorted = codes.clone();
+        sorted.sort();
+        sorted.dedup();
+        assert_eq!(codes.len(), sorted.len());
+        assert!(codes.iter().all(|&c| c & set == c));
+
+        assert_eq!(vec![0], GrayCodeGenerator::new(0u8).collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn bit_set() {
+        let mut set = BitSet::new(200);
+        for &i in &[3, 70, 150, 199] {
+            set.set(i, true);
+        }
+        let subsets: Vec<_> = SubsetGenerator::new(set.clone(), set.clone()).collect();
+        assert_eq!(15, subsets.len());
+        assert_eq!(vec![150, 199], subsets[2].ones().collect::<Vec<_>>());
+        assert!(subsets.last().unwrap().is_zero());
+
+        let universe = &set | &(&!&set.zeroed() >> 190);
+        let supersets: Vec<_> = SupersetGenerator::new(set.clone(), universe.clone()).collect();
+        assert_eq!(1 << 9, supersets.len());
+        assert!(supersets
+            .iter()
+            .all(|s| set.is_subset(s) && s.is_subset(&universe)));
+
+        let pairs: Vec<Vec<usize>> = CombinationGenerator::new(set.clone(), 2)
+            .map(|s| s.ones().collect())
+            .collect();
+        let expected = vec![
+            vec![3, 70],
+            vec![3, 150],
+            vec![70, 150],
+            vec![3, 199],
+            vec![70, 199],
+            vec![150, 199],
+        ];
+        assert_eq!(expected, pairs);
+
+        let codes: Vec<_> = GrayCodeGenerator::new(set).collect();
+        assert_eq!(1 << 4, codes.len());
+        for w in codes.windows(2) {
+            assert_eq!(1, (&w[0] ^ &w[1]).count_ones());
+        }
     }
 }
@@ -0,0 +1,526 @@
+//! Algebraic structures shared by data structures
+//!
+//! Each structure is a marker type with its operations as associated functions, so one element
+//! type can be used with different operations, such as `Sum<u32>` and `Xor<u32>`.
+
+use std::marker::PhantomData;
+
+/// Set with an associative binary operation and its identity element.
+pub trait Monoid {
+    type Item: Clone;
+
+    /// Gets the identity element.
+    fn identity() -> Self::Item;
+
+    /// Combines `a` and `b`, in the order of `a` then `b`.
+    fn op(a: &Self::Item, b: &Self::Item) -> Self::Item;
+
+    /// Combines `n` copies of `a` by binary exponentiation, which is `n * a` for sums.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rsalgo::ds::algebra::{Monoid, Product, Xor};
+    ///
+    /// assert_eq!(81, Product::<u32>::pow(&3, 4));
+    /// assert_eq!(0, Xor::<u32>::pow(&7, 4));
+    /// ```
+    fn pow(a: &Self::Item, n: usize) -> Self::Item {
+        let (mut res, mut base, mut n) = (Self::identity(), a.clone(), n);
+        while n > 0 {
+            if n & 1 == 1 {
+                res = Self::op(&res, &base);
+            }
+            base = Self::op(&base, &base);
+            n >>= 1;
+        }
+        res
+    }
+}
+
+/// Monoid whose operation is commutative.
+pub trait CommutativeMonoid: Monoid {}
+
+/// Monoid where every element has an inverse.
+pub trait Group: Monoid {
+    /// Gets the inverse of `a`.
+    fn inverse(a: &Self::Item) -> Self::Item;
+
+    /// Combines `a` and the inverse of `b`, which is `a - b` for sums.
+    fn inv_op(a: &Self::Item, b: &Self::Item) -> Self::Item {
+        Self::op(a, &Self::inverse(b))
+    }
+}
+
+/// Monoid whose operation is idempotent, `op(a, a) = a`, so overlapping ranges can be combined.
+pub trait Idempotent: Monoid {}
+
+/// Monoid of actions on another monoid `M`, where `op(f, g)` applies `f` first, and each action
+/// distributes over the operation of `M`.
+pub trait Action<M: Monoid>: Monoid {
+    /// Applies `f` to `x`, which is the result of a segment with `len` elements.
+    fn act(f: &Self::Item, x: &M::Item, len: usize) -> M::Item;
+}
+
+/// Set with two operations, where addition is a commutative monoid and multiplication is a
+/// monoid distributing over addition.
+pub trait Semiring {
+    type Item: Clone;
+
+    /// Gets the identity element of addition.
+    fn zero() -> Self::Item;
+
+    /// Gets the identity element of multiplication.
+    fn one() -> Self::Item;
+
+    /// Adds `a` and `b`.
+    fn add(a: &Self::Item, b: &Self::Item) -> Self::Item;
+
+    /// Multiplies `a` by `b`, in the order of `a` then `b`.
+    fn mul(a: &Self::Item, b: &Self::Item) -> Self::Item;
+}
+
+/// Addition, wrapping for integers.
+pub struct Sum<T>(PhantomData<T>);
+
+/// Multiplication, wrapping for integers.
+pub struct Product<T>(PhantomData<T>);
+
+/// Minimum.
+pub struct Min<T>(PhantomData<T>);
+
+/// Maximum.
+pub struct Max<T>(PhantomData<T>);
+
+/// Bitwise xor.
+pub struct Xor<T>(PhantomData<T>);
+
+/// Bitwise and.
+pub struct And<T>(PhantomData<T>);
+
+/// Bitwise or.
+pub struct Or<T>(PhantomData<T>);
+
+/// Greatest common divisor.
+pub struct Gcd<T>(PhantomData<T>);
+
+/// Minimum with its position, where `None` is the identity and the smaller position wins ties.
+pub struct ArgMin<T>(PhantomData<T>);
+
+/// Maximum with its position, where `None` is the identity and the smaller position wins ties.
+pub struct ArgMax<T>(PhantomData<T>);
+
+/// Composition of affine functions, `(a, b)` is `x -> a * x + b`, and `op(f, g)` applies `f` first.
+pub struct Affine<T>(PhantomData<T>);
+
+/// Assignment, where `None` is the identity and the later assignment wins.
+pub struct Assign<T>(PhantomData<T>);
+
+/// Ordinary addition and multiplication, wrapping for integers.
+pub struct Arithmetic<T>(PhantomData<T>);
+
+/// Tropical semiring with `min` as addition and `+` as multiplication, `T::MAX` is infinity.
+///
+/// Multiplication saturates, so sums above `T::MAX` are infinity.
+pub struct MinPlus<T>(PhantomData<T>);
+
+/// Tropical semiring with `max` as addition and `+` as multiplication, `T::MIN` is negative infinity.
+///
+/// Multiplication saturates, so sums below `T::MIN` are negative infinity.
+pub struct MaxPlus<T>(PhantomData<T>);
+
+impl<T: Clone> Affine<T> {
+    /// Applies the affine function `f` to `x`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rsalgo::ds::algebra::{Affine, Monoid};
+    ///
+    /// let f = Affine::<i64>::op(&(2, 1), &(3, 0));
+    /// assert_eq!(21, Affine::apply(&f, &3));
+    /// ```
+    pub fn apply(f: &(T, T), x: &T) -> T
+    where
+        Arithmetic<T>: Semiring<Item = T>,
+    {
+        Arithmetic::<T>::add(&Arithmetic::<T>::mul(&f.0, x), &f.1)
+    }
+}
+
+impl<T: Ord + Clone> Monoid for ArgMin<T> {
+    type Item = Option<(T, usize)>;
+    fn identity() -> Option<(T, usize)> {
+        None
+    }
+    fn op(a: &Option<(T, usize)>, b: &Option<(T, usize)>) -> Option<(T, usize)> {
+        match (a, b) {
+            (Some(x), Some(y)) => Some(x.clone().min(y.clone())),
+            _ => a.clone().or_else(|| b.clone()),
+        }
+    }
+}
+
+impl<T: Ord + Clone> CommutativeMonoid for ArgMin<T> {}
+
+impl<T: Ord + Clone> Idempotent for ArgMin<T> {}
+
+impl<T: Ord + Clone> Monoid for ArgMax<T> {
+    type Item = Option<(T, usize)>;
+    fn identity() -> Option<(T, usize)> {
+        None
+    }
+    fn op(a: &Option<(T, usize)>, b: &Option<(T, usize)>) -> Option<(T, usize)> {
+        match (a, b) {
+            (Some(x), Some(y)) if y.0 > x.0 || (y.0 == x.0 && y.1 < x.1) => b.clone(),
+            (Some(_), _) => a.clone(),
+            _ => b.clone(),
+        }
+    }
+}
+
+impl<T: Ord + Clone> CommutativeMonoid for ArgMax<T> {}
+
+impl<T: Ord + Clone> Idempotent for ArgMax<T> {}
+
+impl<T: Clone> Monoid for Assign<T> {
+    type Item = Option<T>;
+    fn identity() -> Option<T> {
+        None
+    }
+    fn op(f: &Option<T>, g: &Option<T>) -> Option<T> {
+        g.clone().or_else(|| f.clone())
+    }
+}
+
+impl<M: Monoid> Action<M> for Assign<M::Item> {
+    fn act(f: &Option<M::Item>, x: &M::Item, len: usize) -> M::Item {
+        match f {
+            Some(value) => M::pow(value, len),
+            None => x.clone(),
+        }
+    }
+}
+
+macro_rules! impl_integer {
+    ($($t:ty)*) => {$(
+        impl Monoid for Sum<$t> {
+            type Item = $t;
+            fn identity() -> $t { 0 }
+            fn op(a: &$t, b: &$t) -> $t { a.wrapping_add(*b) }
+            fn pow(a: &$t, n: usize) -> $t { a.wrapping_mul(n as $t) }
+        }
+        impl CommutativeMonoid for Sum<$t> {}
+        impl Group for Sum<$t> {
+            fn inverse(a: &$t) -> $t { a.wrapping_neg() }
+            fn inv_op(a: &$t, b: &$t) -> $t { a.wrapping_sub(*b) }
+        }
+
+        impl Monoid for Product<$t> {
+            type Item = $t;
+            fn identity() -> $t { 1 }
+            fn op(a: &$t, b: &$t) -> $t { a.wrapping_mul(*b) }
+        }
+        impl CommutativeMonoid for Product<$t> {}
+
+        impl Monoid for Min<$t> {
+            type Item = $t;
+            fn identity() -> $t { <$t>::MAX }
+            fn op(a: &$t, b: &$t) -> $t { *a.min(b) }
+        }
+        impl CommutativeMonoid for Min<$t> {}
+        impl Idempotent for Min<$t> {}
+
+        impl Monoid for Max<$t> {
+            type Item = $t;
+            fn identity() -> $t { <$t>::MIN }
+            fn op(a: &$t, b: &$t) -> $t { *a.max(b) }
+        }
+        impl CommutativeMonoid for Max<$t> {}
+        impl Idempotent for Max<$t> {}
+
+        impl Monoid for Xor<$t> {
+            type Item = $t;
+            fn identity() -> $t { 0 }
+            fn op(a: &$t, b: &$t) -> $t { a ^ b }
+        }
+        impl CommutativeMonoid for Xor<$t> {}
+        impl Group for Xor<$t> {
+            fn inverse(a: &$t) -> $t { *a }
+        }
+
+        impl Monoid for Affine<$t> {
+            type Item = ($t, $t);
+            fn identity() -> ($t, $t) { (1, 0) }
+            fn op(f: &($t, $t), g: &($t, $t)) -> ($t, $t) {
+                (f.0.wrapping_mul(g.0), f.1.wrapping_mul(g.0).wrapping_add(g.1))
+            }
+        }
+
+        impl Action<Sum<$t>> for Sum<$t> {
+            fn act(f: &$t, x: &$t, len: usize) -> $t { x.wrapping_add(f.wrapping_mul(len as $t)) }
+        }
+        impl Action<Min<$t>> for Sum<$t> {
+            fn act(f: &$t, x: &$t, _: usize) -> $t { x.wrapping_add(*f) }
+        }
+        impl Action<Max<$t>> for Sum<$t> {
+            fn act(f: &$t, x: &$t, _: usize) -> $t { x.wrapping_add(*f) }
+        }
+        impl Action<Sum<$t>> for Affine<$t> {
+            fn act(f: &($t, $t), x: &$t, len: usize) -> $t {
+                f.0.wrapping_mul(*x).wrapping_add(f.1.wrapping_mul(len as $t))
+            }
+        }
+
+        impl Semiring for Arithmetic<$t> {
+            type Item = $t;
+            fn zero() -> $t { 0 }
+            fn one() -> $t { 1 }
+            fn add(a: &$t, b: &$t) -> $t { a.wrapping_add(*b) }
+            fn mul(a: &$t, b: &$t) -> $t { a.wrapping_mul(*b) }
+        }
+
+        impl Semiring for MinPlus<$t> {
+            type Item = $t;
+            fn zero() -> $t { <$t>::MAX }
+            fn one() -> $t { 0 }
+            fn add(a: &$t, b: &$t) -> $t { *a.min(b) }
+            fn mul(a: &$t, b: &$t) -> $t {
+                if *a == <$t>::MAX || *b == <$t>::MAX { <$t>::MAX } else { a.saturating_add(*b) }
+            }
+        }
+
+        impl Semiring for MaxPlus<$t> {
+            type Item = $t;
+            fn zero() -> $t { <$t>::MIN }
+            fn one() -> $t { 0 }
+            fn add(a: &$t, b: &$t) -> $t { *a.max(b) }
+            fn mul(a: &$t, b: &$t) -> $t {
+                if *a == <$t>::MIN || *b == <$t>::MIN { <$t>::MIN } else { a.saturating_add(*b) }
+            }
+        }
+    )*};
+}
+
+impl_integer!(u8 u16 u32 u64 u128 usize i8 i16 i32 i64 i128 isize);
+
+macro_rules! impl_gcd {
+    ($($t:ty)*) => {$(
+        impl Monoid for Gcd<$t> {
+            type Item = $t;
+            fn identity() -> $t { 0 }
+            fn op(a: &$t, b: &$t) -> $t {
+                let (mut a, mut b) = (*a, *b);
+                while b != 0 {
+                    a %= b;
+                    std::mem::swap(&mut a, &mut b);
+                }
+                a
+            }
+        }
+        impl CommutativeMonoid for Gcd<$t> {}
+        impl Idempotent for Gcd<$t> {}
+    )*};
+}
+
+impl_gcd!(u8 u16 u32 u64 u128 usize);
+
+macro_rules! impl_bitwise {
+    ($($t:ty)*) => {$(
+        impl Monoid for And<$t> {
+            type Item = $t;
+            fn identity() -> $t { !<$t>::default() }
+            fn op(a: &$t, b: &$t) -> $t { a & b }
+        }
+        impl CommutativeMonoid for And<$t> {}
+        impl Idempotent for And<$t> {}
+
+        impl Monoid for Or<$t> {
+            type Item = $t;
+            fn identity() -> $t { <$t>::default() }
+            fn op(a: &$t, b: &$t) -> $t { a | b }
+        }
+        impl CommutativeMonoid for Or<$t> {}
+        impl Idempotent for Or<$t> {}
+    )*};
+}
+
+impl_bitwise!(u8 u16 u32 u64 u128 usize i8 i16 i32 i64 i128 isize bool);
+
+macro_rules! impl_float {
+    ($($t:ty)*) => {$(
+        impl Monoid for Sum<$t> {
+            type Item = $t;
+            fn identity() -> $t { 0.0 }
+            fn op(a: &$t, b: &$t) -> $t { a + b }
+            fn pow(a: &$t, n: usize) -> $t { a * n as $t }
+        }
+        impl CommutativeMonoid for Sum<$t> {}
+        impl Group for Sum<$t> {
+            fn inverse(a: &$t) -> $t { -a }
+            fn inv_op(a: &$t, b: &$t) -> $t { a - b }
+        }
+
+        impl Monoid for Product<$t> {
+            type Item = $t;
+            fn identity() -> $t { 1.0 }
+            fn op(a: &$t, b: &$t) -> $t { a * b }
+        }
+        impl CommutativeMonoid for Product<$t> {}
+
+        impl Monoid for Min<$t> {
+            type Item = $t;
+            fn identity() -> $t { <$t>::INFINITY }
+            fn op(a: &$t, b: &$t) -> $t { a.min(*b) }
+        }
+        impl CommutativeMonoid for Min<$t> {}
+        impl Idempotent for Min<$t> {}
+
+        impl Monoid for Max<$t> {
+            type Item = $t;
+            fn identity() -> $t { <$t>::NEG_INFINITY }
+            fn op(a: &$t, b: &$t) -> $t { a.max(*b) }
+        }
+        impl CommutativeMonoid for Max<$t> {}
+        impl Idempotent for Max<$t> {}
+
+        impl Monoid for Affine<$t> {
+            type Item = ($t, $t);
+            fn identity() -> ($t, $t) { (1.0, 0.0) }
+            fn op(f: &($t, $t), g: &($t, $t)) -> ($t, $t) { (f.0 * g.0, f.1 * g.0 + g.1) }
+        }
+
+        impl Action<Sum<$t>> for Sum<$t> {
+            fn act(f: &$t, x: &$t, len: usize) -> $t { x + f * len as $t }
+        }
+        impl Action<Min<$t>> for Sum<$t> {
+            fn act(f: &$t, x: &$t, _: usize) -> $t { x + f }
+        }
+        impl Action<Max<$t>> for Sum<$t> {
+            fn act(f: &$t, x: &$t, _: usize) -> $t { x + f }
+        }
+        impl Action<Sum<$t>> for Affine<$t> {
+            fn act(f: &($t, $t), x: &$t, len: usize) -> $t { f.0 * x + f.1 * len as $t }
+        }
+
+        impl Semiring for Arithmetic<$t> {
+            type Item = $t;
+            fn zero() -> $t { 0.0 }
+            fn one() -> $t { 1.0 }
+            fn add(a: &$t, b: &$t) -> $t { a + b }
+            fn mul(a: &$t, b: &$t) -> $t { a * b }
+        }
+    )*};
+}
+
+impl_float!(f32 f64);
+
+impl Monoid for Xor<bool> {
+    type Item = bool;
+    fn identity() -> bool {
+        false
+    }
+    fn op(a: &bool, b: &bool) -> bool {
+        a ^ b
+    }
+}
+
+impl CommutativeMonoid for Xor<bool> {}
+
+impl Group for Xor<bool> {
+    fn inverse(a: &bool) -> bool {
+        *a
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::Rng;
+
+    fn check_monoid<M: Monoid>(values: &[M::Item])
+    where
+        M::Item: PartialEq + std::fmt::Debug,
+    {
+        for a in values {
+            assert_eq!(*a, M::op(a, &M::identity()));
+            assert_eq!(*a, M::op(&M::identity(), a));
+            for b in values {
+                for c in values {
+                    assert_eq!(M::op(&M::op(a, b), c), M::op(a, &M::op(b, c)));
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn monoids() {
+        let mut rng = rand::thread_rng();
+        let values: Vec<i32> = (0..10).map(|_| rng.gen_range(-100, 100)).collect();
+        check_monoid::<Sum<i32>>(&values);
+        check_monoid::<Product<i32>>(&values);
+        check_monoid::<Min<i32>>(&values);
+        check_monoid::<Max<i32>>(&values);
+        check_monoid::<Xor<i32>>(&values);
+        check_monoid::<And<i32>>(&values);
+        check_monoid::<Or<i32>>(&values);
+
+        let indexed: Vec<Option<(i32, usize)>> = (0..10)
+            .map(|i| Some((rng.gen_range(-3, 3), i)))
+            .chain(Some(None))
+            .collect();
+        check_monoid::<ArgMin<i32>>(&indexed);
+        check_monoid::<ArgMax<i32>>(&indexed);
+        assert_eq!(
+            Some((2, 1)),
+            ArgMax::<i32>::op(&Some((2, 3)), &Some((2, 1)))
+        );
+
+        for a in &values {
+            let n = rng.gen_range(0, 20);
+            let repeated = (0..n).fold(Product::<i32>::identity(), |res, _| res.wrapping_mul(*a));
+            assert_eq!(repeated, Product::<i32>::pow(a, n));
+            assert_eq!(a.wrapping_mul(n as i32), Sum::<i32>::pow(a, n));
+        }
+
+        let values: Vec<u32> = (0..10).map(|_| rng.gen_range(0, 100)).collect();
+        check_monoid::<Gcd<u32>>(&values);
+        for a in &values {
+            assert_eq!(0, Sum::<u32>::inv_op(a, a));
+            assert_eq!(0, Xor::<u32>::op(a, &Xor::<u32>::inverse(a)));
+        }
+
+        let values: Vec<(i64, i64)> = (0..10)
+            .map(|_| (rng.gen_range(-10, 10), rng.gen_range(-10, 10)))
+            .collect();
+        check_monoid::<Affine<i64>>(&values);
+        for f in &values {
+            for g in &values {
+                let x = rng.gen_range(-10, 10);
+                let fg = Affine::<i64>::op(f, g);
+                assert_eq!(
+                    Affine::apply(g, &Affine::apply(f, &x)),
+                    Affine::apply(&fg, &x)
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn semirings() {
+        type T = MinPlus<i32>;
+        assert_eq!(3, T::add(&3, &T::zero()));
+        assert_eq!(i32::MAX, T::mul(&3, &T::zero()));
+        assert_eq!(7, MaxPlus::<i32>::mul(&3, &4));
+        assert_eq!(i32::MIN, MaxPlus::<i32>::mul(&3, &MaxPlus::<i32>::zero()));
+        assert_eq!(12, Arithmetic::<i32>::mul(&3, &4));
+        assert_eq!(i32::MAX, T::mul(&(i32::MAX - 1), &2));
+        assert_eq!(i32::MAX - 1, T::mul(&(i32::MAX - 3), &2));
+        assert_eq!(
+            u64::MAX,
+            MinPlus::<u64>::mul(&(u64::MAX / 2 + 1), &(u64::MAX / 2 + 1))
+        );
+        assert_eq!(i32::MIN, MaxPlus::<i32>::mul(&(i32::MIN + 1), &-2));
+        assert_eq!(i32::MIN + 1, MaxPlus::<i32>::mul(&(i32::MIN + 3), &-2));
+    }
+}
@@ -0,0 +1,559 @@
+use super::BitVector;
+use crate::base::BitMask;
+use std::iter::FromIterator;
+use std::ops::{
+    BitAnd, BitAndAssign, BitOr, BitOrAssign, BitXor, BitXorAssign, Not, Shl, ShlAssign, Shr,
+    ShrAssign,
+};
+
+/// Growable set of bits packed in words, bits beyond the length are always zero.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Default)]
+pub struct BitSet {
+    len: usize,
+    words: Vec<u64>,
+}
+
+impl BitSet {
+    /// Creates a BitSet of `len` unset bits.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rsalgo::ds::BitSet;
+    ///
+    /// let set = BitSet::new(100);
+    /// assert_eq!(0, set.count_ones());
+    /// ```
+    pub fn new(len: usize) -> Self {
+        BitSet {
+            len,
+            words: vec![0; len.div_ceil(64)],
+        }
+    }
+
+    /// Returns the number of bits.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rsalgo::ds::BitSet;
+    ///
+    /// let set = BitSet::new(100);
+    /// assert_eq!(100, set.len());
+    /// ```
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns true if there are no bits.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rsalgo::ds::BitSet;
+    ///
+    /// let set = BitSet::new(0);
+    /// assert!(set.is_empty());
+    /// ```
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Clears the bits beyond the length in the last word.
+    fn trim(&mut self) {
+        if self.len % 64 != 0 {
+            let last = self.words.len() - 1;
+            self.words[last] &= (1 << (self.len % 64)) - 1;
+        }
+    }
+
+    /// Gets the bit at `index`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rsalgo::ds::BitSet;
+    ///
+    /// let mut set = BitSet::new(100);
+    /// set.set(70, true);
+    /// assert!(set.get(70));
+    /// assert!(!set.get(71));
+    /// ```
+    pub fn get(&self, index: usize) -> bool {
+        assert!(index < self.len, "The index is out of range.");
+        self.words[index / 64] >> (index % 64) & 1 == 1
+    }
+
+    /// Sets the bit at `index` to `value`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rsalgo::ds::BitSet;
+    ///
+    /// let mut set = BitSet::new(100);
+    /// set.set(70, true);
+    /// set.set(70, false);
+    /// assert!(!set.get(70));
+    /// ```
+    pub fn set(&mut self, index: usize, value: bool) {
+        assert!(index < self.len, "The index is out of range.");
+        if value {
+            self.words[index / 64] |= 1 << (index % 64);
+        } else {
+            self.words[index / 64] &= !(1 << (index % 64));
+        }
+    }
+
+    /// Flips the bit at `index`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rsalgo::ds::BitSet;
+    ///
+    /// let mut set = BitSet::new(100);
+    /// set.flip(70);
+    /// assert!(set.get(70));
+    /// ```
+    pub fn flip(&mut self, index: usize) {
+        assert!(index < self.len, "The index is out of range.");
+        self.words[index / 64] ^= 1 << (index % 64);
+    }
+
+    /// Appends a bit to the end.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rsalgo::ds::BitSet;
+    ///
+    /// let mut set = BitSet::new(64);
+    /// set.push(true);
+    /// assert_eq!(65, set.len());
+    /// assert!(set.get(64));
+    /// ```
+    pub fn push(&mut self, value: bool) {
+        if self.len % 64 == 0 {
+            self.words.push(0);
+        }
+        self.len += 1;
+        self.set(self.len - 1, value);
+    }
+
+    /// Resizes to `len` bits, new bits are unset.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rsalgo::ds::BitSet;
+    ///
+    /// let mut set = BitSet::new(100);
+    /// set.set(70, true);
+    /// set.resize(70);
+    /// set.resize(100);
+    /// assert!(!set.get(70));
+    /// ```
+    pub fn resize(&mut self, len: usize) {
+        self.len = len;
+        self.words.resize(len.div_ceil(64), 0);
+        self.trim();
+    }
+
+    /// Gets the number of set bits.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rsalgo::ds::BitSet;
+    ///
+    /// let set: BitSet = vec![true, false, true].into_iter().collect();
+    /// assert_eq!(2, set.count_ones());
+    /// ```
+    pub fn count_ones(&self) -> usize {
+        self.words.iter().map(|&w| w.count_ones() as usize).sum()
+    }
+
+    /// Gets the position of the first set bit not before `from`, or None if there is no such bit.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rsalgo::ds::BitSet;
+    ///
+    /// let mut set = BitSet::new(200);
+    /// set.set(3, true);
+    /// set.set(150, true);
+    /// assert_eq!(Some(3), set.next_set(3));
+    /// assert_eq!(Some(150), set.next_set(4));
+    /// assert_eq!(None, set.next_set(151));
+    /// ```
+    pub fn next_set(&self, from: usize) -> Option<usize> {
+        if from >= self.len {
+            return None;
+        }
+        let mut index = from / 64;
+        let mut word = self.words[index] & !0 << (from % 64);
+        while word == 0 {
+            index += 1;
+            word = *self.words.get(index)?;
+        }
+        Some(64 * index + word.trailing_zeros() as usize)
+    }
+
+    /// Gets an iterator over the positions of the set bits in ascending order.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rsalgo::ds::BitSet;
+    ///
+    /// let set: BitSet = vec![true, false, true].into_iter().collect();
+    /// assert_eq!(vec![0, 2], set.ones().collect::<Vec<_>>());
+    /// ```
+    pub fn ones(&self) -> Ones<'_> {
+        Ones {
+            words: &self.words,
+            index: 0,
+            word: self.words.first().cloned().unwrap_or(0),
+        }
+    }
+
+    /// Returns true if every set bit of the BitSet is also set in `other`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rsalgo::ds::BitSet;
+    ///
+    /// let a: BitSet = vec![true, false, false].into_iter().collect();
+    /// let b: BitSet = vec![true, false, true].into_iter().collect();
+    /// assert!(a.is_subset(&b));
+    /// assert!(!b.is_subset(&a));
+    /// ```
+    pub fn is_subset(&self, other: &Self) -> bool {
+        assert_eq!(self.len, other.len, "The lengths are different.");
+        self.words
+            .iter()
+            .zip(&other.words)
+            .all(|(a, b)| a & !b == 0)
+    }
+
+    /// Builds a BitVector of the bits for rank and select queries.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rsalgo::ds::BitSet;
+    ///
+    /// let set: BitSet = vec![true, false, true].into_iter().collect();
+    /// let bv = set.rank_select();
+    /// assert_eq!(1, bv.rank(true, 2));
+    /// assert_eq!(Some(2), bv.select(true, 1));
+    /// ```
+    pub fn rank_select(&self) -> BitVector {
+        BitVector::from_words(self.len, self.words.clone())
+    }
+}
+
+impl FromIterator<bool> for BitSet {
+    fn from_iter<I: IntoIterator<Item = bool>>(iter: I) -> Self {
+        let mut set = BitSet::new(0);
+        for bit in iter {
+            set.push(bit);
+        }
+        set
+    }
+}
+
+/// Iterator over the positions of the set bits of a `BitSet`.
+pub struct Ones<'a> {
+    words: &'a [u64],
+    index: usize,
+    /// Remaining bits of the current word.
+    word: u64,
+}
+
+impl<'a> Iterator for Ones<'a> {
+    type Item = usize;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while self.word == 0 {
+            self.index += 1;
+            self.word = *self.words.get(self.index)?;
+        }
+        let bit = self.word.trailing_zeros() as usize;
+        self.word &= self.word - 1;
+        Some(64 * self.index + bit)
+    }
+}
+
+macro_rules! impl_bitwise_op {
+    ($($op:ident, $method:ident, $assign:ident, $assign_method:ident, $sym:tt);*) => ($(
+        impl $assign<&BitSet> for BitSet {
+            fn $assign_method(&mut self, other: &BitSet) {
+                assert_eq!(self.len, other.len, "The lengths are different.");
+                for (a, b) in self.words.iter_mut().zip(&other.words) {
+                    *a = *a $sym *b;
+                }
+            }
+        }
+
+        impl $op<&BitSet> for &BitSet {
+            type Output = BitSet;
+
+            fn $method(self, other: &BitSet) -> BitSet {
+                let mut res = self.clone();
+                res.$assign_method(other);
+                res
+            }
+        }
+    )*)
+}
+
+impl_bitwise_op! {
+    BitAnd, bitand, BitAndAssign, bitand_assign, &;
+    BitOr, bitor, BitOrAssign, bitor_assign, |;
+    BitXor, bitxor, BitXorAssign, bitxor_assign, ^
+}
+
+impl Not for &BitSet {
+    type Output = BitSet;
+
+    fn not(self) -> BitSet {
+        let mut res = BitSet {
+            len: self.len,
+            words: self.words.iter().map(|w| !w).collect(),
+        };
+        res.trim();
+        res
+    }
+}
+
+impl ShlAssign<usize> for BitSet {
+    /// Moves the bit at `i` to `i + n`, dropping the bits shifted beyond the length.
+    fn shl_assign(&mut self, n: usize) {
+        let (shift, offset) = (n / 64, n % 64);
+        for i in (0..self.words.len()).rev() {
+            self.words[i] = if i < shift {
+                0
+            } else if offset == 0 || i == shift {
+                self.words[i - shift] << offset
+            } else {
+                self.words[i - shift] << offset | self.words[i - shift - 1] >> (64 - offset)
+            };
+        }
+        self.trim();
+    }
+}
+
+impl ShrAssign<usize> for BitSet {
+    /// Moves the bit at `i` to `i - n`, dropping the bits shifted below zero.
+    fn shr_assign(&mut self, n: usize) {
+        let (shift, offset) = (n / 64, n % 64);
+        let len = self.words.len();
+        for i in 0..len {
+            self.words[i] = if i + shift >= len {
+                0
+            } else if offset == 0 || i + shift + 1 == len {
+                self.words[i + shift] >> offset
+            } else {
+                self.words[i + shift] >> offset | self.words[i + shift + 1] << (64 - offset)
+            };
+        }
+    }
+}
+
+impl Shl<usize> for &BitSet {
+    type Output = BitSet;
+
+    fn shl(self, n: usize) -> BitSet {
+        let mut res = self.clone();
+        res <<= n;
+        res
+    }
+}
+
+impl Shr<usize> for &BitSet {
+    type Output = BitSet;
+
+    fn shr(self, n: usize) -> BitSet {
+        let mut res = self.clone();
+        res >>= n;
+        res
+    }
+}
+
+/// A BitSet of fixed length works as a mask beyond `usize::BITS` for the subset generators.
+impl BitMask for BitSet {
+    fn zeroed(&self) -> Self {
+        BitSet::new(self.len)
+    }
+
+    fn is_zero(&self) -> bool {
+        self.words.iter().all(|&w| w == 0)
+    }
+
+    fn count_ones(&self) -> usize {
+        BitSet::count_ones(self)
+    }
+
+    fn trailing_zeros(&self) -> usize {
+        self.next_set(0).unwrap_or(self.len)
+    }
+
+    fn bit(&self, pos: usize) -> bool {
+        pos < self.len && self.get(pos)
+    }
+
+    fn set_bit(&mut self, pos: usize, value: bool) {
+        self.set(pos, value);
+    }
+
+    fn and(&self, other: &Self) -> Self {
+        self & other
+    }
+
+    fn or(&self, other: &Self) -> Self {
+        self | other
+    }
+
+    fn xor(&self, other: &Self) -> Self {
+        self ^ other
+    }
+
+    fn shr(&self, n: usize) -> Self {
+        self >> n
+    }
+
+    fn wrapping_add(&self, other: &Self) -> Self {
+        assert_eq!(self.len, other.len, "The lengths are different.");
+        let mut res = self.clone();
+        let mut carry = false;
+        for (a, &b) in res.words.iter_mut().zip(&other.words) {
+            let (sum, c1) = a.overflowing_add(b);
+            let (sum, c2) = sum.overflowing_add(carry as u64);
+            *a = sum;
+            carry = c1 || c2;
+        }
+        res.trim();
+        res
+    }
+
+    fn wrapping_dec(&self) -> Self {
+        let mut res = self.clone();
+        for word in res.words.iter_mut() {
+            let borrow = *word == 0;
+            *word = word.wrapping_sub(1);
+            if !borrow {
+                break;
+            }
+        }
+        res.trim();
+        res
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::BitSet;
+    use crate::base::BitMask;
+    use rand::Rng;
+
+    fn to_bools(set: &BitSet) -> Vec<bool> {
+        (0..set.len()).map(|i| set.get(i)).collect()
+    }
+
+    #[test]
+    fn bit_set() {
+        let mut rng = rand::thread_rng();
+        for &len in &[0, 1, 63, 64, 65, 200] {
+            let a: Vec<bool> = (0..len).map(|_| rng.gen_ratio(1, 3)).collect();
+            let b: Vec<bool> = (0..len).map(|_| rng.gen_ratio(1, 2)).collect();
+            let (x, y): (BitSet, BitSet) =
+                (a.iter().cloned().collect(), b.iter().cloned().collect());
+            assert_eq!(a, to_bools(&x));
+            assert_eq!(a.iter().filter(|&&v| v).count(), x.count_ones());
+
+            let and: Vec<bool> = (0..len).map(|i| a[i] & b[i]).collect();
+            let or: Vec<bool> = (0..len).map(|i| a[i] | b[i]).collect();
+            let xor: Vec<bool> = (0..len).map(|i| a[i] ^ b[i]).collect();
+            let not: Vec<bool> = a.iter().map(|v| !v).collect();
+            assert_eq!(and, to_bools(&(&x & &y)));
+            assert_eq!(or, to_bools(&(&x | &y)));
+            assert_eq!(xor, to_bools(&(&x ^ &y)));
+            assert_eq!(not, to_bools(&!&x));
+            assert_eq!(len, (!&x).count_ones() + x.count_ones());
+            assert_eq!(and == a, x.is_subset(&y));
+
+            for n in 0..=len + 1 {
+                let shl: Vec<bool> = (0..len).map(|i| i >= n && a[i - n]).collect();
+                let shr: Vec<bool> = (0..len).map(|i| i + n < len && a[i + n]).collect();
+                assert_eq!(shl, to_bools(&(&x << n)));
+                assert_eq!(shr, to_bools(&(&x >> n)));
+                assert_eq!(shl.iter().filter(|&&v| v).count(), (&x << n).count_ones());
+
+                let next = (n..len).find(|&i| a[i]);
+                assert_eq!(next, x.next_set(n));
+            }
+            let ones: Vec<usize> = (0..len).filter(|&i| a[i]).collect();
+            assert_eq!(ones, x.ones().collect::<Vec<_>>());
+
+            let bv = x.rank_select();
+            for end in 0..=len {
+                assert_eq!(a[..end].iter().filter(|&&v| v).count(), bv.rank(true, end));
+            }
+            for k in 0..=ones.len() {
+                assert_eq!(ones.get(k).cloned(), bv.select(true, k));
+            }
+        }
+    }
+
+    #[test]
+    fn growable() {
+        let mut set = BitSet::new(0);
+        let mut expected = Vec::new();
+        let mut rng = rand::thread_rng();
+        for _ in 0..1000 {
+            match rng.gen_range(0, 4) {
+                0 => {
+                    let len = rng.gen_range(0, 300);
+                    set.resize(len);
+                    expected.resize(len, false);
+                }
+                1 if !expected.is_empty() => {
+                    let i = rng.gen_range(0, expected.len());
+                    set.flip(i);
+                    expected[i] = !expected[i];
+                }
+                _ => {
+                    let value = rng.gen();
+                    set.push(value);
+                    expected.push(value);
+                }
+            }
+            assert_eq!(expected, to_bools(&set));
+            assert_eq!(expected.iter().cloned().collect::<BitSet>(), set);
+        }
+    }
+
+    #[test]
+    fn bit_mask() {
+        let mut rng = rand::thread_rng();
+        for _ in 0..200 {
+            let a: u128 = rng.gen::<u128>() >> rng.gen_range(0, 128);
+            let b: u128 = rng.gen::<u128>() >> rng.gen_range(0, 128);
+            let to_set = |v: u128| (0..100).map(|i| v >> i & 1 == 1).collect::<BitSet>();
+            let mask = (1u128 << 100) - 1;
+            let (a, b) = (a & mask, b & mask);
+            let (x, y) = (to_set(a), to_set(b));
+            assert_eq!(to_set(a.wrapping_add(b) & mask), x.wrapping_add(&y));
+            assert_eq!(to_set(a.wrapping_sub(1) & mask), x.wrapping_dec());
+            assert_eq!(
+                a.trailing_zeros().min(100) as usize,
+                BitMask::trailing_zeros(&x)
+            );
+            assert_eq!(a == 0, x.is_zero());
+        }
+    }
+}
@@ -0,0 +1,175 @@
+/// Immutable bit vector supporting rank in O(1) and select in O(log n).
+pub struct BitVector {
+    len: usize,
+    words: Vec<u64>,
+    /// Number of ones before each word.
+    ranks: Vec<usize>,
+}
+
+impl BitVector {
+    /// Creates a BitVector from `bits`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rsalgo::ds::BitVector;
+    ///
+    /// let bv = BitVector::new(&[true, false, true]);
+    /// ```
+    pub fn new(bits: &[bool]) -> Self {
+        let mut words = vec![0u64; bits.len() / 64 + 1];
+        for (i, &bit) in bits.iter().enumerate() {
+            if bit {
+                words[i / 64] |= 1 << (i % 64);
+            }
+        }
+        Self::from_words(bits.len(), words)
+    }
+
+    /// Creates a BitVector of `len` bits packed in `words`, bits beyond `len` should be zero.
+    pub(crate) fn from_words(len: usize, words: Vec<u64>) -> Self {
+        let mut words = words;
+        words.resize(len / 64 + 1, 0);
+        let mut ranks = Vec::with_capacity(words.len() + 1);
+        ranks.push(0);
+        for word in &words {
+            ranks.push(ranks[ranks.len() - 1] + word.count_ones() as usize);
+        }
+        BitVector { len, words, ranks }
+    }
+
+    /// Returns the number of bits.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rsalgo::ds::BitVector;
+    ///
+    /// let bv = BitVector::new(&[true, false, true]);
+    /// assert_eq!(3, bv.len());
+    /// ```
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns true if there are no bits.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rsalgo::ds::BitVector;
+    ///
+    /// let bv = BitVector::new(&[]);
+    /// assert!(bv.is_empty());
+    /// ```
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Gets the bit at `index`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rsalgo::ds::BitVector;
+    ///
+    /// let bv = BitVector::new(&[true, false, true]);
+    /// assert!(bv.get(0));
+    /// assert!(!bv.get(1));
+    /// ```
+    pub fn get(&self, index: usize) -> bool {
+        assert!(index < self.len, "The index is out of range.");
+        self.words[index / 64] >> (index % 64) & 1 == 1
+    }
+
+    /// Gets the number of `bit` in `[0, end)`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rsalgo::ds::BitVector;
+    ///
+    /// let bv = BitVector::new(&[true, false, true]);
+    /// assert_eq!(1, bv.rank(true, 2));
+    /// assert_eq!(1, bv.rank(false, 3));
+    /// ```
+    pub fn rank(&self, bit: bool, end: usize) -> usize {
+        assert!(end <= self.len, "The index is out of range.");
+        let mask = (1u64 << (end % 64)) - 1;
+        let ones = self.ranks[end / 64] + (self.words[end / 64] & mask).count_ones() as usize;
+        if bit {
+            ones
+        } else {
+            end - ones
+        }
+    }
+
+    /// Gets the position of the `k`-th (0-indexed) `bit`, or None if there are not so many.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rsalgo::ds::BitVector;
+    ///
+    /// let bv = BitVector::new(&[true, false, true]);
+    /// assert_eq!(Some(2), bv.select(true, 1));
+    /// assert_eq!(Some(1), bv.select(false, 0));
+    /// assert_eq!(None, bv.select(false, 1));
+    /// ```
+    pub fn select(&self, bit: bool, k: usize) -> Option<usize> {
+        if k >= self.rank(bit, self.len) {
+            return None;
+        }
+        let count = |word: usize| {
+            if bit {
+                self.ranks[word]
+            } else {
+                64 * word - self.ranks[word]
+            }
+        };
+        // The last word whose preceding count is not greater than `k`.
+        let (mut lo, mut hi) = (0, self.words.len());
+        while hi - lo > 1 {
+            let mid = lo + (hi - lo) / 2;
+            if count(mid) <= k {
+                lo = mid;
+            } else {
+                hi = mid;
+            }
+        }
+        let mut word = if bit { self.words[lo] } else { !self.words[lo] };
+        for _ in 0..k - count(lo) {
+            word &= word - 1;
+        }
+        Some(64 * lo + word.trailing_zeros() as usize)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::BitVector;
+    use rand::Rng;
+
+    #[test]
+    fn bit_vector() {
+        let mut rng = rand::thread_rng();
+        for &len in &[0, 1, 63, 64, 65, 300] {
+            let bits: Vec<bool> = (0..len).map(|_| rng.gen_ratio(1, 3)).collect();
+            let bv = BitVector::new(&bits);
+            for (i, &bit) in bits.iter().enumerate() {
+                assert_eq!(bit, bv.get(i));
+            }
+            for end in 0..=len {
+                let ones = bits[..end].iter().filter(|&&b| b).count();
+                assert_eq!(ones, bv.rank(true, end));
+                assert_eq!(end - ones, bv.rank(false, end));
+            }
+            for &bit in &[false, true] {
+                let positions: Vec<usize> = (0..len).filter(|&i| bits[i] == bit).collect();
+                for k in 0..=positions.len() {
+                    assert_eq!(positions.get(k).cloned(), bv.select(bit, k));
+                }
+            }
+        }
+    }
+}
@@ -1,9 +1,11 @@
 /// Disjoint set
 pub struct DisjointSet {
-    capacity: usize,
     size: usize,
     parent: Vec<usize>,
     rank: Option<Vec<usize>>,
+    count: Vec<usize>,
+    /// Circular linked lists of the members in each set.
+    next: Vec<usize>,
 }
 
 impl DisjointSet {
@@ -16,7 +18,6 @@ impl DisjointSet {
     /// ```
     pub fn new(capacity: usize, enable_rank: bool) -> Self {
         DisjointSet {
-            capacity,
             size: capacity,
             parent: (0..capacity).collect(),
             rank: if enable_rank {
@@ -24,6 +25,8 @@ impl DisjointSet {
             } else {
                 None
             },
+            count: vec![1; capacity],
+            next: (0..capacity).collect(),
         }
     }
 
@@ -50,7 +53,7 @@ impl DisjointSet {
     /// assert_eq!(100, s.capacity());
     /// ```
     pub fn capacity(&self) -> usize {
-        self.capacity
+        self.parent.len()
     }
 
     /// Returns true if the set contains no elements.
@@ -63,13 +66,35 @@ impl DisjointSet {
     /// assert!(s.is_empty());
     /// ```
     pub fn is_empty(&self) -> bool {
-        self.capacity == 0
+        self.parent.is_empty()
     }
 
     pub fn is_one(&self) -> bool {
         self.size == 1
     }
 
+    /// Add a new element in its own set, returns its id.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let mut s = rsalgo::ds::DisjointSet::new(2, true);
+    ///
+    /// assert_eq!(2, s.push());
+    /// assert_eq!(3, s.len());
+    /// ```
+    pub fn push(&mut self) -> usize {
+        let id = self.parent.len();
+        self.parent.push(id);
+        if let Some(rk) = &mut self.rank {
+            rk.push(0);
+        }
+        self.count.push(1);
+        self.next.push(id);
+        self.size += 1;
+        id
+    }
+
     /// Get the representation element of `id`'s set.
     ///
     /// # Examples
@@ -101,19 +126,36 @@ impl DisjointSet {
         self.find(a) == self.find(b)
     }
 
-    /// Unite `a`'s set and `b`'s set.
+    /// Get the size of `id`'s set.
     ///
     /// # Examples
     ///
     /// ```
     /// let mut s = rsalgo::ds::DisjointSet::new(100, true);
     /// s.unite(0, 1);
+    /// assert_eq!(2, s.size_of(0));
+    /// assert_eq!(1, s.size_of(2));
+    /// ```
+    pub fn size_of(&mut self, id: usize) -> usize {
+        let root = self.find(id);
+        self.count[root]
+    }
+
+    /// Unite `a`'s set and `b`'s set, returns the new representation element if two sets are merged.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let mut s = rsalgo::ds::DisjointSet::new(100, true);
+    /// let root = s.unite(0, 1).unwrap();
     /// assert_eq!(true, s.in_same(0, 1));
+    /// assert_eq!(root, s.find(1));
+    /// assert_eq!(None, s.unite(1, 0));
     /// ```
-    pub fn unite(&mut self, a: usize, b: usize) {
+    pub fn unite(&mut self, a: usize, b: usize) -> Option<usize> {
         let (mut a, mut b) = (self.find(a), self.find(b));
         if a == b {
-            return;
+            return None;
         }
         if let Some(rk) = &mut self.rank {
             if rk[a] < rk[b] {
@@ -122,7 +164,72 @@ impl DisjointSet {
             rk[a] += if rk[a] == rk[b] { 1 } else { 0 }
         }
         self.parent[b] = a;
+        self.count[a] += self.count[b];
+        self.next.swap(a, b);
         self.size -= 1;
+        Some(a)
+    }
+
+    /// Gets an iterator over the members of `id`'s set, starting from `id`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let mut s = rsalgo::ds::DisjointSet::new(100, true);
+    /// s.unite(0, 1);
+    /// s.unite(1, 5);
+    /// let mut members: Vec<usize> = s.members(5).collect();
+    /// members.sort();
+    /// assert_eq!(vec![0, 1, 5], members);
+    /// ```
+    pub fn members(&self, id: usize) -> Members<'_> {
+        Members {
+            next: &self.next,
+            start: id,
+            current: Some(id),
+        }
+    }
+
+    /// Get all sets, ordered by their smallest members which are the first ones of each set.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let mut s = rsalgo::ds::DisjointSet::new(4, true);
+    /// s.unite(3, 0);
+    /// s.unite(1, 2);
+    /// assert_eq!(vec![vec![0, 3], vec![1, 2]], s.groups());
+    /// ```
+    pub fn groups(&mut self) -> Vec<Vec<usize>> {
+        let mut index = vec![usize::MAX; self.parent.len()];
+        let mut res: Vec<Vec<usize>> = Vec::with_capacity(self.size);
+        for id in 0..self.parent.len() {
+            let root = self.find(id);
+            if index[root] == usize::MAX {
+                index[root] = res.len();
+                res.push(Vec::with_capacity(self.count[root]));
+            }
+            res[index[root]].push(id);
+        }
+        res
+    }
+}
+
+/// Iterator over the members of a set in a DisjointSet.
+pub struct Members<'a> {
+    next: &'a [usize],
+    start: usize,
+    current: Option<usize>,
+}
+
+impl<'a> Iterator for Members<'a> {
+    type Item = usize;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let id = self.current?;
+        let next = self.next[id];
+        self.current = if next == self.start { None } else { Some(next) };
+        Some(id)
     }
 }
 
@@ -155,4 +262,50 @@ mod tests {
             assert!(sb.in_same(a, b));
         }
     }
+
+    #[test]
+    fn groups() {
+        let mut rng = rand::thread_rng();
+
+        let mut s = DisjointSet::new(0, rng.gen());
+        let mut group: Vec<usize> = Vec::new();
+
+        for _ in 0..300 {
+            if group.is_empty() || rng.gen_ratio(1, 5) {
+                group.push(s.push());
+                continue;
+            }
+            let a = rng.gen_range(0, group.len());
+            let b = rng.gen_range(0, group.len());
+            let (ga, gb) = (group[a], group[b]);
+            match s.unite(a, b) {
+                Some(root) => {
+                    assert_ne!(ga, gb);
+                    assert_eq!(root, s.find(a));
+                    for g in group.iter_mut() {
+                        if *g == gb {
+                            *g = ga;
+                        }
+                    }
+                }
+                None => assert_eq!(ga, gb),
+            }
+
+            let id = rng.gen_range(0, group.len());
+            let mut members: Vec<usize> = s.members(id).collect();
+            assert_eq!(id, members[0]);
+            members.sort();
+            let expected: Vec<usize> = (0..group.len())
+                .filter(|&i| group[i] == group[id])
+                .collect();
+            assert_eq!(expected, members);
+            assert_eq!(expected.len(), s.size_of(id));
+        }
+
+        let groups = s.groups();
+        assert_eq!(s.len(), groups.len());
+        for g in groups {
+            assert!(g.iter().all(|&i| group[i] == group[g[0]]));
+        }
+    }
 }
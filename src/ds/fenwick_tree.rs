@@ -1,31 +1,60 @@
-use std::ops::{Add, Sub};
+use super::algebra::{CommutativeMonoid, Group, Sum};
+use std::marker::PhantomData;
+use std::ops::Range;
 
-// Fenwick tree for point added and prefix sum
-pub struct FenwickTree<T>
+// Fenwick tree for point added and prefix sum over a commutative monoid
+pub struct FenwickTree<T, M = Sum<T>>
 where
-    T: Copy + Add<Output = T> + Sub<Output = T>,
+    T: Clone,
+    M: CommutativeMonoid<Item = T>,
 {
-    inner: Vec<<T as Add>::Output>,
-    zero: T,
+    inner: Vec<T>,
+    monoid: PhantomData<M>,
 }
 
-impl<T> FenwickTree<T>
+impl<T, M> FenwickTree<T, M>
 where
-    T: Copy + Add<Output = T> + Sub<Output = T>,
+    T: Clone,
+    M: CommutativeMonoid<Item = T>,
 {
-    /// Creates an empty FenwickTree with `zero`.
+    /// Creates a FenwickTree filled with the identity element.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rsalgo::ds::{algebra::Xor, FenwickTree};
+    ///
+    /// let ft: FenwickTree<u32> = FenwickTree::new(10);
+    /// let xor: FenwickTree<u32, Xor<u32>> = FenwickTree::new(10);
+    /// ```
+    pub fn new(size: usize) -> Self {
+        FenwickTree {
+            inner: vec![M::identity(); size],
+            monoid: PhantomData,
+        }
+    }
+
+    /// Creates a FenwickTree from `values` in O(n).
     ///
     /// # Examples
     ///
     /// ```
     /// use rsalgo::ds::FenwickTree;
     ///
-    /// let ft: FenwickTree<u32> = FenwickTree::new(10, 0);
+    /// let ft: FenwickTree<u32> = FenwickTree::from_slice(&[1, 2, 3, 4]);
+    /// assert_eq!(6, ft.prefix_sum(3));
     /// ```
-    pub fn new(size: usize, zero: T) -> Self {
+    pub fn from_slice(values: &[T]) -> Self {
+        let mut inner = values.to_vec();
+        for pos in 1..=inner.len() {
+            let parent = pos + (1 << pos.trailing_zeros());
+            if parent <= inner.len() {
+                inner[parent - 1] = M::op(&inner[parent - 1], &inner[pos - 1]);
+            }
+        }
         FenwickTree {
-            inner: vec![zero; size],
-            zero,
+            inner,
+            monoid: PhantomData,
         }
     }
 
@@ -36,7 +65,7 @@ where
     /// ```
     /// use rsalgo::ds::FenwickTree;
     ///
-    /// let v: FenwickTree<u32> = FenwickTree::new(10, 0);
+    /// let v: FenwickTree<u32> = FenwickTree::new(10);
     /// assert_eq!(v.len(), 10);
     /// ```
     pub fn len(&self) -> usize {
@@ -50,7 +79,7 @@ where
     /// ```
     /// use rsalgo::ds::FenwickTree;
     ///
-    /// let v: FenwickTree<u32> = FenwickTree::new(0, 0);
+    /// let v: FenwickTree<u32> = FenwickTree::new(0);
     /// assert!(v.is_empty());
     /// ```
     pub fn is_empty(&self) -> bool {
@@ -64,14 +93,14 @@ where
     /// ```
     /// use rsalgo::ds::FenwickTree;
     ///
-    /// let mut v: FenwickTree<u32> = FenwickTree::new(10, 0);
+    /// let mut v: FenwickTree<u32> = FenwickTree::new(10);
     /// v.add(0, 1);
     /// assert_eq!(1, v.prefix_sum(1));
     /// ```
     pub fn add(&mut self, pos: usize, delta: T) {
         let mut pos = pos + 1;
         while pos <= self.len() {
-            self.inner[pos - 1] = self.inner[pos - 1] + delta;
+            self.inner[pos - 1] = M::op(&self.inner[pos - 1], &delta);
             pos += 1 << pos.trailing_zeros();
         }
     }
@@ -82,32 +111,340 @@ where
     ///
     /// ```
     /// use rsalgo::ds::FenwickTree;
-    /// let mut v: FenwickTree<u32> = FenwickTree::new(10, 0);
+    /// let mut v: FenwickTree<u32> = FenwickTree::new(10);
     /// v.add(0, 1);
     /// v.add(1, 2);
     /// assert_eq!(3, v.prefix_sum(2));
     /// ```
     pub fn prefix_sum(&self, end: usize) -> T {
-        let mut res = self.zero;
+        let mut res = M::identity();
         let mut end = end;
         while end != 0 {
-            res = res + self.inner[end - 1];
+            res = M::op(&res, &self.inner[end - 1]);
             end -= 1 << end.trailing_zeros();
         }
         res
     }
 }
 
+impl<T, M> FenwickTree<T, M>
+where
+    T: Clone,
+    M: CommutativeMonoid<Item = T> + Group,
+{
+    /// Gets sum of `range` in basic sequence.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rsalgo::ds::FenwickTree;
+    ///
+    /// let ft: FenwickTree<i32> = FenwickTree::from_slice(&[1, 2, 3, 4]);
+    /// assert_eq!(5, ft.range_sum(1..3));
+    /// assert_eq!(0, ft.range_sum(2..2));
+    /// ```
+    pub fn range_sum(&self, range: Range<usize>) -> T {
+        M::inv_op(&self.prefix_sum(range.end), &self.prefix_sum(range.start))
+    }
+
+    /// Set the value at the position `pos` to `value`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rsalgo::ds::FenwickTree;
+    ///
+    /// let mut ft: FenwickTree<i32> = FenwickTree::from_slice(&[1, 2, 3, 4]);
+    /// ft.set(1, 10);
+    /// assert_eq!(10, ft.range_sum(1..2));
+    /// assert_eq!(18, ft.prefix_sum(4));
+    /// ```
+    pub fn set(&mut self, pos: usize, value: T) {
+        let current = self.range_sum(pos..pos + 1);
+        self.add(pos, M::inv_op(&value, &current));
+    }
+}
+
+impl<T, M> FenwickTree<T, M>
+where
+    T: Clone + Ord,
+    M: CommutativeMonoid<Item = T>,
+{
+    /// Gets the smallest `end` such that `prefix_sum(end) >= target` in O(log n) by binary lifting,
+    /// or None if there is no such `end`. Prefix sums must be non-decreasing.
+    ///
+    /// With counts of discretized keys, it finds the `k`-th smallest key as `lower_bound(k + 1) - 1`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rsalgo::ds::FenwickTree;
+    ///
+    /// // multiset {1, 1, 3}
+    /// let ft: FenwickTree<usize> = FenwickTree::from_slice(&[0, 2, 0, 1]);
+    /// assert_eq!(Some(0), ft.lower_bound(0));
+    /// assert_eq!(Some(2), ft.lower_bound(2));
+    /// assert_eq!(Some(4), ft.lower_bound(3));
+    /// assert_eq!(None, ft.lower_bound(4));
+    /// assert_eq!(3, ft.lower_bound(2 + 1).unwrap() - 1);
+    /// ```
+    pub fn lower_bound(&self, target: T) -> Option<usize> {
+        let mut acc = M::identity();
+        if acc >= target {
+            return Some(0);
+        }
+        let mut pos = 0;
+        let mut step = if self.is_empty() {
+            0
+        } else {
+            1 << (usize::BITS - 1 - self.len().leading_zeros())
+        };
+        while step > 0 {
+            if pos + step <= self.len() {
+                let next = M::op(&acc, &self.inner[pos + step - 1]);
+                if next < target {
+                    pos += step;
+                    acc = next;
+                }
+            }
+            step >>= 1;
+        }
+        if pos < self.len() {
+            Some(pos + 1)
+        } else {
+            None
+        }
+    }
+}
+
+/// Fenwick tree for range added and point query over a commutative group
+pub struct RangeAddFenwickTree<T, G = Sum<T>>
+where
+    T: Clone,
+    G: CommutativeMonoid<Item = T> + Group,
+{
+    diff: FenwickTree<T, G>,
+}
+
+impl<T, G> RangeAddFenwickTree<T, G>
+where
+    T: Clone,
+    G: CommutativeMonoid<Item = T> + Group,
+{
+    /// Creates a RangeAddFenwickTree filled with the identity element.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rsalgo::ds::RangeAddFenwickTree;
+    ///
+    /// let ft: RangeAddFenwickTree<i32> = RangeAddFenwickTree::new(10);
+    /// ```
+    pub fn new(size: usize) -> Self {
+        RangeAddFenwickTree {
+            diff: FenwickTree::new(size),
+        }
+    }
+
+    /// Returns the length.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rsalgo::ds::RangeAddFenwickTree;
+    ///
+    /// let ft: RangeAddFenwickTree<i32> = RangeAddFenwickTree::new(10);
+    /// assert_eq!(10, ft.len());
+    /// ```
+    pub fn len(&self) -> usize {
+        self.diff.len()
+    }
+
+    /// Returns if RangeAddFenwickTree is empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rsalgo::ds::RangeAddFenwickTree;
+    ///
+    /// let ft: RangeAddFenwickTree<i32> = RangeAddFenwickTree::new(0);
+    /// assert!(ft.is_empty());
+    /// ```
+    pub fn is_empty(&self) -> bool {
+        self.diff.is_empty()
+    }
+
+    /// Add `delta` to all positions in `range`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rsalgo::ds::RangeAddFenwickTree;
+    ///
+    /// let mut ft: RangeAddFenwickTree<i32> = RangeAddFenwickTree::new(10);
+    /// ft.add(2..5, 3);
+    /// assert_eq!(3, ft.get(4));
+    /// assert_eq!(0, ft.get(5));
+    /// ```
+    pub fn add(&mut self, range: Range<usize>, delta: T) {
+        if range.start >= range.end {
+            return;
+        }
+        self.diff.add(range.start, delta.clone());
+        if range.end < self.len() {
+            self.diff.add(range.end, G::inverse(&delta));
+        }
+    }
+
+    /// Gets the value at the position `pos`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rsalgo::ds::RangeAddFenwickTree;
+    ///
+    /// let mut ft: RangeAddFenwickTree<i32> = RangeAddFenwickTree::new(10);
+    /// ft.add(0..10, 1);
+    /// ft.add(3..4, 2);
+    /// assert_eq!(3, ft.get(3));
+    /// ```
+    pub fn get(&self, pos: usize) -> T {
+        self.diff.prefix_sum(pos + 1)
+    }
+}
+
+/// Fenwick tree for range added and range sum over a commutative group
+pub struct RangeSumFenwickTree<T, G = Sum<T>>
+where
+    T: Clone,
+    G: CommutativeMonoid<Item = T> + Group,
+{
+    /// Differences `d[i]`.
+    diff: FenwickTree<T, G>,
+    /// `i * d[i]`.
+    weighted: FenwickTree<T, G>,
+}
+
+impl<T, G> RangeSumFenwickTree<T, G>
+where
+    T: Clone,
+    G: CommutativeMonoid<Item = T> + Group,
+{
+    /// Creates a RangeSumFenwickTree filled with the identity element.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rsalgo::ds::RangeSumFenwickTree;
+    ///
+    /// let ft: RangeSumFenwickTree<i64> = RangeSumFenwickTree::new(10);
+    /// ```
+    pub fn new(size: usize) -> Self {
+        RangeSumFenwickTree {
+            diff: FenwickTree::new(size + 1),
+            weighted: FenwickTree::new(size + 1),
+        }
+    }
+
+    /// Returns the length.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rsalgo::ds::RangeSumFenwickTree;
+    ///
+    /// let ft: RangeSumFenwickTree<i64> = RangeSumFenwickTree::new(10);
+    /// assert_eq!(10, ft.len());
+    /// ```
+    pub fn len(&self) -> usize {
+        self.diff.len() - 1
+    }
+
+    /// Returns if RangeSumFenwickTree is empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rsalgo::ds::RangeSumFenwickTree;
+    ///
+    /// let ft: RangeSumFenwickTree<i64> = RangeSumFenwickTree::new(0);
+    /// assert!(ft.is_empty());
+    /// ```
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    fn add_diff(&mut self, pos: usize, delta: T) {
+        self.weighted.add(pos, G::pow(&delta, pos));
+        self.diff.add(pos, delta);
+    }
+
+    /// Add `delta` to all positions in `range`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rsalgo::ds::RangeSumFenwickTree;
+    ///
+    /// let mut ft: RangeSumFenwickTree<i64> = RangeSumFenwickTree::new(10);
+    /// ft.add(2..5, 3);
+    /// assert_eq!(6, ft.range_sum(3..7));
+    /// ```
+    pub fn add(&mut self, range: Range<usize>, delta: T) {
+        if range.start >= range.end {
+            return;
+        }
+        self.add_diff(range.start, delta.clone());
+        self.add_diff(range.end, G::inverse(&delta));
+    }
+
+    /// Gets sum of `[0, end)`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rsalgo::ds::RangeSumFenwickTree;
+    ///
+    /// let mut ft: RangeSumFenwickTree<i64> = RangeSumFenwickTree::new(10);
+    /// ft.add(0..10, 2);
+    /// assert_eq!(8, ft.prefix_sum(4));
+    /// ```
+    pub fn prefix_sum(&self, end: usize) -> T {
+        // sum of d[i] * (end - i) for i < end
+        G::inv_op(
+            &G::pow(&self.diff.prefix_sum(end), end),
+            &self.weighted.prefix_sum(end),
+        )
+    }
+
+    /// Gets sum of `range`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rsalgo::ds::RangeSumFenwickTree;
+    ///
+    /// let mut ft: RangeSumFenwickTree<i64> = RangeSumFenwickTree::new(10);
+    /// ft.add(0..5, 1);
+    /// ft.add(3..8, 2);
+    /// assert_eq!(7, ft.range_sum(2..5));
+    /// ```
+    pub fn range_sum(&self, range: Range<usize>) -> T {
+        G::inv_op(&self.prefix_sum(range.end), &self.prefix_sum(range.start))
+    }
+}
+
 #[cfg(test)]
 mod tests {
-    use super::FenwickTree;
+    use super::{FenwickTree, RangeAddFenwickTree, RangeSumFenwickTree};
+    use crate::ds::algebra::{Max, Xor};
     use rand::Rng;
 
     #[test]
     fn m1_qp() {
         const LEN: usize = 100;
         let mut source: Vec<usize> = vec![0; LEN];
-        let mut ft = FenwickTree::new(LEN, 0);
+        let mut ft: FenwickTree<usize> = FenwickTree::new(LEN);
 
         let mut rng = rand::thread_rng();
 
@@ -119,12 +456,101 @@ mod tests {
                 ft.add(pos, delta);
             } else {
                 let pos = rng.gen_range(0, LEN);
-                let mut exp = 0;
-                for i in 0..pos {
-                    exp += source[i]
-                }
+                let exp: usize = source[..pos].iter().sum();
+                assert_eq!(exp, ft.prefix_sum(pos));
+            }
+        }
+    }
+
+    #[test]
+    fn prefix_max() {
+        const LEN: usize = 100;
+        let mut source: Vec<i32> = vec![i32::MIN; LEN];
+        let mut ft: FenwickTree<i32, Max<i32>> = FenwickTree::new(LEN);
+
+        let mut rng = rand::thread_rng();
+
+        for _ in 1..1000 {
+            let pos = rng.gen_range(0, LEN);
+            if rng.gen_ratio(2, 3) {
+                let value = rng.gen_range(-1000, 1000);
+                source[pos] = source[pos].max(value);
+                ft.add(pos, value);
+            } else {
+                let exp = source[..pos].iter().cloned().max().unwrap_or(i32::MIN);
                 assert_eq!(exp, ft.prefix_sum(pos));
             }
         }
     }
+
+    #[test]
+    fn set_range_sum_lower_bound() {
+        const LEN: usize = 100;
+        let mut rng = rand::thread_rng();
+        let mut source: Vec<usize> = (0..LEN).map(|_| rng.gen_range(0, 5)).collect();
+        let mut ft: FenwickTree<usize> = FenwickTree::from_slice(&source);
+
+        for _ in 1..1000 {
+            let pos = rng.gen_range(0, LEN);
+            let value = rng.gen_range(0, 5);
+            source[pos] = value;
+            ft.set(pos, value);
+
+            let l = rng.gen_range(0, LEN + 1);
+            let r = rng.gen_range(l, LEN + 1);
+            assert_eq!(source[l..r].iter().sum::<usize>(), ft.range_sum(l..r));
+
+            let target = rng.gen_range(0, 250);
+            let expected = (0..=LEN).find(|&end| source[..end].iter().sum::<usize>() >= target);
+            assert_eq!(expected, ft.lower_bound(target));
+        }
+    }
+
+    #[test]
+    fn range_add() {
+        const LEN: usize = 50;
+        let mut rng = rand::thread_rng();
+        let mut source: Vec<i64> = vec![0; LEN];
+        let mut point: RangeAddFenwickTree<i64> = RangeAddFenwickTree::new(LEN);
+        let mut range: RangeSumFenwickTree<i64> = RangeSumFenwickTree::new(LEN);
+
+        for _ in 1..1000 {
+            let l = rng.gen_range(0, LEN + 1);
+            let r = rng.gen_range(l, LEN + 1);
+            if rng.gen_ratio(1, 2) {
+                let delta = rng.gen_range(-100, 100);
+                source[l..r].iter_mut().for_each(|x| *x += delta);
+                point.add(l..r, delta);
+                range.add(l..r, delta);
+            } else {
+                assert_eq!(source[l..r].iter().sum::<i64>(), range.range_sum(l..r));
+                if l < LEN {
+                    assert_eq!(source[l], point.get(l));
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn range_xor() {
+        const LEN: usize = 50;
+        let mut rng = rand::thread_rng();
+        let mut source: Vec<u32> = vec![0; LEN];
+        let mut ft: RangeSumFenwickTree<u32, Xor<u32>> = RangeSumFenwickTree::new(LEN);
+
+        for _ in 1..1000 {
+            let l = rng.gen_range(0, LEN + 1);
+            let r = rng.gen_range(l, LEN + 1);
+            if rng.gen_ratio(1, 2) {
+                let delta = rng.gen();
+                source[l..r].iter_mut().for_each(|x| *x ^= delta);
+                ft.add(l..r, delta);
+            } else {
+                assert_eq!(
+                    source[l..r].iter().fold(0, |a, b| a ^ b),
+                    ft.range_sum(l..r)
+                );
+            }
+        }
+    }
 }
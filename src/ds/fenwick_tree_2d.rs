@@ -0,0 +1,183 @@
+use super::algebra::{CommutativeMonoid, Group, Sum};
+use std::marker::PhantomData;
+use std::ops::Range;
+
+// 2D Fenwick tree for point added and rectangle sum over a commutative monoid
+pub struct FenwickTree2D<T, M = Sum<T>>
+where
+    T: Clone,
+    M: CommutativeMonoid<Item = T>,
+{
+    rows: usize,
+    cols: usize,
+    inner: Vec<T>,
+    monoid: PhantomData<M>,
+}
+
+impl<T, M> FenwickTree2D<T, M>
+where
+    T: Clone,
+    M: CommutativeMonoid<Item = T>,
+{
+    /// Creates a FenwickTree2D of `rows * cols` identity elements.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rsalgo::ds::FenwickTree2D;
+    ///
+    /// let ft: FenwickTree2D<u32> = FenwickTree2D::new(3, 4);
+    /// ```
+    pub fn new(rows: usize, cols: usize) -> Self {
+        FenwickTree2D {
+            rows,
+            cols,
+            inner: vec![M::identity(); rows * cols],
+            monoid: PhantomData,
+        }
+    }
+
+    /// Returns the number of rows.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rsalgo::ds::FenwickTree2D;
+    ///
+    /// let ft: FenwickTree2D<u32> = FenwickTree2D::new(3, 4);
+    /// assert_eq!(3, ft.rows());
+    /// ```
+    pub fn rows(&self) -> usize {
+        self.rows
+    }
+
+    /// Returns the number of columns.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rsalgo::ds::FenwickTree2D;
+    ///
+    /// let ft: FenwickTree2D<u32> = FenwickTree2D::new(3, 4);
+    /// assert_eq!(4, ft.cols());
+    /// ```
+    pub fn cols(&self) -> usize {
+        self.cols
+    }
+
+    /// Add `delta` to the position `(row, col)`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rsalgo::ds::FenwickTree2D;
+    ///
+    /// let mut ft: FenwickTree2D<u32> = FenwickTree2D::new(3, 4);
+    /// ft.add(1, 2, 5);
+    /// assert_eq!(5, ft.prefix_sum(2, 3));
+    /// assert_eq!(0, ft.prefix_sum(2, 2));
+    /// ```
+    pub fn add(&mut self, row: usize, col: usize, delta: T) {
+        let mut i = row + 1;
+        while i <= self.rows {
+            let mut j = col + 1;
+            while j <= self.cols {
+                let pos = (i - 1) * self.cols + j - 1;
+                self.inner[pos] = M::op(&self.inner[pos], &delta);
+                j += 1 << j.trailing_zeros();
+            }
+            i += 1 << i.trailing_zeros();
+        }
+    }
+
+    /// Gets sum of the rectangle `[0, row_end) * [0, col_end)`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rsalgo::ds::FenwickTree2D;
+    ///
+    /// let mut ft: FenwickTree2D<u32> = FenwickTree2D::new(3, 4);
+    /// ft.add(0, 0, 1);
+    /// ft.add(2, 3, 2);
+    /// assert_eq!(3, ft.prefix_sum(3, 4));
+    /// ```
+    pub fn prefix_sum(&self, row_end: usize, col_end: usize) -> T {
+        let mut res = M::identity();
+        let mut i = row_end;
+        while i != 0 {
+            let mut j = col_end;
+            while j != 0 {
+                res = M::op(&res, &self.inner[(i - 1) * self.cols + j - 1]);
+                j -= 1 << j.trailing_zeros();
+            }
+            i -= 1 << i.trailing_zeros();
+        }
+        res
+    }
+}
+
+impl<T, M> FenwickTree2D<T, M>
+where
+    T: Clone,
+    M: CommutativeMonoid<Item = T> + Group,
+{
+    /// Gets sum of the rectangle of `rows` and `cols`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rsalgo::ds::FenwickTree2D;
+    ///
+    /// let mut ft: FenwickTree2D<i32> = FenwickTree2D::new(3, 4);
+    /// ft.add(0, 0, 1);
+    /// ft.add(1, 1, 2);
+    /// ft.add(2, 3, 4);
+    /// assert_eq!(6, ft.sum(1..3, 1..4));
+    /// ```
+    pub fn sum(&self, rows: Range<usize>, cols: Range<usize>) -> T {
+        let outer = M::inv_op(
+            &self.prefix_sum(rows.end, cols.end),
+            &self.prefix_sum(rows.start, cols.end),
+        );
+        let inner = M::inv_op(
+            &self.prefix_sum(rows.end, cols.start),
+            &self.prefix_sum(rows.start, cols.start),
+        );
+        M::inv_op(&outer, &inner)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::FenwickTree2D;
+    use rand::Rng;
+
+    #[test]
+    fn rectangle_sum() {
+        const ROWS: usize = 12;
+        const COLS: usize = 9;
+        let mut rng = rand::thread_rng();
+        let mut source = [[0i64; COLS]; ROWS];
+        let mut ft: FenwickTree2D<i64> = FenwickTree2D::new(ROWS, COLS);
+
+        for _ in 0..1000 {
+            if rng.gen_ratio(1, 2) {
+                let (row, col) = (rng.gen_range(0, ROWS), rng.gen_range(0, COLS));
+                let delta = rng.gen_range(-100, 100);
+                source[row][col] += delta;
+                ft.add(row, col, delta);
+            } else {
+                let r0 = rng.gen_range(0, ROWS + 1);
+                let r1 = rng.gen_range(r0, ROWS + 1);
+                let c0 = rng.gen_range(0, COLS + 1);
+                let c1 = rng.gen_range(c0, COLS + 1);
+                let expected: i64 = source[r0..r1]
+                    .iter()
+                    .map(|row| row[c0..c1].iter().sum::<i64>())
+                    .sum();
+                assert_eq!(expected, ft.sum(r0..r1, c0..c1));
+            }
+        }
+    }
+}
@@ -0,0 +1,491 @@
+use super::algebra::{Monoid, Sum};
+use std::ops::Range;
+
+type Link<T> = Option<Box<Node<T>>>;
+
+struct Node<T> {
+    value: T,
+    /// Result of the subtree from left to right.
+    sum: T,
+    /// Result of the subtree from right to left.
+    rev_sum: T,
+    priority: u64,
+    size: usize,
+    /// The children should be swapped and reversed, `sum` and `rev_sum` are already swapped.
+    reversed: bool,
+    left: Link<T>,
+    right: Link<T>,
+}
+
+fn size<T>(link: &Link<T>) -> usize {
+    link.as_ref().map_or(0, |node| node.size)
+}
+
+fn reverse<T>(link: &mut Link<T>) {
+    if let Some(node) = link {
+        node.reversed = !node.reversed;
+        std::mem::swap(&mut node.sum, &mut node.rev_sum);
+    }
+}
+
+fn push_down<T>(node: &mut Node<T>) {
+    if node.reversed {
+        node.reversed = false;
+        std::mem::swap(&mut node.left, &mut node.right);
+        reverse(&mut node.left);
+        reverse(&mut node.right);
+    }
+}
+
+fn update<T: Clone, M: Monoid<Item = T>>(node: &mut Node<T>) {
+    node.size = size(&node.left) + 1 + size(&node.right);
+    let (mut sum, mut rev_sum) = (node.value.clone(), node.value.clone());
+    if let Some(left) = &node.left {
+        sum = M::op(&left.sum, &sum);
+        rev_sum = M::op(&rev_sum, &left.rev_sum);
+    }
+    if let Some(right) = &node.right {
+        sum = M::op(&sum, &right.sum);
+        rev_sum = M::op(&right.rev_sum, &rev_sum);
+    }
+    node.sum = sum;
+    node.rev_sum = rev_sum;
+}
+
+/// Sequence on a treap keyed by position, supporting insertion, removal, reversal and range
+/// queries of a monoid in expected O(log n).
+pub struct ImplicitTreap<T, M = Sum<T>>
+where
+    T: Clone,
+    M: Monoid<Item = T>,
+{
+    root: Link<T>,
+    monoid: std::marker::PhantomData<M>,
+}
+
+impl<T, M> Default for ImplicitTreap<T, M>
+where
+    T: Clone,
+    M: Monoid<Item = T>,
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T, M> ImplicitTreap<T, M>
+where
+    T: Clone,
+    M: Monoid<Item = T>,
+{
+    /// Creates an empty ImplicitTreap.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rsalgo::ds::ImplicitTreap;
+    ///
+    /// let seq: ImplicitTreap<i32> = ImplicitTreap::new();
+    /// ```
+    pub fn new() -> Self {
+        ImplicitTreap {
+            root: None,
+            monoid: std::marker::PhantomData,
+        }
+    }
+
+    fn merge(a: Link<T>, b: Link<T>) -> Link<T> {
+        match (a, b) {
+            (None, b) => b,
+            (a, None) => a,
+            (Some(mut a), Some(mut b)) => {
+                if a.priority > b.priority {
+                    push_down(&mut a);
+                    a.right = Self::merge(a.right.take(), Some(b));
+                    update::<T, M>(&mut a);
+                    Some(a)
+                } else {
+                    push_down(&mut b);
+                    b.left = Self::merge(Some(a), b.left.take());
+                    update::<T, M>(&mut b);
+                    Some(b)
+                }
+            }
+        }
+    }
+
+    fn split(link: Link<T>, index: usize) -> (Link<T>, Link<T>) {
+        match link {
+            None => (None, None),
+            Some(mut node) => {
+                push_down(&mut node);
+                let left_size = size(&node.left);
+                if index <= left_size {
+                    let (left, right) = Self::split(node.left.take(), index);
+                    node.left = right;
+                    update::<T, M>(&mut node);
+                    (left, Some(node))
+                } else {
+                    let (left, right) = Self::split(node.right.take(), index - left_size - 1);
+                    node.right = left;
+                    update::<T, M>(&mut node);
+                    (Some(node), right)
+                }
+            }
+        }
+    }
+
+    /// Splits out `range`, calls `f` on it and merges the parts back.
+    fn with_range<R, F: FnOnce(&mut Link<T>) -> R>(&mut self, range: Range<usize>, f: F) -> R {
+        assert!(
+            range.start <= range.end && range.end <= self.len(),
+            "The range is invalid."
+        );
+        let (left, right) = Self::split(self.root.take(), range.end);
+        let (left, mut mid) = Self::split(left, range.start);
+        let res = f(&mut mid);
+        self.root = Self::merge(Self::merge(left, mid), right);
+        res
+    }
+
+    /// Returns the number of elements.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rsalgo::ds::ImplicitTreap;
+    ///
+    /// let mut seq: ImplicitTreap<i32> = ImplicitTreap::new();
+    /// seq.push(1);
+    /// assert_eq!(1, seq.len());
+    /// ```
+    pub fn len(&self) -> usize {
+        size(&self.root)
+    }
+
+    /// Returns true if the ImplicitTreap contains no elements.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rsalgo::ds::ImplicitTreap;
+    ///
+    /// let seq: ImplicitTreap<i32> = ImplicitTreap::new();
+    /// assert!(seq.is_empty());
+    /// ```
+    pub fn is_empty(&self) -> bool {
+        self.root.is_none()
+    }
+
+    /// Inserts `value` at position `index`, shifting the elements after it.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rsalgo::ds::ImplicitTreap;
+    ///
+    /// let mut seq: ImplicitTreap<i32> = ImplicitTreap::new();
+    /// seq.insert(0, 1);
+    /// seq.insert(0, 2);
+    /// seq.insert(1, 3);
+    /// assert_eq!(vec![&2, &3, &1], seq.iter().collect::<Vec<_>>());
+    /// ```
+    pub fn insert(&mut self, index: usize, value: T) {
+        assert!(index <= self.len(), "The index is out of range.");
+        let node = Some(Box::new(Node {
+            value: value.clone(),
+            sum: value.clone(),
+            rev_sum: value,
+            priority: rand::random(),
+            size: 1,
+            reversed: false,
+            left: None,
+            right: None,
+        }));
+        let (left, right) = Self::split(self.root.take(), index);
+        self.root = Self::merge(Self::merge(left, node), right);
+    }
+
+    /// Appends `value` to the back.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rsalgo::ds::ImplicitTreap;
+    ///
+    /// let mut seq: ImplicitTreap<i32> = ImplicitTreap::new();
+    /// seq.push(1);
+    /// seq.push(2);
+    /// assert_eq!(Some(&2), seq.get(1));
+    /// ```
+    pub fn push(&mut self, value: T) {
+        self.insert(self.len(), value)
+    }
+
+    /// Removes and returns the element at position `index`, shifting the elements after it.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rsalgo::ds::ImplicitTreap;
+    ///
+    /// let mut seq: ImplicitTreap<i32> = ImplicitTreap::new();
+    /// seq.push(1);
+    /// seq.push(2);
+    /// assert_eq!(1, seq.remove(0));
+    /// assert_eq!(Some(&2), seq.get(0));
+    /// ```
+    pub fn remove(&mut self, index: usize) -> T {
+        assert!(index < self.len(), "The index is out of range.");
+        let (left, right) = Self::split(self.root.take(), index + 1);
+        let (left, mid) = Self::split(left, index);
+        self.root = Self::merge(left, right);
+        mid.unwrap().value
+    }
+
+    /// Returns a reference to the element at position `index`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rsalgo::ds::ImplicitTreap;
+    ///
+    /// let mut seq: ImplicitTreap<i32> = ImplicitTreap::new();
+    /// seq.push(1);
+    /// assert_eq!(Some(&1), seq.get(0));
+    /// assert_eq!(None, seq.get(1));
+    /// ```
+    pub fn get(&self, index: usize) -> Option<&T> {
+        let (mut link, mut index, mut reversed) = (&self.root, index, false);
+        while let Some(node) = link {
+            reversed ^= node.reversed;
+            let (left, right) = if reversed {
+                (&node.right, &node.left)
+            } else {
+                (&node.left, &node.right)
+            };
+            let left_size = size(left);
+            if index < left_size {
+                link = left;
+            } else if index == left_size {
+                return Some(&node.value);
+            } else {
+                index -= left_size + 1;
+                link = right;
+            }
+        }
+        None
+    }
+
+    /// Replaces the element at position `index` with `value`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rsalgo::ds::ImplicitTreap;
+    ///
+    /// let mut seq: ImplicitTreap<i32> = ImplicitTreap::new();
+    /// seq.push(1);
+    /// seq.push(2);
+    /// seq.set(0, 5);
+    /// assert_eq!(7, seq.query(0..2));
+    /// ```
+    pub fn set(&mut self, index: usize, value: T) {
+        assert!(index < self.len(), "The index is out of range.");
+        self.with_range(index..index + 1, |mid| {
+            let node = mid.as_mut().unwrap();
+            node.value = value;
+            update::<T, M>(node);
+        })
+    }
+
+    /// Reverses the elements in `range`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rsalgo::ds::ImplicitTreap;
+    ///
+    /// let mut seq: ImplicitTreap<i32> = ImplicitTreap::new();
+    /// for i in 0..5 {
+    ///     seq.push(i);
+    /// }
+    /// seq.reverse(1..4);
+    /// assert_eq!(vec![&0, &3, &2, &1, &4], seq.iter().collect::<Vec<_>>());
+    /// ```
+    pub fn reverse(&mut self, range: Range<usize>) {
+        self.with_range(range, reverse)
+    }
+
+    /// Gets the result of the elements in `range` from left to right, the identity for an empty
+    /// range.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rsalgo::ds::ImplicitTreap;
+    ///
+    /// let mut seq: ImplicitTreap<i32> = ImplicitTreap::new();
+    /// for i in 0..5 {
+    ///     seq.push(i);
+    /// }
+    /// assert_eq!(6, seq.query(1..4));
+    /// assert_eq!(0, seq.query(2..2));
+    /// ```
+    pub fn query(&mut self, range: Range<usize>) -> T {
+        self.with_range(range, |mid| {
+            mid.as_ref()
+                .map_or_else(M::identity, |node| node.sum.clone())
+        })
+    }
+
+    /// Splits the ImplicitTreap at position `index`, returns the elements from the `index`-th one.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rsalgo::ds::ImplicitTreap;
+    ///
+    /// let mut seq: ImplicitTreap<i32> = ImplicitTreap::new();
+    /// seq.push(1);
+    /// seq.push(2);
+    /// let right = seq.split_off(1);
+    /// assert_eq!(Some(&2), right.get(0));
+    /// assert_eq!(1, seq.len());
+    /// ```
+    pub fn split_off(&mut self, index: usize) -> Self {
+        assert!(index <= self.len(), "The index is out of range.");
+        let (left, right) = Self::split(self.root.take(), index);
+        self.root = left;
+        ImplicitTreap {
+            root: right,
+            monoid: std::marker::PhantomData,
+        }
+    }
+
+    /// Moves all elements of `other` to the back.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rsalgo::ds::ImplicitTreap;
+    ///
+    /// let mut seq: ImplicitTreap<i32> = ImplicitTreap::new();
+    /// seq.push(1);
+    /// let mut other = ImplicitTreap::new();
+    /// other.push(2);
+    /// seq.append(&mut other);
+    /// assert_eq!(vec![&1, &2], seq.iter().collect::<Vec<_>>());
+    /// ```
+    pub fn append(&mut self, other: &mut Self) {
+        self.root = Self::merge(self.root.take(), other.root.take());
+    }
+
+    /// Gets an iterator over the elements from left to right.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rsalgo::ds::ImplicitTreap;
+    ///
+    /// let mut seq: ImplicitTreap<i32> = ImplicitTreap::new();
+    /// seq.push(1);
+    /// seq.push(2);
+    /// assert_eq!(vec![&1, &2], seq.iter().collect::<Vec<_>>());
+    /// ```
+    pub fn iter(&self) -> Iter<'_, T> {
+        let mut iter = Iter { stack: Vec::new() };
+        iter.push_left(&self.root, false);
+        iter
+    }
+}
+
+/// Iterator over the elements of an ImplicitTreap from left to right.
+pub struct Iter<'a, T> {
+    /// Nodes with whether their ancestors reverse them.
+    stack: Vec<(&'a Node<T>, bool)>,
+}
+
+impl<'a, T> Iter<'a, T> {
+    fn push_left(&mut self, link: &'a Link<T>, reversed: bool) {
+        let (mut link, mut reversed) = (link, reversed);
+        while let Some(node) = link {
+            self.stack.push((node, reversed));
+            reversed ^= node.reversed;
+            link = if reversed { &node.right } else { &node.left };
+        }
+    }
+}
+
+impl<'a, T> Iterator for Iter<'a, T> {
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<&'a T> {
+        let (node, reversed) = self.stack.pop()?;
+        let reversed = reversed ^ node.reversed;
+        self.push_left(if reversed { &node.left } else { &node.right }, reversed);
+        Some(&node.value)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::ImplicitTreap;
+    use crate::ds::algebra::{Affine, Monoid};
+    use rand::Rng;
+
+    #[test]
+    fn implicit_treap() {
+        let mut rng = rand::thread_rng();
+        let mut seq: ImplicitTreap<(i64, i64), Affine<i64>> = ImplicitTreap::new();
+        let mut expected: Vec<(i64, i64)> = Vec::new();
+
+        for _ in 0..2000 {
+            let len = expected.len();
+            match rng.gen_range(0, 6) {
+                0 | 1 => {
+                    let index = rng.gen_range(0, len + 1);
+                    let value = (rng.gen_range(-2, 3), rng.gen_range(-5, 5));
+                    seq.insert(index, value);
+                    expected.insert(index, value);
+                }
+                2 if len > 0 => {
+                    let index = rng.gen_range(0, len);
+                    assert_eq!(expected.remove(index), seq.remove(index));
+                }
+                3 if len > 0 => {
+                    let index = rng.gen_range(0, len);
+                    let value = (rng.gen_range(-2, 3), rng.gen_range(-5, 5));
+                    seq.set(index, value);
+                    expected[index] = value;
+                }
+                4 => {
+                    let l = rng.gen_range(0, len + 1);
+                    let r = rng.gen_range(l, len + 1);
+                    seq.reverse(l..r);
+                    expected[l..r].reverse();
+                }
+                _ => {
+                    let l = rng.gen_range(0, len + 1);
+                    let r = rng.gen_range(l, len + 1);
+                    let product = expected[l..r]
+                        .iter()
+                        .fold(Affine::<i64>::identity(), |f, g| Affine::<i64>::op(&f, g));
+                    assert_eq!(product, seq.query(l..r));
+                }
+            }
+            assert_eq!(expected.len(), seq.len());
+            let index = rng.gen_range(0, expected.len() + 1);
+            assert_eq!(expected.get(index), seq.get(index));
+        }
+        assert!(expected.iter().eq(seq.iter()));
+
+        let index = rng.gen_range(0, expected.len() + 1);
+        let mut right = seq.split_off(index);
+        assert!(expected[..index].iter().eq(seq.iter()));
+        assert!(expected[index..].iter().eq(right.iter()));
+        right.append(&mut seq);
+        expected.rotate_left(index);
+        assert!(expected.iter().eq(right.iter()));
+    }
+}
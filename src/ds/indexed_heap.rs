@@ -0,0 +1,330 @@
+/// Indexed d-ary min-heap of values keyed by indices in `[0, capacity)`, supporting decrease-key
+/// and removal by index in O(d log_d n).
+pub struct IndexedHeap<T: Ord> {
+    arity: usize,
+    /// Indices in heap order.
+    heap: Vec<usize>,
+    /// Position of each index in `heap`.
+    position: Vec<Option<usize>>,
+    values: Vec<Option<T>>,
+}
+
+impl<T: Ord> IndexedHeap<T> {
+    /// Creates an empty 4-ary IndexedHeap for indices in `[0, capacity)`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rsalgo::ds::IndexedHeap;
+    ///
+    /// let heap: IndexedHeap<u32> = IndexedHeap::new(10);
+    /// ```
+    pub fn new(capacity: usize) -> Self {
+        Self::with_arity(capacity, 4)
+    }
+
+    /// Creates an empty `arity`-ary IndexedHeap for indices in `[0, capacity)`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rsalgo::ds::IndexedHeap;
+    ///
+    /// let heap: IndexedHeap<u32> = IndexedHeap::with_arity(10, 2);
+    /// ```
+    pub fn with_arity(capacity: usize, arity: usize) -> Self {
+        assert!(arity >= 2, "The arity should be at least 2.");
+        let mut values = Vec::with_capacity(capacity);
+        values.resize_with(capacity, || None);
+        IndexedHeap {
+            arity,
+            heap: Vec::new(),
+            position: vec![None; capacity],
+            values,
+        }
+    }
+
+    /// Returns the number of values.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rsalgo::ds::IndexedHeap;
+    ///
+    /// let mut heap = IndexedHeap::new(10);
+    /// heap.push(3, 5);
+    /// assert_eq!(1, heap.len());
+    /// ```
+    pub fn len(&self) -> usize {
+        self.heap.len()
+    }
+
+    /// Returns true if the IndexedHeap contains no values.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rsalgo::ds::IndexedHeap;
+    ///
+    /// let heap: IndexedHeap<u32> = IndexedHeap::new(10);
+    /// assert!(heap.is_empty());
+    /// ```
+    pub fn is_empty(&self) -> bool {
+        self.heap.is_empty()
+    }
+
+    /// Returns the number of indices.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rsalgo::ds::IndexedHeap;
+    ///
+    /// let heap: IndexedHeap<u32> = IndexedHeap::new(10);
+    /// assert_eq!(10, heap.capacity());
+    /// ```
+    pub fn capacity(&self) -> usize {
+        self.position.len()
+    }
+
+    /// Returns true if `index` has a value in the IndexedHeap.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rsalgo::ds::IndexedHeap;
+    ///
+    /// let mut heap = IndexedHeap::new(10);
+    /// heap.push(3, 5);
+    /// assert!(heap.contains(3));
+    /// assert!(!heap.contains(4));
+    /// ```
+    pub fn contains(&self, index: usize) -> bool {
+        self.position[index].is_some()
+    }
+
+    /// Returns a reference to the value of `index`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rsalgo::ds::IndexedHeap;
+    ///
+    /// let mut heap = IndexedHeap::new(10);
+    /// heap.push(3, 5);
+    /// assert_eq!(Some(&5), heap.get(3));
+    /// ```
+    pub fn get(&self, index: usize) -> Option<&T> {
+        self.values[index].as_ref()
+    }
+
+    /// Provides the index with the minimum value, or None if the IndexedHeap is empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rsalgo::ds::IndexedHeap;
+    ///
+    /// let mut heap = IndexedHeap::new(10);
+    /// heap.push(3, 5);
+    /// heap.push(4, 2);
+    /// assert_eq!(Some((4, &2)), heap.peek());
+    /// ```
+    pub fn peek(&self) -> Option<(usize, &T)> {
+        self.heap
+            .first()
+            .map(|&index| (index, self.values[index].as_ref().unwrap()))
+    }
+
+    /// Pushes `value` with `index`, which should not be in the IndexedHeap.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rsalgo::ds::IndexedHeap;
+    ///
+    /// let mut heap = IndexedHeap::new(10);
+    /// heap.push(3, 5);
+    /// assert_eq!(Some((3, &5)), heap.peek());
+    /// ```
+    pub fn push(&mut self, index: usize, value: T) {
+        assert!(!self.contains(index), "The index is already in the heap.");
+        self.values[index] = Some(value);
+        self.position[index] = Some(self.heap.len());
+        self.heap.push(index);
+        self.sift_up(self.heap.len() - 1);
+    }
+
+    /// Removes the index with the minimum value and returns it with the value, or None if the
+    /// IndexedHeap is empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rsalgo::ds::IndexedHeap;
+    ///
+    /// let mut heap = IndexedHeap::new(10);
+    /// heap.push(3, 5);
+    /// heap.push(4, 2);
+    /// assert_eq!(Some((4, 2)), heap.pop());
+    /// assert_eq!(Some((3, 5)), heap.pop());
+    /// assert_eq!(None, heap.pop());
+    /// ```
+    pub fn pop(&mut self) -> Option<(usize, T)> {
+        let index = *self.heap.first()?;
+        self.remove(index).map(|value| (index, value))
+    }
+
+    /// Replaces the value of `index` with a value not greater than it.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rsalgo::ds::IndexedHeap;
+    ///
+    /// let mut heap = IndexedHeap::new(10);
+    /// heap.push(3, 5);
+    /// heap.push(4, 2);
+    /// heap.decrease_key(3, 1);
+    /// assert_eq!(Some((3, &1)), heap.peek());
+    /// ```
+    pub fn decrease_key(&mut self, index: usize, value: T) {
+        let pos = self.position[index].expect("The index is not in the heap.");
+        assert!(
+            &value <= self.values[index].as_ref().unwrap(),
+            "The new value is greater than the current value."
+        );
+        self.values[index] = Some(value);
+        self.sift_up(pos);
+    }
+
+    /// Pushes `value` with `index`, or decreases the value of `index` to `value` if it is less,
+    /// returns true if the IndexedHeap is changed, as the relaxation in Dijkstra's algorithm.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rsalgo::ds::IndexedHeap;
+    ///
+    /// let mut heap = IndexedHeap::new(10);
+    /// assert!(heap.push_or_decrease(3, 5));
+    /// assert!(!heap.push_or_decrease(3, 6));
+    /// assert!(heap.push_or_decrease(3, 4));
+    /// assert_eq!(Some(&4), heap.get(3));
+    /// ```
+    pub fn push_or_decrease(&mut self, index: usize, value: T) -> bool {
+        match &self.values[index] {
+            None => self.push(index, value),
+            Some(current) if value < *current => self.decrease_key(index, value),
+            _ => return false,
+        }
+        true
+    }
+
+    /// Removes `index` and returns its value, or None if it is not in the IndexedHeap.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rsalgo::ds::IndexedHeap;
+    ///
+    /// let mut heap = IndexedHeap::new(10);
+    /// heap.push(3, 5);
+    /// heap.push(4, 2);
+    /// assert_eq!(Some(2), heap.remove(4));
+    /// assert_eq!(None, heap.remove(4));
+    /// assert_eq!(Some((3, &5)), heap.peek());
+    /// ```
+    pub fn remove(&mut self, index: usize) -> Option<T> {
+        let pos = self.position[index]?;
+        let last = self.heap.len() - 1;
+        self.swap(pos, last);
+        self.heap.pop();
+        self.position[index] = None;
+        if pos < last {
+            self.sift_up(pos);
+            self.sift_down(pos);
+        }
+        self.values[index].take()
+    }
+
+    fn less(&self, a: usize, b: usize) -> bool {
+        self.values[self.heap[a]] < self.values[self.heap[b]]
+    }
+
+    fn swap(&mut self, a: usize, b: usize) {
+        self.heap.swap(a, b);
+        self.position[self.heap[a]] = Some(a);
+        self.position[self.heap[b]] = Some(b);
+    }
+
+    fn sift_up(&mut self, pos: usize) {
+        let mut pos = pos;
+        while pos > 0 {
+            let parent = (pos - 1) / self.arity;
+            if !self.less(pos, parent) {
+                break;
+            }
+            self.swap(pos, parent);
+            pos = parent;
+        }
+    }
+
+    fn sift_down(&mut self, pos: usize) {
+        let mut pos = pos;
+        loop {
+            let first = pos * self.arity + 1;
+            let last = (first + self.arity).min(self.heap.len());
+            let child = match (first..last)
+                .min_by(|&a, &b| self.values[self.heap[a]].cmp(&self.values[self.heap[b]]))
+            {
+                Some(child) if self.less(child, pos) => child,
+                _ => break,
+            };
+            self.swap(pos, child);
+            pos = child;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::IndexedHeap;
+    use rand::Rng;
+
+    #[test]
+    fn indexed_heap() {
+        const CAPACITY: usize = 50;
+        let mut rng = rand::thread_rng();
+        for &arity in &[2, 3, 4, 8] {
+            let mut heap = IndexedHeap::with_arity(CAPACITY, arity);
+            let mut expected: Vec<Option<i32>> = vec![None; CAPACITY];
+
+            for _ in 0..2000 {
+                let index = rng.gen_range(0, CAPACITY);
+                let value = rng.gen_range(-1000, 1000);
+                match rng.gen_range(0, 4) {
+                    0 => {
+                        let changed = expected[index].is_none_or(|v| value < v);
+                        if changed {
+                            expected[index] = Some(value);
+                        }
+                        assert_eq!(changed, heap.push_or_decrease(index, value));
+                    }
+                    1 => assert_eq!(expected[index].take(), heap.remove(index)),
+                    _ => {
+                        let min = (0..CAPACITY)
+                            .filter_map(|i| expected[i].map(|v| (v, i)))
+                            .min();
+                        let top = heap.pop();
+                        assert_eq!(min.map(|(v, _)| v), top.map(|(_, v)| v));
+                        if let Some((i, _)) = top {
+                            assert_eq!(expected[i].take(), Some(top.unwrap().1));
+                        }
+                    }
+                }
+                assert_eq!(expected.iter().flatten().count(), heap.len());
+            }
+        }
+    }
+}
@@ -0,0 +1,254 @@
+use super::DisjointSet;
+use std::collections::HashMap;
+use std::hash::Hash;
+
+/// Disjoint set keyed by arbitrary values, new keys are added on first use.
+pub struct KeyedDisjointSet<K: Hash + Eq + Clone> {
+    ids: HashMap<K, usize>,
+    keys: Vec<K>,
+    inner: DisjointSet,
+}
+
+impl<K: Hash + Eq + Clone> Default for KeyedDisjointSet<K> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<K: Hash + Eq + Clone> KeyedDisjointSet<K> {
+    /// Create an empty keyed disjoint set with union by rank.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let s: rsalgo::ds::KeyedDisjointSet<&str> = rsalgo::ds::KeyedDisjointSet::new();
+    /// ```
+    pub fn new() -> Self {
+        KeyedDisjointSet {
+            ids: HashMap::new(),
+            keys: Vec::new(),
+            inner: DisjointSet::new(0, true),
+        }
+    }
+
+    /// Get the number of disjoint sets.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let mut s = rsalgo::ds::KeyedDisjointSet::new();
+    /// s.unite("a", "b");
+    /// s.insert("c");
+    /// assert_eq!(2, s.len());
+    /// ```
+    pub fn len(&self) -> usize {
+        self.inner.len()
+    }
+
+    /// Get the number of keys.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let mut s = rsalgo::ds::KeyedDisjointSet::new();
+    /// s.unite("a", "b");
+    /// assert_eq!(2, s.capacity());
+    /// ```
+    pub fn capacity(&self) -> usize {
+        self.keys.len()
+    }
+
+    /// Returns true if the set contains no keys.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let s: rsalgo::ds::KeyedDisjointSet<u32> = rsalgo::ds::KeyedDisjointSet::new();
+    /// assert!(s.is_empty());
+    /// ```
+    pub fn is_empty(&self) -> bool {
+        self.keys.is_empty()
+    }
+
+    pub fn is_one(&self) -> bool {
+        self.inner.is_one()
+    }
+
+    /// Returns true if the set contains `key`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let mut s = rsalgo::ds::KeyedDisjointSet::new();
+    /// s.insert(1);
+    /// assert!(s.contains(&1));
+    /// assert!(!s.contains(&2));
+    /// ```
+    pub fn contains(&self, key: &K) -> bool {
+        self.ids.contains_key(key)
+    }
+
+    /// Add `key` in its own set if it is absent, returns its internal id.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let mut s = rsalgo::ds::KeyedDisjointSet::new();
+    /// assert_eq!(0, s.insert("a"));
+    /// assert_eq!(1, s.insert("b"));
+    /// assert_eq!(0, s.insert("a"));
+    /// ```
+    pub fn insert(&mut self, key: K) -> usize {
+        if let Some(&id) = self.ids.get(&key) {
+            return id;
+        }
+        let id = self.inner.push();
+        self.ids.insert(key.clone(), id);
+        self.keys.push(key);
+        id
+    }
+
+    /// Get the representation key of `key`'s set, or None if `key` is absent.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let mut s = rsalgo::ds::KeyedDisjointSet::new();
+    /// s.unite("a", "b");
+    /// let root = s.find(&"a").cloned();
+    /// assert_eq!(root.as_ref(), s.find(&"b"));
+    /// assert_eq!(None, s.find(&"c"));
+    /// ```
+    pub fn find(&mut self, key: &K) -> Option<&K> {
+        let id = *self.ids.get(key)?;
+        let root = self.inner.find(id);
+        Some(&self.keys[root])
+    }
+
+    /// Return whether the two keys are present and in the same set.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let mut s = rsalgo::ds::KeyedDisjointSet::new();
+    /// s.unite("a", "b");
+    /// assert!(s.in_same(&"a", &"b"));
+    /// assert!(!s.in_same(&"a", &"c"));
+    /// ```
+    pub fn in_same(&mut self, a: &K, b: &K) -> bool {
+        match (self.ids.get(a), self.ids.get(b)) {
+            (Some(&a), Some(&b)) => self.inner.in_same(a, b),
+            _ => false,
+        }
+    }
+
+    /// Get the size of `key`'s set, or 0 if `key` is absent.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let mut s = rsalgo::ds::KeyedDisjointSet::new();
+    /// s.unite("a", "b");
+    /// assert_eq!(2, s.size_of(&"a"));
+    /// assert_eq!(0, s.size_of(&"c"));
+    /// ```
+    pub fn size_of(&mut self, key: &K) -> usize {
+        match self.ids.get(key) {
+            Some(&id) => self.inner.size_of(id),
+            None => 0,
+        }
+    }
+
+    /// Unite `a`'s set and `b`'s set, inserting absent keys, returns the new representation key
+    /// if two sets are merged.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let mut s = rsalgo::ds::KeyedDisjointSet::new();
+    /// assert!(s.unite("a", "b").is_some());
+    /// assert_eq!(None, s.unite("b", "a"));
+    /// ```
+    pub fn unite(&mut self, a: K, b: K) -> Option<&K> {
+        let (a, b) = (self.insert(a), self.insert(b));
+        let root = self.inner.unite(a, b)?;
+        Some(&self.keys[root])
+    }
+
+    /// Gets an iterator over the members of `key`'s set, which is empty if `key` is absent.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let mut s = rsalgo::ds::KeyedDisjointSet::new();
+    /// s.unite("a", "b");
+    /// let mut members: Vec<_> = s.members(&"b").collect();
+    /// members.sort();
+    /// assert_eq!(vec![&"a", &"b"], members);
+    /// ```
+    pub fn members(&self, key: &K) -> impl Iterator<Item = &K> {
+        let keys = &self.keys;
+        self.ids
+            .get(key)
+            .map(|&id| self.inner.members(id))
+            .into_iter()
+            .flatten()
+            .map(move |id| &keys[id])
+    }
+
+    /// Get all sets, ordered by the insertion order of their first inserted keys.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let mut s = rsalgo::ds::KeyedDisjointSet::new();
+    /// s.unite("a", "c");
+    /// s.insert("b");
+    /// assert_eq!(vec![vec![&"a", &"c"], vec![&"b"]], s.groups());
+    /// ```
+    pub fn groups(&mut self) -> Vec<Vec<&K>> {
+        let keys = &self.keys;
+        self.inner
+            .groups()
+            .into_iter()
+            .map(|group| group.into_iter().map(|id| &keys[id]).collect())
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::KeyedDisjointSet;
+    use crate::ds::DisjointSet;
+    use rand::Rng;
+
+    #[test]
+    fn keyed_disjoint_set() {
+        let mut rng = rand::thread_rng();
+        const SIZE: usize = 40;
+
+        let mut s = KeyedDisjointSet::new();
+        let mut expected = DisjointSet::new(SIZE, false);
+        let key = |i: usize| format!("k{}", i);
+
+        for i in 0..SIZE {
+            s.insert(key(i));
+        }
+        for _ in 0..60 {
+            let (a, b) = (rng.gen_range(0, SIZE), rng.gen_range(0, SIZE));
+            assert_eq!(
+                expected.unite(a, b).is_some(),
+                s.unite(key(a), key(b)).is_some()
+            );
+            assert_eq!(expected.len(), s.len());
+
+            let (a, b) = (rng.gen_range(0, SIZE), rng.gen_range(0, SIZE));
+            assert_eq!(expected.in_same(a, b), s.in_same(&key(a), &key(b)));
+            assert_eq!(expected.size_of(a), s.size_of(&key(a)));
+            assert_eq!(expected.size_of(a), s.members(&key(a)).count());
+        }
+
+        assert_eq!(expected.groups().len(), s.groups().len());
+        assert_eq!(SIZE, s.capacity());
+    }
+}
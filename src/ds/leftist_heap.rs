@@ -0,0 +1,190 @@
+use super::meldable_heap::drop_tree;
+
+type Link<T> = Option<Box<Node<T>>>;
+
+struct Node<T> {
+    value: T,
+    /// Length of the right spine.
+    rank: usize,
+    left: Link<T>,
+    right: Link<T>,
+}
+
+fn rank<T>(link: &Link<T>) -> usize {
+    link.as_ref().map_or(0, |node| node.rank)
+}
+
+fn merge<T: Ord>(a: Link<T>, b: Link<T>) -> Link<T> {
+    match (a, b) {
+        (Some(a), Some(b)) => {
+            let (mut root, other) = if a.value <= b.value { (a, b) } else { (b, a) };
+            root.right = merge(root.right.take(), Some(other));
+            if rank(&root.left) < rank(&root.right) {
+                std::mem::swap(&mut root.left, &mut root.right);
+            }
+            root.rank = rank(&root.right) + 1;
+            Some(root)
+        }
+        (a, b) => a.or(b),
+    }
+}
+
+/// Leftist min-heap, supporting push, pop and merge in O(log n).
+pub struct LeftistHeap<T: Ord> {
+    root: Link<T>,
+    len: usize,
+}
+
+impl<T: Ord> Default for LeftistHeap<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: Ord> LeftistHeap<T> {
+    /// Creates an empty LeftistHeap.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rsalgo::ds::LeftistHeap;
+    ///
+    /// let heap: LeftistHeap<u32> = LeftistHeap::new();
+    /// ```
+    pub fn new() -> Self {
+        LeftistHeap { root: None, len: 0 }
+    }
+
+    /// Returns the number of values.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rsalgo::ds::LeftistHeap;
+    ///
+    /// let mut heap = LeftistHeap::new();
+    /// heap.push(1);
+    /// assert_eq!(1, heap.len());
+    /// ```
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns true if the LeftistHeap contains no values.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rsalgo::ds::LeftistHeap;
+    ///
+    /// let heap: LeftistHeap<u32> = LeftistHeap::new();
+    /// assert!(heap.is_empty());
+    /// ```
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Provides the minimum value, or None if the LeftistHeap is empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rsalgo::ds::LeftistHeap;
+    ///
+    /// let mut heap = LeftistHeap::new();
+    /// heap.push(3);
+    /// heap.push(1);
+    /// assert_eq!(Some(&1), heap.peek());
+    /// ```
+    pub fn peek(&self) -> Option<&T> {
+        self.root.as_ref().map(|node| &node.value)
+    }
+
+    /// Pushes `value` into the LeftistHeap.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rsalgo::ds::LeftistHeap;
+    ///
+    /// let mut heap = LeftistHeap::new();
+    /// heap.push(3);
+    /// assert_eq!(Some(&3), heap.peek());
+    /// ```
+    pub fn push(&mut self, value: T) {
+        let node = Some(Box::new(Node {
+            value,
+            rank: 1,
+            left: None,
+            right: None,
+        }));
+        self.root = merge(self.root.take(), node);
+        self.len += 1;
+    }
+
+    /// Removes the minimum value and returns it, or None if the LeftistHeap is empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rsalgo::ds::LeftistHeap;
+    ///
+    /// let mut heap = LeftistHeap::new();
+    /// heap.push(3);
+    /// heap.push(1);
+    /// assert_eq!(Some(1), heap.pop());
+    /// assert_eq!(Some(3), heap.pop());
+    /// assert_eq!(None, heap.pop());
+    /// ```
+    pub fn pop(&mut self) -> Option<T> {
+        let root = *self.root.take()?;
+        self.root = merge(root.left, root.right);
+        self.len -= 1;
+        Some(root.value)
+    }
+
+    /// Moves all values of `other` into the LeftistHeap in O(log n).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rsalgo::ds::LeftistHeap;
+    ///
+    /// let mut heap = LeftistHeap::new();
+    /// heap.push(3);
+    /// let mut other = LeftistHeap::new();
+    /// other.push(1);
+    /// heap.append(&mut other);
+    /// assert!(other.is_empty());
+    /// assert_eq!(Some(1), heap.pop());
+    /// ```
+    pub fn append(&mut self, other: &mut Self) {
+        self.root = merge(self.root.take(), other.root.take());
+        self.len += std::mem::replace(&mut other.len, 0);
+    }
+}
+
+impl<T: Ord> Drop for LeftistHeap<T> {
+    fn drop(&mut self) {
+        drop_tree(self.root.take(), |node, stack| {
+            stack.extend(node.left.take());
+            stack.extend(node.right.take());
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::LeftistHeap;
+    use crate::ds::meldable_heap::tests::{check_against_binary_heap, check_deep_drop};
+
+    #[test]
+    fn leftist_heap() {
+        check_against_binary_heap::<LeftistHeap<i32>>();
+    }
+
+    #[test]
+    fn deep_drop() {
+        check_deep_drop::<LeftistHeap<i32>>();
+    }
+}
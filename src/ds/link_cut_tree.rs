@@ -0,0 +1,468 @@
+use super::algebra::{Monoid, Sum};
+
+struct Node<T> {
+    value: T,
+    /// Result of the splay subtree from left to right, which is from the root side.
+    sum: T,
+    /// Result of the splay subtree from right to left.
+    rev_sum: T,
+    left: Option<usize>,
+    right: Option<usize>,
+    /// Parent in the splay tree, or the path parent for the root of a splay tree.
+    parent: Option<usize>,
+    /// The children should be swapped and reversed, `sum` and `rev_sum` are already swapped.
+    reversed: bool,
+}
+
+/// Link-cut tree over a forest of vertices with values of a monoid, supporting link, cut,
+/// re-rooting and path queries in amortized O(log n).
+pub struct LinkCutTree<T, M = Sum<T>>
+where
+    T: Clone,
+    M: Monoid<Item = T>,
+{
+    nodes: Vec<Node<T>>,
+    monoid: std::marker::PhantomData<M>,
+}
+
+impl<T, M> LinkCutTree<T, M>
+where
+    T: Clone,
+    M: Monoid<Item = T>,
+{
+    /// Creates a LinkCutTree of isolated vertices with `values`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rsalgo::ds::LinkCutTree;
+    ///
+    /// let lct: LinkCutTree<i32> = LinkCutTree::new(&[1, 2, 3]);
+    /// ```
+    pub fn new(values: &[T]) -> Self {
+        LinkCutTree {
+            nodes: values
+                .iter()
+                .map(|value| Node {
+                    value: value.clone(),
+                    sum: value.clone(),
+                    rev_sum: value.clone(),
+                    left: None,
+                    right: None,
+                    parent: None,
+                    reversed: false,
+                })
+                .collect(),
+            monoid: std::marker::PhantomData,
+        }
+    }
+
+    /// Returns the number of vertices.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rsalgo::ds::LinkCutTree;
+    ///
+    /// let lct: LinkCutTree<i32> = LinkCutTree::new(&[1, 2, 3]);
+    /// assert_eq!(3, lct.len());
+    /// ```
+    pub fn len(&self) -> usize {
+        self.nodes.len()
+    }
+
+    /// Returns true if there are no vertices.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rsalgo::ds::LinkCutTree;
+    ///
+    /// let lct: LinkCutTree<i32> = LinkCutTree::new(&[]);
+    /// assert!(lct.is_empty());
+    /// ```
+    pub fn is_empty(&self) -> bool {
+        self.nodes.is_empty()
+    }
+
+    fn is_splay_root(&self, x: usize) -> bool {
+        match self.nodes[x].parent {
+            Some(p) => self.nodes[p].left != Some(x) && self.nodes[p].right != Some(x),
+            None => true,
+        }
+    }
+
+    fn reverse(&mut self, x: usize) {
+        let node = &mut self.nodes[x];
+        node.reversed = !node.reversed;
+        std::mem::swap(&mut node.sum, &mut node.rev_sum);
+    }
+
+    fn push(&mut self, x: usize) {
+        if self.nodes[x].reversed {
+            let node = &mut self.nodes[x];
+            node.reversed = false;
+            std::mem::swap(&mut node.left, &mut node.right);
+            let (left, right) = (node.left, node.right);
+            left.into_iter().chain(right).for_each(|c| self.reverse(c));
+        }
+    }
+
+    fn update(&mut self, x: usize) {
+        let (mut sum, mut rev_sum) = (self.nodes[x].value.clone(), self.nodes[x].value.clone());
+        if let Some(left) = self.nodes[x].left {
+            sum = M::op(&self.nodes[left].sum, &sum);
+            rev_sum = M::op(&rev_sum, &self.nodes[left].rev_sum);
+        }
+        if let Some(right) = self.nodes[x].right {
+            sum = M::op(&sum, &self.nodes[right].sum);
+            rev_sum = M::op(&self.nodes[right].rev_sum, &rev_sum);
+        }
+        self.nodes[x].sum = sum;
+        self.nodes[x].rev_sum = rev_sum;
+    }
+
+    fn rotate(&mut self, x: usize) {
+        let p = self.nodes[x].parent.unwrap();
+        let g = self.nodes[p].parent;
+        if !self.is_splay_root(p) {
+            let g = g.unwrap();
+            if self.nodes[g].left == Some(p) {
+                self.nodes[g].left = Some(x);
+            } else {
+                self.nodes[g].right = Some(x);
+            }
+        }
+        self.nodes[x].parent = g;
+        if self.nodes[p].left == Some(x) {
+            let child = self.nodes[x].right;
+            self.nodes[p].left = child;
+            self.nodes[x].right = Some(p);
+            if let Some(c) = child {
+                self.nodes[c].parent = Some(p);
+            }
+        } else {
+            let child = self.nodes[x].left;
+            self.nodes[p].right = child;
+            self.nodes[x].left = Some(p);
+            if let Some(c) = child {
+                self.nodes[c].parent = Some(p);
+            }
+        }
+        self.nodes[p].parent = Some(x);
+        self.update(p);
+        self.update(x);
+    }
+
+    fn splay(&mut self, x: usize) {
+        let mut path = vec![x];
+        while !self.is_splay_root(path[path.len() - 1]) {
+            path.push(self.nodes[path[path.len() - 1]].parent.unwrap());
+        }
+        for &v in path.iter().rev() {
+            self.push(v);
+        }
+
+        while !self.is_splay_root(x) {
+            let p = self.nodes[x].parent.unwrap();
+            if !self.is_splay_root(p) {
+                let g = self.nodes[p].parent.unwrap();
+                let zigzig = (self.nodes[g].left == Some(p)) == (self.nodes[p].left == Some(x));
+                self.rotate(if zigzig { p } else { x });
+            }
+            self.rotate(x);
+        }
+    }
+
+    /// Makes the path from the root to `x` preferred, returns the last vertex joined to the path.
+    fn access(&mut self, x: usize) -> usize {
+        let (mut last, mut current, mut res) = (None, Some(x), x);
+        while let Some(v) = current {
+            self.splay(v);
+            self.nodes[v].right = last;
+            self.update(v);
+            res = v;
+            last = Some(v);
+            current = self.nodes[v].parent;
+        }
+        self.splay(x);
+        res
+    }
+
+    /// Makes `x` the root of its tree.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rsalgo::ds::LinkCutTree;
+    ///
+    /// let mut lct: LinkCutTree<i32> = LinkCutTree::new(&[1, 2, 3]);
+    /// lct.link(0, 1);
+    /// lct.link(1, 2);
+    /// lct.make_root(2);
+    /// assert_eq!(2, lct.find_root(0));
+    /// ```
+    pub fn make_root(&mut self, x: usize) {
+        self.access(x);
+        self.reverse(x);
+    }
+
+    /// Gets the root of the tree containing `x`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rsalgo::ds::LinkCutTree;
+    ///
+    /// let mut lct: LinkCutTree<i32> = LinkCutTree::new(&[1, 2, 3]);
+    /// lct.link(0, 1);
+    /// assert_eq!(1, lct.find_root(0));
+    /// assert_eq!(2, lct.find_root(2));
+    /// ```
+    pub fn find_root(&mut self, x: usize) -> usize {
+        self.access(x);
+        let mut root = x;
+        self.push(root);
+        while let Some(left) = self.nodes[root].left {
+            root = left;
+            self.push(root);
+        }
+        self.splay(root);
+        root
+    }
+
+    /// Returns true if `a` and `b` are in the same tree.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rsalgo::ds::LinkCutTree;
+    ///
+    /// let mut lct: LinkCutTree<i32> = LinkCutTree::new(&[1, 2, 3]);
+    /// lct.link(0, 1);
+    /// assert!(lct.connected(0, 1));
+    /// assert!(!lct.connected(0, 2));
+    /// ```
+    pub fn connected(&mut self, a: usize, b: usize) -> bool {
+        self.find_root(a) == self.find_root(b)
+    }
+
+    /// Adds the edge between `child` and `parent` by making `child` the root of its tree and
+    /// attaching it to `parent`, returns false if they are already connected.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rsalgo::ds::LinkCutTree;
+    ///
+    /// let mut lct: LinkCutTree<i32> = LinkCutTree::new(&[1, 2, 3]);
+    /// assert!(lct.link(0, 1));
+    /// assert!(lct.link(2, 1));
+    /// assert!(!lct.link(0, 2));
+    /// ```
+    pub fn link(&mut self, child: usize, parent: usize) -> bool {
+        if self.connected(child, parent) {
+            return false;
+        }
+        self.make_root(child);
+        self.nodes[child].parent = Some(parent);
+        true
+    }
+
+    /// Removes the edge between `a` and `b`, returns false if there is no such edge.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rsalgo::ds::LinkCutTree;
+    ///
+    /// let mut lct: LinkCutTree<i32> = LinkCutTree::new(&[1, 2, 3]);
+    /// lct.link(0, 1);
+    /// lct.link(1, 2);
+    /// assert!(!lct.cut(0, 2));
+    /// assert!(lct.cut(1, 0));
+    /// assert!(!lct.connected(0, 2));
+    /// ```
+    pub fn cut(&mut self, a: usize, b: usize) -> bool {
+        if a == b {
+            return false;
+        }
+        self.make_root(a);
+        self.access(b);
+        if self.nodes[b].left != Some(a) {
+            return false;
+        }
+        self.push(a);
+        if self.nodes[a].right.is_some() {
+            return false;
+        }
+        self.nodes[b].left = None;
+        self.nodes[a].parent = None;
+        self.update(b);
+        true
+    }
+
+    /// Gets the lowest common ancestor of `a` and `b` in the current rooted tree, or None if they
+    /// are not connected.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rsalgo::ds::LinkCutTree;
+    ///
+    /// let mut lct: LinkCutTree<i32> = LinkCutTree::new(&[1, 2, 3, 4]);
+    /// lct.link(1, 0);
+    /// lct.link(2, 0);
+    /// lct.make_root(0);
+    /// assert_eq!(Some(0), lct.lca(1, 2));
+    /// assert_eq!(Some(1), lct.lca(1, 1));
+    /// assert_eq!(None, lct.lca(1, 3));
+    /// ```
+    pub fn lca(&mut self, a: usize, b: usize) -> Option<usize> {
+        if !self.connected(a, b) {
+            return None;
+        }
+        self.access(a);
+        Some(self.access(b))
+    }
+
+    /// Returns a reference to the value of `x`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rsalgo::ds::LinkCutTree;
+    ///
+    /// let lct: LinkCutTree<i32> = LinkCutTree::new(&[1, 2, 3]);
+    /// assert_eq!(&2, lct.get(1));
+    /// ```
+    pub fn get(&self, x: usize) -> &T {
+        &self.nodes[x].value
+    }
+
+    /// Replaces the value of `x` with `value`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rsalgo::ds::LinkCutTree;
+    ///
+    /// let mut lct: LinkCutTree<i32> = LinkCutTree::new(&[1, 2, 3]);
+    /// lct.link(0, 1);
+    /// lct.set(1, 5);
+    /// assert_eq!(Some(6), lct.path_query(0, 1));
+    /// ```
+    pub fn set(&mut self, x: usize, value: T) {
+        self.access(x);
+        self.nodes[x].value = value;
+        self.update(x);
+    }
+
+    /// Gets the result of the values on the path from `a` to `b` in order, or None if they are
+    /// not connected, it makes `a` the root of its tree.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rsalgo::ds::LinkCutTree;
+    ///
+    /// let mut lct: LinkCutTree<i32> = LinkCutTree::new(&[1, 2, 3, 4]);
+    /// lct.link(0, 1);
+    /// lct.link(2, 1);
+    /// assert_eq!(Some(6), lct.path_query(0, 2));
+    /// assert_eq!(None, lct.path_query(0, 3));
+    /// ```
+    pub fn path_query(&mut self, a: usize, b: usize) -> Option<T> {
+        if !self.connected(a, b) {
+            return None;
+        }
+        self.make_root(a);
+        self.access(b);
+        Some(self.nodes[b].sum.clone())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::LinkCutTree;
+    use crate::ds::algebra::{Affine, Monoid};
+    use rand::Rng;
+    use std::collections::BTreeSet;
+
+    /// Gets the path from `a` to `b` in the forest of `edges`.
+    fn path(n: usize, edges: &BTreeSet<(usize, usize)>, a: usize, b: usize) -> Option<Vec<usize>> {
+        let mut parent = vec![None; n];
+        let mut visited = vec![false; n];
+        let mut stack = vec![a];
+        visited[a] = true;
+        while let Some(v) = stack.pop() {
+            for &(x, y) in edges {
+                for &(u, w) in &[(x, y), (y, x)] {
+                    if u == v && !visited[w] {
+                        visited[w] = true;
+                        parent[w] = Some(v);
+                        stack.push(w);
+                    }
+                }
+            }
+        }
+        if !visited[b] {
+            return None;
+        }
+        let mut res = vec![b];
+        while let Some(p) = parent[res[res.len() - 1]] {
+            res.push(p);
+        }
+        res.reverse();
+        Some(res)
+    }
+
+    #[test]
+    fn link_cut_tree() {
+        const SIZE: usize = 12;
+        let mut rng = rand::thread_rng();
+        let mut values: Vec<(i64, i64)> = (0..SIZE)
+            .map(|_| (rng.gen_range(-2, 3), rng.gen_range(-5, 5)))
+            .collect();
+        let mut lct: LinkCutTree<(i64, i64), Affine<i64>> = LinkCutTree::new(&values);
+        let mut edges = BTreeSet::new();
+
+        for _ in 0..2000 {
+            let (a, b) = (rng.gen_range(0, SIZE), rng.gen_range(0, SIZE));
+            let expected = path(SIZE, &edges, a, b);
+            match rng.gen_range(0, 5) {
+                0 => {
+                    assert_eq!(expected.is_none(), lct.link(a, b));
+                    if expected.is_none() {
+                        edges.insert((a.min(b), a.max(b)));
+                    }
+                }
+                1 => assert_eq!(edges.remove(&(a.min(b), a.max(b))), lct.cut(a, b)),
+                2 => {
+                    values[a] = (rng.gen_range(-2, 3), rng.gen_range(-5, 5));
+                    lct.set(a, values[a]);
+                }
+                3 => {
+                    let root = lct.find_root(a);
+                    let lca = path(SIZE, &edges, root, a).and_then(|pa| {
+                        let pb = path(SIZE, &edges, root, b)?;
+                        pa.iter()
+                            .zip(&pb)
+                            .take_while(|(x, y)| x == y)
+                            .last()
+                            .map(|(x, _)| *x)
+                    });
+                    assert_eq!(lca, lct.lca(a, b));
+                }
+                _ => {
+                    let product = expected.map(|p| {
+                        p.iter().fold(Affine::<i64>::identity(), |f, &v| {
+                            Affine::<i64>::op(&f, &values[v])
+                        })
+                    });
+                    assert_eq!(product, lct.path_query(a, b));
+                }
+            }
+        }
+    }
+}
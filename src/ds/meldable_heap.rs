@@ -0,0 +1,137 @@
+//! Helpers shared by the pointer-based meldable heaps
+
+/// Drops a tree of boxed nodes iteratively, since the trees of meldable heaps can be as deep as
+/// the number of values. `detach` moves the children of a node into the stack.
+pub(crate) fn drop_tree<N, F>(root: Option<Box<N>>, detach: F)
+where
+    F: Fn(&mut N, &mut Vec<Box<N>>),
+{
+    let mut stack: Vec<Box<N>> = root.into_iter().collect();
+    while let Some(mut node) = stack.pop() {
+        detach(&mut node, &mut stack);
+    }
+}
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use crate::ds::{LeftistHeap, PairingHeap};
+    use rand::Rng;
+    use std::cmp::Reverse;
+    use std::collections::BinaryHeap;
+
+    /// Common interface of the meldable min-heaps under test.
+    pub(crate) trait MinHeap: Default {
+        fn push(&mut self, value: i32);
+        fn pop(&mut self) -> Option<i32>;
+        fn peek(&self) -> Option<&i32>;
+        fn len(&self) -> usize;
+        fn append(&mut self, other: &mut Self);
+    }
+
+    macro_rules! impl_min_heap {
+        ($($heap:ident),*) => ($(
+            impl MinHeap for $heap<i32> {
+                fn push(&mut self, value: i32) {
+                    $heap::push(self, value)
+                }
+                fn pop(&mut self) -> Option<i32> {
+                    $heap::pop(self)
+                }
+                fn peek(&self) -> Option<&i32> {
+                    $heap::peek(self)
+                }
+                fn len(&self) -> usize {
+                    $heap::len(self)
+                }
+                fn append(&mut self, other: &mut Self) {
+                    $heap::append(self, other)
+                }
+            }
+        )*)
+    }
+
+    impl_min_heap!(PairingHeap, LeftistHeap);
+
+    /// Checks random pushes, pops and appends on a few heaps against `BinaryHeap`.
+    pub(crate) fn check_against_binary_heap<H: MinHeap>() {
+        let mut rng = rand::thread_rng();
+        let mut heaps: Vec<H> = (0..4).map(|_| H::default()).collect();
+        let mut expected: Vec<BinaryHeap<Reverse<i32>>> =
+            (0..4).map(|_| BinaryHeap::new()).collect();
+
+        for _ in 0..5000 {
+            let i = rng.gen_range(0, 4);
+            match rng.gen_range(0, 10) {
+                0..=4 => {
+                    let value = rng.gen_range(-1000, 1000);
+                    heaps[i].push(value);
+                    expected[i].push(Reverse(value));
+                }
+                5..=8 => {
+                    assert_eq!(expected[i].peek().map(|v| v.0), heaps[i].peek().cloned());
+                    assert_eq!(expected[i].pop().map(|v| v.0), heaps[i].pop());
+                }
+                _ => {
+                    let j = rng.gen_range(0, 4);
+                    if i != j {
+                        let mut other = std::mem::take(&mut heaps[j]);
+                        heaps[i].append(&mut other);
+                        let mut other = std::mem::take(&mut expected[j]);
+                        expected[i].append(&mut other);
+                    }
+                }
+            }
+            assert_eq!(expected[i].len(), heaps[i].len());
+        }
+    }
+
+    /// Drops a heap built by pushing descending values, which makes the deepest tree.
+    pub(crate) fn check_deep_drop<H: MinHeap>() {
+        let mut heap = H::default();
+        for i in (0..100_000).rev() {
+            heap.push(i);
+        }
+    }
+
+    #[test]
+    fn heaps_agree_after_append() {
+        let mut rng = rand::thread_rng();
+        let mut pairing: Vec<PairingHeap<i32>> = (0..4).map(|_| PairingHeap::new()).collect();
+        let mut leftist: Vec<LeftistHeap<i32>> = (0..4).map(|_| LeftistHeap::new()).collect();
+
+        for _ in 0..5000 {
+            let i = rng.gen_range(0, 4);
+            match rng.gen_range(0, 10) {
+                0..=4 => {
+                    let value = rng.gen_range(-1000, 1000);
+                    pairing[i].push(value);
+                    leftist[i].push(value);
+                }
+                5..=6 => assert_eq!(pairing[i].pop(), leftist[i].pop()),
+                _ => {
+                    let j = rng.gen_range(0, 4);
+                    if i != j {
+                        let mut other = std::mem::take(&mut pairing[j]);
+                        pairing[i].append(&mut other);
+                        let mut other = std::mem::take(&mut leftist[j]);
+                        leftist[i].append(&mut other);
+                        assert!(pairing[j].is_empty() && leftist[j].is_empty());
+                    }
+                }
+            }
+            assert_eq!(pairing[i].len(), leftist[i].len());
+            assert_eq!(pairing[i].peek(), leftist[i].peek());
+        }
+
+        for (p, l) in pairing.iter_mut().zip(leftist.iter_mut()) {
+            let (mut a, mut b) = (Vec::new(), Vec::new());
+            while let Some(value) = p.pop() {
+                a.push(value);
+            }
+            while let Some(value) = l.pop() {
+                b.push(value);
+            }
+            assert_eq!(a, b);
+        }
+    }
+}
@@ -1,16 +1,83 @@
 //! Data structures
 
+pub mod algebra;
+
 mod disjoint_set;
-pub use disjoint_set::DisjointSet;
+pub use disjoint_set::{DisjointSet, Members};
+
+mod keyed_disjoint_set;
+pub use keyed_disjoint_set::KeyedDisjointSet;
+
+mod weighted_disjoint_set;
+pub use weighted_disjoint_set::{PotentialConflict, WeightedDisjointSet};
+
+mod rollback_disjoint_set;
+pub use rollback_disjoint_set::RollbackDisjointSet;
 
 mod monotonic_queue;
-pub use monotonic_queue::MonotonicQueue;
+pub use monotonic_queue::{sliding_window_max, sliding_window_min, MonotonicQueue, SlidingWindow};
+
+mod monotonic_stack;
+pub use monotonic_stack::{
+    largest_rectangle, maximal_rectangle, next_greater, next_smaller, previous_greater,
+    previous_smaller, MonotonicStack,
+};
 
 mod prefix_sum;
-pub use prefix_sum::PrefixSum1D;
+pub use prefix_sum::{DifferenceArray2D, PrefixSum1D, PrefixSum2D, PrefixSumND};
 
 mod fenwick_tree;
-pub use fenwick_tree::FenwickTree;
+pub use fenwick_tree::{FenwickTree, RangeAddFenwickTree, RangeSumFenwickTree};
+
+mod fenwick_tree_2d;
+pub use fenwick_tree_2d::FenwickTree2D;
 
 mod sparse_table;
-pub use sparse_table::SparseTable;
+pub use sparse_table::{DisjointSparseTable, SparseTable};
+
+mod sqrt_decomposition;
+pub use sqrt_decomposition::SqrtDecomposition;
+
+mod segment_tree;
+pub use segment_tree::LazySegmentTree;
+
+mod persistent_segment_tree;
+pub use persistent_segment_tree::{PersistentSegmentTree, RangeKth};
+
+mod treap;
+pub use treap::{Iter as TreapIter, TreapMap, TreapSet};
+
+mod implicit_treap;
+pub use implicit_treap::{ImplicitTreap, Iter as ImplicitTreapIter};
+
+mod indexed_heap;
+pub use indexed_heap::IndexedHeap;
+
+mod meldable_heap;
+
+mod pairing_heap;
+pub use pairing_heap::PairingHeap;
+
+mod leftist_heap;
+pub use leftist_heap::LeftistHeap;
+
+mod link_cut_tree;
+pub use link_cut_tree::LinkCutTree;
+
+mod bit_vector;
+pub use bit_vector::BitVector;
+
+mod bit_set;
+pub use bit_set::{BitSet, Ones};
+
+mod wavelet_matrix;
+pub use wavelet_matrix::WaveletMatrix;
+
+mod trie;
+pub use trie::{Iter as TrieIter, Trie};
+
+mod persistent_array;
+pub use persistent_array::PersistentArray;
+
+mod persistent_disjoint_set;
+pub use persistent_disjoint_set::PersistentDisjointSet;
@@ -1,24 +1,57 @@
+use std::cmp::Ordering;
 use std::collections::VecDeque;
 
-/// A queue with its elements in ascending order (unstrict)
-#[derive(Default)]
-pub struct MonotonicQueue<'a, T: PartialOrd> {
-    inner: VecDeque<&'a T>,
+/// A queue keeping its elements in ascending order (unstrict) of a comparator, with the
+/// insertion index of each element, so the head is the minimum of the elements not expired.
+pub struct MonotonicQueue<T, F = fn(&T, &T) -> Ordering> {
+    inner: VecDeque<(usize, T)>,
+    /// Insertion index of the next element.
+    next: usize,
+    compare: F,
 }
 
-impl<'a, T: PartialOrd> MonotonicQueue<'a, T> {
-    /// Creates an empty MonotonicQueue.
+impl<T: Ord> MonotonicQueue<T> {
+    /// Creates an empty MonotonicQueue with the natural order, whose head is the minimum.
     ///
     /// # Examples
     ///
     /// ```
     /// use rsalgo::ds::MonotonicQueue;
     ///
-    /// let vector: MonotonicQueue<u32> = MonotonicQueue::new();
+    /// let queue: MonotonicQueue<u32> = MonotonicQueue::new();
     /// ```
     pub fn new() -> Self {
+        Self::with_comparator(T::cmp)
+    }
+}
+
+impl<T: Ord> Default for MonotonicQueue<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T, F> MonotonicQueue<T, F>
+where
+    F: Fn(&T, &T) -> Ordering,
+{
+    /// Creates an empty MonotonicQueue ordered by `compare`, reverse the order for the maximum.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rsalgo::ds::MonotonicQueue;
+    ///
+    /// let mut queue = MonotonicQueue::with_comparator(|a: &u32, b: &u32| b.cmp(a));
+    /// queue.enqueue(1);
+    /// queue.enqueue(3);
+    /// assert_eq!(Some((1, &3)), queue.head());
+    /// ```
+    pub fn with_comparator(compare: F) -> Self {
         MonotonicQueue {
             inner: VecDeque::new(),
+            next: 0,
+            compare,
         }
     }
 
@@ -50,103 +83,218 @@ impl<'a, T: PartialOrd> MonotonicQueue<'a, T> {
         self.inner.is_empty()
     }
 
-    /// Clears the MonotonicQueue, removing all values.
+    /// Clears the MonotonicQueue, removing all values, the insertion index is kept.
     ///
     /// # Examples
     ///
     /// ```
     /// use rsalgo::ds::MonotonicQueue;
     ///
-    /// let mut v: MonotonicQueue<u32> = MonotonicQueue::new();
-    /// v.enqueue(&1);
+    /// let mut v = MonotonicQueue::new();
+    /// v.enqueue(1);
     /// v.clear();
     /// assert!(v.is_empty());
+    /// assert_eq!(1, v.enqueue(2));
     /// ```
     pub fn clear(&mut self) {
         self.inner.clear()
     }
 
-    /// Appends an element to the back of the MonotonicQueue.
+    /// Appends an element to the back of the MonotonicQueue, returns its insertion index.
     ///
     /// # Examples
     ///
     /// ```
-    /// use rsalgo::ds::MonotonicQueue;;
+    /// use rsalgo::ds::MonotonicQueue;
     ///
     /// let mut buf = MonotonicQueue::new();
-    /// buf.enqueue(&1);
-    /// buf.enqueue(&3); // removed when enqueue `2`
-    /// buf.enqueue(&2);
+    /// assert_eq!(0, buf.enqueue(1));
+    /// buf.enqueue(3); // removed when enqueue `2`
+    /// assert_eq!(2, buf.enqueue(2));
     /// assert_eq!(2, buf.len());
     /// ```
-    pub fn enqueue(&mut self, value: &'a T) {
-        while let Some(back) = self.inner.back() {
-            if back <= &value {
+    pub fn enqueue(&mut self, value: T) -> usize {
+        while let Some((_, back)) = self.inner.back() {
+            if (self.compare)(back, &value) != Ordering::Greater {
                 break;
             }
             self.inner.pop_back();
         }
-        self.inner.push_back(value);
+        let index = self.next;
+        self.inner.push_back((index, value));
+        self.next += 1;
+        index
     }
 
-    /// Removes the first element and returns it, or None if the MonotonicQueue is empty.
+    /// Removes the first element and returns it with its insertion index, or None if the
+    /// MonotonicQueue is empty.
     ///
     /// # Examples
     ///
     /// ```
     /// use rsalgo::ds::MonotonicQueue;
     /// let mut d = MonotonicQueue::new();
-    /// d.enqueue(&1);
-    /// d.enqueue(&2);
+    /// d.enqueue(1);
+    /// d.enqueue(2);
     ///
-    /// assert_eq!(d.dequeue(), Some(&1));
-    /// assert_eq!(d.dequeue(), Some(&2));
+    /// assert_eq!(d.dequeue(), Some((0, 1)));
+    /// assert_eq!(d.dequeue(), Some((1, 2)));
     /// assert_eq!(d.dequeue(), None);
     /// ```
-    pub fn dequeue(&mut self) -> Option<&T> {
+    pub fn dequeue(&mut self) -> Option<(usize, T)> {
         self.inner.pop_front()
     }
 
-    /// Provides a reference to the front element, or None if the MonotonicQueue is empty.
+    /// Removes the elements whose insertion index is less than `before_index`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rsalgo::ds::MonotonicQueue;
+    /// let mut d = MonotonicQueue::new();
+    /// d.enqueue(1);
+    /// d.enqueue(3);
+    /// d.enqueue(2);
+    ///
+    /// d.pop_expired(1);
+    /// assert_eq!(d.head(), Some((2, &2)));
+    /// ```
+    pub fn pop_expired(&mut self, before_index: usize) {
+        while let Some((index, _)) = self.inner.front() {
+            if *index >= before_index {
+                break;
+            }
+            self.inner.pop_front();
+        }
+    }
+
+    /// Provides the front element with its insertion index, or None if the MonotonicQueue is empty.
     ///
     /// # Examples
     ///
     /// ```
     /// use rsalgo::ds::MonotonicQueue;
     /// let mut d = MonotonicQueue::new();
-    /// d.enqueue(&1);
-    /// d.enqueue(&2);
+    /// d.enqueue(1);
+    /// d.enqueue(2);
     ///
-    /// assert_eq!(d.head(), Some(&&1));
+    /// assert_eq!(d.head(), Some((0, &1)));
     /// ```
-    pub fn head(&self) -> Option<&&T> {
-        self.inner.front()
+    pub fn head(&self) -> Option<(usize, &T)> {
+        self.inner.front().map(|(index, value)| (*index, value))
+    }
+}
+
+/// Iterator over the extreme value of each window of a slice, created by `sliding_window_min`
+/// or `sliding_window_max`.
+pub struct SlidingWindow<'a, T> {
+    values: &'a [T],
+    window: usize,
+    queue: MonotonicQueue<&'a T, fn(&&'a T, &&'a T) -> Ordering>,
+}
+
+impl<'a, T> Iterator for SlidingWindow<'a, T> {
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<&'a T> {
+        while self.queue.next < self.values.len() {
+            let index = self.queue.enqueue(&self.values[self.queue.next]);
+            if index + 1 >= self.window {
+                self.queue.pop_expired(index + 1 - self.window);
+                return self.queue.head().map(|(_, value)| *value);
+            }
+        }
+        None
     }
 }
 
+fn sliding_window<'a, T>(
+    values: &'a [T],
+    window: usize,
+    compare: fn(&&'a T, &&'a T) -> Ordering,
+) -> SlidingWindow<'a, T> {
+    assert!(window > 0, "The window should not be empty.");
+    SlidingWindow {
+        values,
+        window,
+        queue: MonotonicQueue::with_comparator(compare),
+    }
+}
+
+/// Gets the minimum of each window of length `window` in `values`, from left to right.
+///
+/// # Examples
+///
+/// ```
+/// use rsalgo::ds::sliding_window_min;
+///
+/// let mins: Vec<_> = sliding_window_min(&[4, 2, 5, 3, 1], 3).cloned().collect();
+/// assert_eq!(vec![2, 2, 1], mins);
+/// ```
+pub fn sliding_window_min<T: Ord>(values: &[T], window: usize) -> SlidingWindow<'_, T> {
+    sliding_window(values, window, |a, b| a.cmp(b))
+}
+
+/// Gets the maximum of each window of length `window` in `values`, from left to right.
+///
+/// # Examples
+///
+/// ```
+/// use rsalgo::ds::sliding_window_max;
+///
+/// let maxs: Vec<_> = sliding_window_max(&[4, 2, 5, 3, 1], 3).cloned().collect();
+/// assert_eq!(vec![5, 5, 5], maxs);
+/// ```
+pub fn sliding_window_max<T: Ord>(values: &[T], window: usize) -> SlidingWindow<'_, T> {
+    sliding_window(values, window, |a, b| b.cmp(a))
+}
+
 #[cfg(test)]
 mod tests {
-    use super::MonotonicQueue;
+    use super::{sliding_window_max, sliding_window_min, MonotonicQueue};
+    use rand::Rng;
 
     #[test]
     fn monotonic_queue() {
         let a = [1, 2, 2, 1, 3, 9, 4, 5, 20, 80];
-        let expect = [1, 1, 3, 4, 5, 20, 80];
+        let expect = [(0, 1), (3, 1), (4, 3), (6, 4), (7, 5), (8, 20), (9, 80)];
 
         let mut q: MonotonicQueue<u32> = MonotonicQueue::new();
         assert!(q.is_empty());
 
-        for x in &a {
+        for &x in &a {
             q.enqueue(x);
         }
 
         assert_eq!(q.len(), expect.len());
 
-        for x in &expect {
-            assert_eq!(&x, q.head().unwrap());
-            assert_eq!(x, q.dequeue().unwrap());
+        for &(index, x) in &expect {
+            assert_eq!(Some((index, &x)), q.head());
+            assert_eq!(Some((index, x)), q.dequeue());
         }
 
         assert!(q.is_empty());
     }
+
+    #[test]
+    fn sliding_window() {
+        let mut rng = rand::thread_rng();
+        for _ in 0..50 {
+            let values: Vec<i32> = (0..rng.gen_range(0, 40))
+                .map(|_| rng.gen_range(-10, 10))
+                .collect();
+            let window = rng.gen_range(1, 10);
+            let windows = values.windows(window);
+            let mins: Vec<_> = windows.clone().map(|w| w.iter().min().unwrap()).collect();
+            let maxs: Vec<_> = windows.map(|w| w.iter().max().unwrap()).collect();
+            assert_eq!(
+                mins,
+                sliding_window_min(&values, window).collect::<Vec<_>>()
+            );
+            assert_eq!(
+                maxs,
+                sliding_window_max(&values, window).collect::<Vec<_>>()
+            );
+        }
+    }
 }
@@ -0,0 +1,360 @@
+use std::cmp::Ordering;
+
+/// A stack keeping its elements in strictly ascending order of a comparator, with the insertion
+/// index of each element, so the element below a pushed one is its nearest previous smaller one.
+pub struct MonotonicStack<T, F = fn(&T, &T) -> Ordering> {
+    inner: Vec<(usize, T)>,
+    /// Insertion index of the next element.
+    next: usize,
+    compare: F,
+}
+
+impl<T: Ord> MonotonicStack<T> {
+    /// Creates an empty MonotonicStack with the natural order.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rsalgo::ds::MonotonicStack;
+    ///
+    /// let stack: MonotonicStack<u32> = MonotonicStack::new();
+    /// ```
+    pub fn new() -> Self {
+        Self::with_comparator(T::cmp)
+    }
+}
+
+impl<T: Ord> Default for MonotonicStack<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T, F> MonotonicStack<T, F>
+where
+    F: Fn(&T, &T) -> Ordering,
+{
+    /// Creates an empty MonotonicStack ordered by `compare`, reverse the order for descending.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rsalgo::ds::MonotonicStack;
+    ///
+    /// let mut stack = MonotonicStack::with_comparator(|a: &u32, b: &u32| b.cmp(a));
+    /// stack.push(3);
+    /// assert_eq!(Some(0), stack.push(1));
+    /// ```
+    pub fn with_comparator(compare: F) -> Self {
+        MonotonicStack {
+            inner: Vec::new(),
+            next: 0,
+            compare,
+        }
+    }
+
+    /// Returns the number of elements.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rsalgo::ds::MonotonicStack;
+    ///
+    /// let stack: MonotonicStack<u32> = MonotonicStack::new();
+    /// assert_eq!(0, stack.len());
+    /// ```
+    pub fn len(&self) -> usize {
+        self.inner.len()
+    }
+
+    /// Returns true if the MonotonicStack is empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rsalgo::ds::MonotonicStack;
+    ///
+    /// let stack: MonotonicStack<u32> = MonotonicStack::new();
+    /// assert!(stack.is_empty());
+    /// ```
+    pub fn is_empty(&self) -> bool {
+        self.inner.is_empty()
+    }
+
+    /// Clears the MonotonicStack, removing all values, the insertion index is kept.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rsalgo::ds::MonotonicStack;
+    ///
+    /// let mut stack = MonotonicStack::new();
+    /// stack.push(1);
+    /// stack.clear();
+    /// assert!(stack.is_empty());
+    /// ```
+    pub fn clear(&mut self) {
+        self.inner.clear()
+    }
+
+    /// Pushes an element after popping the elements not less than it, returns the insertion
+    /// index of the element below it, or None if it is at the bottom.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rsalgo::ds::MonotonicStack;
+    ///
+    /// let mut stack = MonotonicStack::new();
+    /// assert_eq!(None, stack.push(1));
+    /// assert_eq!(Some(0), stack.push(3));
+    /// assert_eq!(Some(0), stack.push(2)); // `3` is popped
+    /// assert_eq!(None, stack.push(1)); // `1` and `2` are popped
+    /// assert_eq!(1, stack.len());
+    /// ```
+    pub fn push(&mut self, value: T) -> Option<usize> {
+        while let Some((_, top)) = self.inner.last() {
+            if (self.compare)(top, &value) == Ordering::Less {
+                break;
+            }
+            self.inner.pop();
+        }
+        let below = self.inner.last().map(|(index, _)| *index);
+        self.inner.push((self.next, value));
+        self.next += 1;
+        below
+    }
+
+    /// Removes the top element and returns it with its insertion index, or None if the
+    /// MonotonicStack is empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rsalgo::ds::MonotonicStack;
+    ///
+    /// let mut stack = MonotonicStack::new();
+    /// stack.push(1);
+    /// stack.push(2);
+    /// assert_eq!(Some((1, 2)), stack.pop());
+    /// assert_eq!(Some((0, 1)), stack.pop());
+    /// assert_eq!(None, stack.pop());
+    /// ```
+    pub fn pop(&mut self) -> Option<(usize, T)> {
+        self.inner.pop()
+    }
+
+    /// Provides the top element with its insertion index, or None if the MonotonicStack is empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rsalgo::ds::MonotonicStack;
+    ///
+    /// let mut stack = MonotonicStack::new();
+    /// stack.push(1);
+    /// stack.push(2);
+    /// assert_eq!(Some((1, &2)), stack.top());
+    /// ```
+    pub fn top(&self) -> Option<(usize, &T)> {
+        self.inner.last().map(|(index, value)| (*index, value))
+    }
+}
+
+fn nearest<'a, T, F>(values: &'a [T], reversed: bool, compare: F) -> Vec<Option<usize>>
+where
+    F: Fn(&&'a T, &&'a T) -> Ordering,
+{
+    let n = values.len();
+    let mut stack = MonotonicStack::with_comparator(compare);
+    let mut res = vec![None; n];
+    for i in 0..n {
+        let i = if reversed { n - 1 - i } else { i };
+        let below = stack.push(&values[i]);
+        res[i] = if reversed {
+            below.map(|j| n - 1 - j)
+        } else {
+            below
+        };
+    }
+    res
+}
+
+/// Gets the nearest position `j < i` with `values[j] < values[i]` for each position `i`.
+///
+/// # Examples
+///
+/// ```
+/// use rsalgo::ds::previous_smaller;
+///
+/// assert_eq!(vec![None, None, Some(1), Some(1)], previous_smaller(&[3, 1, 4, 4]));
+/// ```
+pub fn previous_smaller<T: Ord>(values: &[T]) -> Vec<Option<usize>> {
+    nearest(values, false, |a, b| a.cmp(b))
+}
+
+/// Gets the nearest position `j > i` with `values[j] < values[i]` for each position `i`.
+///
+/// # Examples
+///
+/// ```
+/// use rsalgo::ds::next_smaller;
+///
+/// assert_eq!(vec![Some(1), None, Some(3), None], next_smaller(&[3, 1, 4, 2]));
+/// ```
+pub fn next_smaller<T: Ord>(values: &[T]) -> Vec<Option<usize>> {
+    nearest(values, true, |a, b| a.cmp(b))
+}
+
+/// Gets the nearest position `j < i` with `values[j] > values[i]` for each position `i`.
+///
+/// # Examples
+///
+/// ```
+/// use rsalgo::ds::previous_greater;
+///
+/// assert_eq!(vec![None, Some(0), None, Some(2)], previous_greater(&[3, 1, 4, 2]));
+/// ```
+pub fn previous_greater<T: Ord>(values: &[T]) -> Vec<Option<usize>> {
+    nearest(values, false, |a, b| b.cmp(a))
+}
+
+/// Gets the nearest position `j > i` with `values[j] > values[i]` for each position `i`.
+///
+/// # Examples
+///
+/// ```
+/// use rsalgo::ds::next_greater;
+///
+/// assert_eq!(vec![Some(2), Some(2), None, None], next_greater(&[3, 1, 4, 4]));
+/// ```
+pub fn next_greater<T: Ord>(values: &[T]) -> Vec<Option<usize>> {
+    nearest(values, true, |a, b| b.cmp(a))
+}
+
+/// Gets the area of the largest rectangle in the histogram of `heights` in O(n).
+///
+/// # Examples
+///
+/// ```
+/// use rsalgo::ds::largest_rectangle;
+///
+/// assert_eq!(10, largest_rectangle(&[2, 1, 5, 6, 2, 3]));
+/// ```
+pub fn largest_rectangle(heights: &[usize]) -> usize {
+    let previous = previous_smaller(heights);
+    let next = next_smaller(heights);
+    (0..heights.len())
+        .map(|i| {
+            let start = previous[i].map_or(0, |j| j + 1);
+            let end = next[i].unwrap_or(heights.len());
+            heights[i] * (end - start)
+        })
+        .max()
+        .unwrap_or(0)
+}
+
+/// Gets the area of the largest submatrix of all `true` in `matrix` in O(rows * cols).
+///
+/// # Examples
+///
+/// ```
+/// use rsalgo::ds::maximal_rectangle;
+///
+/// let matrix = vec![
+///     vec![true, false, true, false],
+///     vec![true, false, true, true],
+///     vec![true, true, true, true],
+/// ];
+/// assert_eq!(4, maximal_rectangle(&matrix));
+/// ```
+pub fn maximal_rectangle(matrix: &[Vec<bool>]) -> usize {
+    let cols = matrix.first().map_or(0, |row| row.len());
+    let mut heights = vec![0; cols];
+    let mut res = 0;
+    for row in matrix {
+        assert_eq!(cols, row.len(), "The grid is not rectangular.");
+        for (height, &cell) in heights.iter_mut().zip(row) {
+            *height = if cell { *height + 1 } else { 0 };
+        }
+        res = res.max(largest_rectangle(&heights));
+    }
+    res
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::Rng;
+
+    fn brute_nearest<F: Fn(usize, usize) -> bool>(
+        n: usize,
+        reversed: bool,
+        f: F,
+    ) -> Vec<Option<usize>> {
+        (0..n)
+            .map(|i| {
+                if reversed {
+                    (i + 1..n).find(|&j| f(i, j))
+                } else {
+                    (0..i).rev().find(|&j| f(i, j))
+                }
+            })
+            .collect()
+    }
+
+    #[test]
+    fn nearest() {
+        let mut rng = rand::thread_rng();
+        for _ in 0..50 {
+            let v: Vec<i32> = (0..rng.gen_range(0, 30))
+                .map(|_| rng.gen_range(0, 8))
+                .collect();
+            let n = v.len();
+            assert_eq!(
+                brute_nearest(n, false, |i, j| v[j] < v[i]),
+                previous_smaller(&v)
+            );
+            assert_eq!(brute_nearest(n, true, |i, j| v[j] < v[i]), next_smaller(&v));
+            assert_eq!(
+                brute_nearest(n, false, |i, j| v[j] > v[i]),
+                previous_greater(&v)
+            );
+            assert_eq!(brute_nearest(n, true, |i, j| v[j] > v[i]), next_greater(&v));
+        }
+    }
+
+    #[test]
+    fn rectangle() {
+        let mut rng = rand::thread_rng();
+        for _ in 0..50 {
+            let (rows, cols) = (rng.gen_range(0, 8), rng.gen_range(1, 8));
+            let matrix: Vec<Vec<bool>> = (0..rows)
+                .map(|_| (0..cols).map(|_| rng.gen_ratio(3, 4)).collect())
+                .collect();
+
+            let mut expected = 0;
+            for r0 in 0..rows {
+                for r1 in r0 + 1..=rows {
+                    for c0 in 0..cols {
+                        for c1 in c0 + 1..=cols {
+                            if matrix[r0..r1]
+                                .iter()
+                                .all(|row| row[c0..c1].iter().all(|&x| x))
+                            {
+                                expected = expected.max((r1 - r0) * (c1 - c0));
+                            }
+                        }
+                    }
+                }
+            }
+            assert_eq!(expected, maximal_rectangle(&matrix));
+        }
+    }
+
+    #[test]
+    #[should_panic]
+    fn jagged_rectangle() {
+        maximal_rectangle(&[vec![true, true], vec![true]]);
+    }
+}
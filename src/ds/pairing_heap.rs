@@ -0,0 +1,183 @@
+use super::meldable_heap::drop_tree;
+
+struct Node<T> {
+    value: T,
+    children: Vec<Box<Node<T>>>,
+}
+
+fn meld<T: Ord>(a: Box<Node<T>>, b: Box<Node<T>>) -> Box<Node<T>> {
+    let (mut root, child) = if a.value <= b.value { (a, b) } else { (b, a) };
+    root.children.push(child);
+    root
+}
+
+/// Pairing min-heap, supporting push and merge in O(1), and pop in amortized O(log n).
+pub struct PairingHeap<T: Ord> {
+    root: Option<Box<Node<T>>>,
+    len: usize,
+}
+
+impl<T: Ord> Default for PairingHeap<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: Ord> PairingHeap<T> {
+    /// Creates an empty PairingHeap.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rsalgo::ds::PairingHeap;
+    ///
+    /// let heap: PairingHeap<u32> = PairingHeap::new();
+    /// ```
+    pub fn new() -> Self {
+        PairingHeap { root: None, len: 0 }
+    }
+
+    /// Returns the number of values.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rsalgo::ds::PairingHeap;
+    ///
+    /// let mut heap = PairingHeap::new();
+    /// heap.push(1);
+    /// assert_eq!(1, heap.len());
+    /// ```
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns true if the PairingHeap contains no values.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rsalgo::ds::PairingHeap;
+    ///
+    /// let heap: PairingHeap<u32> = PairingHeap::new();
+    /// assert!(heap.is_empty());
+    /// ```
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Provides the minimum value, or None if the PairingHeap is empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rsalgo::ds::PairingHeap;
+    ///
+    /// let mut heap = PairingHeap::new();
+    /// heap.push(3);
+    /// heap.push(1);
+    /// assert_eq!(Some(&1), heap.peek());
+    /// ```
+    pub fn peek(&self) -> Option<&T> {
+        self.root.as_ref().map(|node| &node.value)
+    }
+
+    /// Pushes `value` into the PairingHeap.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rsalgo::ds::PairingHeap;
+    ///
+    /// let mut heap = PairingHeap::new();
+    /// heap.push(3);
+    /// assert_eq!(Some(&3), heap.peek());
+    /// ```
+    pub fn push(&mut self, value: T) {
+        let node = Box::new(Node {
+            value,
+            children: Vec::new(),
+        });
+        self.root = Some(match self.root.take() {
+            Some(root) => meld(root, node),
+            None => node,
+        });
+        self.len += 1;
+    }
+
+    /// Removes the minimum value and returns it, or None if the PairingHeap is empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rsalgo::ds::PairingHeap;
+    ///
+    /// let mut heap = PairingHeap::new();
+    /// heap.push(3);
+    /// heap.push(1);
+    /// assert_eq!(Some(1), heap.pop());
+    /// assert_eq!(Some(3), heap.pop());
+    /// assert_eq!(None, heap.pop());
+    /// ```
+    pub fn pop(&mut self) -> Option<T> {
+        let root = *self.root.take()?;
+        let mut children = root.children.into_iter();
+        let mut pairs = Vec::new();
+        while let Some(a) = children.next() {
+            pairs.push(match children.next() {
+                Some(b) => meld(a, b),
+                None => a,
+            });
+        }
+        self.root = pairs.into_iter().rev().reduce(|res, node| meld(node, res));
+        self.len -= 1;
+        Some(root.value)
+    }
+
+    /// Moves all values of `other` into the PairingHeap in O(1).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rsalgo::ds::PairingHeap;
+    ///
+    /// let mut heap = PairingHeap::new();
+    /// heap.push(3);
+    /// let mut other = PairingHeap::new();
+    /// other.push(1);
+    /// heap.append(&mut other);
+    /// assert!(other.is_empty());
+    /// assert_eq!(Some(1), heap.pop());
+    /// ```
+    pub fn append(&mut self, other: &mut Self) {
+        self.root = match (self.root.take(), other.root.take()) {
+            (Some(a), Some(b)) => Some(meld(a, b)),
+            (a, b) => a.or(b),
+        };
+        self.len += std::mem::replace(&mut other.len, 0);
+    }
+}
+
+impl<T: Ord> Drop for PairingHeap<T> {
+    fn drop(&mut self) {
+        drop_tree(self.root.take(), |node, stack| {
+            stack.append(&mut node.children)
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::PairingHeap;
+    use crate::ds::meldable_heap::tests::{check_against_binary_heap, check_deep_drop};
+
+    #[test]
+    fn pairing_heap() {
+        check_against_binary_heap::<PairingHeap<i32>>();
+    }
+
+    #[test]
+    fn deep_drop() {
+        check_deep_drop::<PairingHeap<i32>>();
+    }
+}
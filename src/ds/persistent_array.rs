@@ -0,0 +1,203 @@
+struct Node<T> {
+    left: usize,
+    right: usize,
+    value: Option<T>,
+}
+
+/// Fully persistent array by path copying, each update creates a new version in O(log n).
+pub struct PersistentArray<T: Clone> {
+    len: usize,
+    nodes: Vec<Node<T>>,
+    /// Root node of each version.
+    roots: Vec<usize>,
+}
+
+impl<T: Clone> PersistentArray<T> {
+    /// Creates a PersistentArray whose version `0` contains `values`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rsalgo::ds::PersistentArray;
+    ///
+    /// let a = PersistentArray::new(&[1, 2, 3]);
+    /// assert_eq!(2, *a.get(0, 1));
+    /// ```
+    pub fn new(values: &[T]) -> Self {
+        let mut res = PersistentArray {
+            len: values.len(),
+            nodes: Vec::with_capacity(2 * values.len()),
+            roots: Vec::new(),
+        };
+        let root = res.build(values);
+        res.roots.push(root);
+        res
+    }
+
+    fn build(&mut self, values: &[T]) -> usize {
+        let node = if values.len() <= 1 {
+            Node {
+                left: 0,
+                right: 0,
+                value: values.first().cloned(),
+            }
+        } else {
+            let mid = values.len() / 2;
+            Node {
+                left: self.build(&values[..mid]),
+                right: self.build(&values[mid..]),
+                value: None,
+            }
+        };
+        self.nodes.push(node);
+        self.nodes.len() - 1
+    }
+
+    /// Returns the length of the array.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rsalgo::ds::PersistentArray;
+    ///
+    /// let a = PersistentArray::new(&[1, 2, 3]);
+    /// assert_eq!(3, a.len());
+    /// ```
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns true if the array is empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rsalgo::ds::PersistentArray;
+    ///
+    /// let a: PersistentArray<u32> = PersistentArray::new(&[]);
+    /// assert!(a.is_empty());
+    /// ```
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Returns the number of versions.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rsalgo::ds::PersistentArray;
+    ///
+    /// let mut a = PersistentArray::new(&[1, 2, 3]);
+    /// a.set(0, 0, 5);
+    /// assert_eq!(2, a.versions());
+    /// ```
+    pub fn versions(&self) -> usize {
+        self.roots.len()
+    }
+
+    /// Gets the value at `index` in `version`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rsalgo::ds::PersistentArray;
+    ///
+    /// let mut a = PersistentArray::new(&[1, 2, 3]);
+    /// let v = a.set(0, 2, 5);
+    /// assert_eq!(3, *a.get(0, 2));
+    /// assert_eq!(5, *a.get(v, 2));
+    /// ```
+    pub fn get(&self, version: usize, index: usize) -> &T {
+        assert!(index < self.len, "The index is out of range.");
+        let (mut v, mut lo, mut hi) = (self.roots[version], 0, self.len);
+        while hi - lo > 1 {
+            let mid = lo + (hi - lo) / 2;
+            if index < mid {
+                v = self.nodes[v].left;
+                hi = mid;
+            } else {
+                v = self.nodes[v].right;
+                lo = mid;
+            }
+        }
+        self.nodes[v].value.as_ref().unwrap()
+    }
+
+    /// Creates a new version from `version` with the value at `index` set to `value`, returns the new version.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rsalgo::ds::PersistentArray;
+    ///
+    /// let mut a = PersistentArray::new(&[1, 2, 3]);
+    /// let v1 = a.set(0, 0, 4);
+    /// let v2 = a.set(v1, 1, 5);
+    /// let v3 = a.set(0, 1, 6);
+    /// assert_eq!(vec![4, 5, 3], (0..3).map(|i| *a.get(v2, i)).collect::<Vec<_>>());
+    /// assert_eq!(vec![1, 6, 3], (0..3).map(|i| *a.get(v3, i)).collect::<Vec<_>>());
+    /// ```
+    pub fn set(&mut self, version: usize, index: usize, value: T) -> usize {
+        assert!(index < self.len, "The index is out of range.");
+        let root = self.update(self.roots[version], 0, self.len, index, value);
+        self.roots.push(root);
+        self.roots.len() - 1
+    }
+
+    fn update(&mut self, v: usize, lo: usize, hi: usize, index: usize, value: T) -> usize {
+        let node = if hi - lo == 1 {
+            Node {
+                left: 0,
+                right: 0,
+                value: Some(value),
+            }
+        } else {
+            let mid = lo + (hi - lo) / 2;
+            let (mut left, mut right) = (self.nodes[v].left, self.nodes[v].right);
+            if index < mid {
+                left = self.update(left, lo, mid, index, value);
+            } else {
+                right = self.update(right, mid, hi, index, value);
+            }
+            Node {
+                left,
+                right,
+                value: None,
+            }
+        };
+        self.nodes.push(node);
+        self.nodes.len() - 1
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::PersistentArray;
+    use rand::Rng;
+
+    #[test]
+    fn persistent_array() {
+        let mut rng = rand::thread_rng();
+        for &len in &[1, 2, 7, 50] {
+            let initial: Vec<u32> = (0..len).map(|_| rng.gen()).collect();
+            let mut a = PersistentArray::new(&initial);
+            let mut expected = vec![initial];
+
+            for _ in 0..200 {
+                let version = rng.gen_range(0, expected.len());
+                let (index, value) = (rng.gen_range(0, len), rng.gen());
+                let mut next = expected[version].clone();
+                next[index] = value;
+                assert_eq!(expected.len(), a.set(version, index, value));
+                expected.push(next);
+            }
+
+            for (version, values) in expected.iter().enumerate() {
+                for (index, value) in values.iter().enumerate() {
+                    assert_eq!(value, a.get(version, index));
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,185 @@
+use super::PersistentArray;
+
+/// Fully persistent disjoint set on a persistent array, union by rank without path compression.
+///
+/// Version `0` has all elements in their own sets, and each `unite` creates a new version.
+pub struct PersistentDisjointSet {
+    /// Parent and rank of each element.
+    nodes: PersistentArray<(usize, usize)>,
+    /// Array version and the number of sets of each version.
+    versions: Vec<(usize, usize)>,
+}
+
+impl PersistentDisjointSet {
+    /// Create a persistent disjoint set with `capacity` elements.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let s = rsalgo::ds::PersistentDisjointSet::new(100);
+    /// ```
+    pub fn new(capacity: usize) -> Self {
+        let nodes: Vec<(usize, usize)> = (0..capacity).map(|i| (i, 0)).collect();
+        PersistentDisjointSet {
+            nodes: PersistentArray::new(&nodes),
+            versions: vec![(0, capacity)],
+        }
+    }
+
+    /// Get the number of elements.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let s = rsalgo::ds::PersistentDisjointSet::new(100);
+    ///
+    /// assert_eq!(100, s.capacity());
+    /// ```
+    pub fn capacity(&self) -> usize {
+        self.nodes.len()
+    }
+
+    /// Returns true if the set contains no elements.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let s = rsalgo::ds::PersistentDisjointSet::new(0);
+    ///
+    /// assert!(s.is_empty());
+    /// ```
+    pub fn is_empty(&self) -> bool {
+        self.nodes.is_empty()
+    }
+
+    /// Returns the number of versions.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let mut s = rsalgo::ds::PersistentDisjointSet::new(100);
+    /// s.unite(0, 1, 2);
+    /// assert_eq!(2, s.versions());
+    /// ```
+    pub fn versions(&self) -> usize {
+        self.versions.len()
+    }
+
+    /// Get the number of disjoint sets in `version`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let mut s = rsalgo::ds::PersistentDisjointSet::new(100);
+    /// let v = s.unite(0, 1, 2);
+    /// assert_eq!(100, s.len(0));
+    /// assert_eq!(99, s.len(v));
+    /// ```
+    pub fn len(&self, version: usize) -> usize {
+        self.versions[version].1
+    }
+
+    /// Get the representation element of `id`'s set in `version` in O(log^2 n).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let s = rsalgo::ds::PersistentDisjointSet::new(100);
+    ///
+    /// assert_eq!(0, s.find(0, 0));
+    /// ```
+    pub fn find(&self, version: usize, id: usize) -> usize {
+        let array = self.versions[version].0;
+        let mut id = id;
+        loop {
+            let parent = self.nodes.get(array, id).0;
+            if parent == id {
+                return id;
+            }
+            id = parent;
+        }
+    }
+
+    /// Return whether the two element is in the same set in `version`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let s = rsalgo::ds::PersistentDisjointSet::new(100);
+    ///
+    /// assert_eq!(false, s.in_same(0, 0, 1));
+    /// ```
+    pub fn in_same(&self, version: usize, a: usize, b: usize) -> bool {
+        self.find(version, a) == self.find(version, b)
+    }
+
+    /// Unite `a`'s set and `b`'s set based on `version`, returns the new version.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let mut s = rsalgo::ds::PersistentDisjointSet::new(100);
+    /// let v1 = s.unite(0, 0, 1);
+    /// let v2 = s.unite(v1, 1, 2);
+    /// let v3 = s.unite(0, 2, 3);
+    /// assert!(s.in_same(v2, 0, 2));
+    /// assert!(!s.in_same(v1, 0, 2));
+    /// assert!(!s.in_same(v3, 0, 3));
+    /// assert!(s.in_same(v3, 2, 3));
+    /// ```
+    pub fn unite(&mut self, version: usize, a: usize, b: usize) -> usize {
+        let (mut array, mut size) = self.versions[version];
+        let (mut a, mut b) = (self.find(version, a), self.find(version, b));
+        if a != b {
+            let (mut ra, mut rb) = (self.nodes.get(array, a).1, self.nodes.get(array, b).1);
+            if ra < rb {
+                std::mem::swap(&mut a, &mut b);
+                std::mem::swap(&mut ra, &mut rb);
+            }
+            array = self.nodes.set(array, b, (a, rb));
+            if ra == rb {
+                array = self.nodes.set(array, a, (a, ra + 1));
+            }
+            size -= 1;
+        }
+        self.versions.push((array, size));
+        self.versions.len() - 1
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::PersistentDisjointSet;
+    use crate::ds::DisjointSet;
+    use rand::Rng;
+
+    #[test]
+    fn persistent_disjoint_set() {
+        let mut rng = rand::thread_rng();
+        const SIZE: usize = 30;
+
+        let mut s = PersistentDisjointSet::new(SIZE);
+        let mut edges: Vec<Vec<(usize, usize)>> = vec![Vec::new()];
+
+        for _ in 0..200 {
+            let version = rng.gen_range(0, edges.len());
+            let (a, b) = (rng.gen_range(0, SIZE), rng.gen_range(0, SIZE));
+            let mut next = edges[version].clone();
+            next.push((a, b));
+            assert_eq!(edges.len(), s.unite(version, a, b));
+            edges.push(next);
+        }
+
+        for (version, edges) in edges.iter().enumerate() {
+            let mut expected = DisjointSet::new(SIZE, true);
+            for &(a, b) in edges {
+                expected.unite(a, b);
+            }
+            assert_eq!(expected.len(), s.len(version));
+            for _ in 0..10 {
+                let (a, b) = (rng.gen_range(0, SIZE), rng.gen_range(0, SIZE));
+                assert_eq!(expected.in_same(a, b), s.in_same(version, a, b));
+            }
+        }
+    }
+}
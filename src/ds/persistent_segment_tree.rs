@@ -0,0 +1,407 @@
+use super::algebra::{Monoid, Sum};
+use crate::base::Discretizer;
+use std::ops::Range;
+
+struct Node<T> {
+    left: usize,
+    right: usize,
+    value: T,
+}
+
+/// Persistent segment tree over a monoid with nodes in an arena, each update creates a new version.
+pub struct PersistentSegmentTree<T, M = Sum<T>>
+where
+    T: Clone,
+    M: Monoid<Item = T>,
+{
+    len: usize,
+    nodes: Vec<Node<T>>,
+    /// Root node of each version.
+    roots: Vec<usize>,
+    monoid: std::marker::PhantomData<M>,
+}
+
+impl<T, M> PersistentSegmentTree<T, M>
+where
+    T: Clone,
+    M: Monoid<Item = T>,
+{
+    /// Creates a PersistentSegmentTree whose version `0` contains `values`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rsalgo::ds::PersistentSegmentTree;
+    ///
+    /// let st: PersistentSegmentTree<i32> = PersistentSegmentTree::new(&[1, 2, 3]);
+    /// assert_eq!(6, st.query(0, 0..3));
+    /// ```
+    pub fn new(values: &[T]) -> Self {
+        let mut st = PersistentSegmentTree {
+            len: values.len(),
+            nodes: Vec::with_capacity(2 * values.len()),
+            roots: Vec::new(),
+            monoid: std::marker::PhantomData,
+        };
+        let root = st.build(values);
+        st.roots.push(root);
+        st
+    }
+
+    fn build(&mut self, values: &[T]) -> usize {
+        let node = match values.len() {
+            0 => Node {
+                left: 0,
+                right: 0,
+                value: M::identity(),
+            },
+            1 => Node {
+                left: 0,
+                right: 0,
+                value: values[0].clone(),
+            },
+            len => {
+                let left = self.build(&values[..len / 2]);
+                let right = self.build(&values[len / 2..]);
+                Node {
+                    left,
+                    right,
+                    value: M::op(&self.nodes[left].value, &self.nodes[right].value),
+                }
+            }
+        };
+        self.nodes.push(node);
+        self.nodes.len() - 1
+    }
+
+    /// Returns the number of elements.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rsalgo::ds::PersistentSegmentTree;
+    ///
+    /// let st: PersistentSegmentTree<i32> = PersistentSegmentTree::new(&[1, 2, 3]);
+    /// assert_eq!(3, st.len());
+    /// ```
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns true if there are no elements.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rsalgo::ds::PersistentSegmentTree;
+    ///
+    /// let st: PersistentSegmentTree<i32> = PersistentSegmentTree::new(&[]);
+    /// assert!(st.is_empty());
+    /// ```
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Returns the number of versions.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rsalgo::ds::PersistentSegmentTree;
+    ///
+    /// let mut st: PersistentSegmentTree<i32> = PersistentSegmentTree::new(&[1, 2, 3]);
+    /// st.set(0, 1, 5);
+    /// assert_eq!(2, st.versions());
+    /// ```
+    pub fn versions(&self) -> usize {
+        self.roots.len()
+    }
+
+    /// Gets the result of the values in `range` of `version`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rsalgo::ds::PersistentSegmentTree;
+    ///
+    /// let mut st: PersistentSegmentTree<i32> = PersistentSegmentTree::new(&[1, 2, 3]);
+    /// let v = st.set(0, 1, 5);
+    /// assert_eq!(3, st.query(0, 0..2));
+    /// assert_eq!(6, st.query(v, 0..2));
+    /// assert_eq!(0, st.query(v, 1..1));
+    /// ```
+    pub fn query(&self, version: usize, range: Range<usize>) -> T {
+        assert!(
+            range.start <= range.end && range.end <= self.len,
+            "The range is invalid."
+        );
+        if range.start == range.end {
+            return M::identity();
+        }
+        self.query_node(self.roots[version], 0, self.len, &range)
+    }
+
+    fn query_node(&self, v: usize, lo: usize, hi: usize, range: &Range<usize>) -> T {
+        if range.start <= lo && hi <= range.end {
+            return self.nodes[v].value.clone();
+        }
+        let mid = lo + (hi - lo) / 2;
+        if range.end <= mid {
+            self.query_node(self.nodes[v].left, lo, mid, range)
+        } else if range.start >= mid {
+            self.query_node(self.nodes[v].right, mid, hi, range)
+        } else {
+            M::op(
+                &self.query_node(self.nodes[v].left, lo, mid, range),
+                &self.query_node(self.nodes[v].right, mid, hi, range),
+            )
+        }
+    }
+
+    /// Creates a new version from `version` with the value at `pos` set to `value`, returns the new version.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rsalgo::ds::PersistentSegmentTree;
+    ///
+    /// let mut st: PersistentSegmentTree<i32> = PersistentSegmentTree::new(&[1, 2, 3]);
+    /// let v1 = st.set(0, 0, 10);
+    /// let v2 = st.set(v1, 2, 20);
+    /// assert_eq!(32, st.query(v2, 0..3));
+    /// assert_eq!(15, st.query(v1, 0..3));
+    /// ```
+    pub fn set(&mut self, version: usize, pos: usize, value: T) -> usize {
+        self.modify(version, pos, |_| value)
+    }
+
+    /// Creates a new version from `version` with `delta` combined into the value at `pos`,
+    /// returns the new version.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rsalgo::ds::PersistentSegmentTree;
+    ///
+    /// let mut st: PersistentSegmentTree<i32> = PersistentSegmentTree::new(&[1, 2, 3]);
+    /// let v = st.add(0, 1, 10);
+    /// assert_eq!(12, st.query(v, 1..2));
+    /// ```
+    pub fn add(&mut self, version: usize, pos: usize, delta: T) -> usize {
+        self.modify(version, pos, |value| M::op(value, &delta))
+    }
+
+    fn modify<F: FnOnce(&T) -> T>(&mut self, version: usize, pos: usize, f: F) -> usize {
+        assert!(pos < self.len, "The index is out of range.");
+        let root = self.update(self.roots[version], 0, self.len, pos, f);
+        self.roots.push(root);
+        self.roots.len() - 1
+    }
+
+    fn update<F: FnOnce(&T) -> T>(
+        &mut self,
+        v: usize,
+        lo: usize,
+        hi: usize,
+        pos: usize,
+        f: F,
+    ) -> usize {
+        let node = if hi - lo == 1 {
+            Node {
+                left: 0,
+                right: 0,
+                value: f(&self.nodes[v].value),
+            }
+        } else {
+            let mid = lo + (hi - lo) / 2;
+            let (mut left, mut right) = (self.nodes[v].left, self.nodes[v].right);
+            if pos < mid {
+                left = self.update(left, lo, mid, pos, f);
+            } else {
+                right = self.update(right, mid, hi, pos, f);
+            }
+            Node {
+                left,
+                right,
+                value: M::op(&self.nodes[left].value, &self.nodes[right].value),
+            }
+        };
+        self.nodes.push(node);
+        self.nodes.len() - 1
+    }
+}
+
+impl PersistentSegmentTree<usize> {
+    /// Gets the smallest position `p` that the sum of `[0, p]` in `newer` minus that in `older`
+    /// is greater than `k`, where every value in `newer` is not less than that in `older`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rsalgo::ds::PersistentSegmentTree;
+    ///
+    /// let mut st: PersistentSegmentTree<usize> = PersistentSegmentTree::new(&[0; 4]);
+    /// let v1 = st.add(0, 1, 2);
+    /// let v2 = st.add(v1, 3, 1);
+    /// assert_eq!(Some(1), st.kth_difference(0, v2, 1));
+    /// assert_eq!(Some(3), st.kth_difference(0, v2, 2));
+    /// assert_eq!(Some(3), st.kth_difference(v1, v2, 0));
+    /// assert_eq!(None, st.kth_difference(0, v2, 3));
+    /// ```
+    pub fn kth_difference(&self, older: usize, newer: usize, k: usize) -> Option<usize> {
+        let (mut a, mut b) = (self.roots[older], self.roots[newer]);
+        if self.len == 0 || self.nodes[b].value - self.nodes[a].value <= k {
+            return None;
+        }
+        let (mut lo, mut hi, mut k) = (0, self.len, k);
+        while hi - lo > 1 {
+            let mid = lo + (hi - lo) / 2;
+            let (la, lb) = (self.nodes[a].left, self.nodes[b].left);
+            let left = self.nodes[lb].value - self.nodes[la].value;
+            if k < left {
+                a = la;
+                b = lb;
+                hi = mid;
+            } else {
+                k -= left;
+                a = self.nodes[a].right;
+                b = self.nodes[b].right;
+                lo = mid;
+            }
+        }
+        Some(lo)
+    }
+}
+
+/// K-th smallest value in any subarray by a persistent segment tree over discretized values.
+pub struct RangeKth<T: Ord + Clone> {
+    discretizer: Discretizer<T>,
+    /// Version `i` counts the ranks of the first `i` values.
+    counts: PersistentSegmentTree<usize>,
+}
+
+impl<T: Ord + Clone> RangeKth<T> {
+    /// Builds a RangeKth of `values` in O(n log n).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rsalgo::ds::RangeKth;
+    ///
+    /// let rk = RangeKth::new(&[5, 1, 4, 2, 3]);
+    /// assert_eq!(Some(&2), rk.kth_smallest(1..4, 1));
+    /// ```
+    pub fn new(values: &[T]) -> Self {
+        let discretizer = Discretizer::new(values);
+        let mut counts = PersistentSegmentTree::new(&vec![0; discretizer.len()]);
+        for (i, rank) in discretizer.compress(values).into_iter().enumerate() {
+            counts.add(i, rank, 1);
+        }
+        RangeKth {
+            discretizer,
+            counts,
+        }
+    }
+
+    /// Returns the number of values.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rsalgo::ds::RangeKth;
+    ///
+    /// let rk = RangeKth::new(&[5, 1, 4]);
+    /// assert_eq!(3, rk.len());
+    /// ```
+    pub fn len(&self) -> usize {
+        self.counts.versions() - 1
+    }
+
+    /// Returns true if there are no values.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rsalgo::ds::RangeKth;
+    ///
+    /// let rk: RangeKth<u32> = RangeKth::new(&[]);
+    /// assert!(rk.is_empty());
+    /// ```
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Gets the `k`-th (0-indexed) smallest value in `range` in O(log n), or None if `k` is not
+    /// less than the length of `range`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rsalgo::ds::RangeKth;
+    ///
+    /// let rk = RangeKth::new(&[3, 1, 3, 2]);
+    /// assert_eq!(Some(&1), rk.kth_smallest(0..4, 0));
+    /// assert_eq!(Some(&3), rk.kth_smallest(0..4, 2));
+    /// assert_eq!(None, rk.kth_smallest(1..3, 2));
+    /// ```
+    pub fn kth_smallest(&self, range: Range<usize>, k: usize) -> Option<&T> {
+        assert!(
+            range.start <= range.end && range.end <= self.len(),
+            "The range is invalid."
+        );
+        let rank = self.counts.kth_difference(range.start, range.end, k)?;
+        self.discretizer.value(rank)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{PersistentSegmentTree, RangeKth};
+    use crate::ds::algebra::Max;
+    use rand::Rng;
+
+    #[test]
+    fn persistent_segment_tree() {
+        let mut rng = rand::thread_rng();
+        for &len in &[1, 2, 7, 40] {
+            let initial: Vec<i32> = (0..len).map(|_| rng.gen_range(-100, 100)).collect();
+            let mut st: PersistentSegmentTree<i32, Max<i32>> = PersistentSegmentTree::new(&initial);
+            let mut expected = vec![initial];
+
+            for _ in 0..200 {
+                let version = rng.gen_range(0, expected.len());
+                let (pos, value) = (rng.gen_range(0, len), rng.gen_range(-100, 100));
+                let mut next = expected[version].clone();
+                next[pos] = value;
+                assert_eq!(expected.len(), st.set(version, pos, value));
+                expected.push(next);
+
+                let version = rng.gen_range(0, expected.len());
+                let l = rng.gen_range(0, len + 1);
+                let r = rng.gen_range(l, len + 1);
+                let max = expected[version][l..r]
+                    .iter()
+                    .cloned()
+                    .max()
+                    .unwrap_or(i32::MIN);
+                assert_eq!(max, st.query(version, l..r));
+            }
+        }
+    }
+
+    #[test]
+    fn range_kth() {
+        let mut rng = rand::thread_rng();
+        let values: Vec<i32> = (0..60).map(|_| rng.gen_range(0, 20)).collect();
+        let rk = RangeKth::new(&values);
+
+        for _ in 0..500 {
+            let l = rng.gen_range(0, values.len() + 1);
+            let r = rng.gen_range(l, values.len() + 1);
+            let mut sorted = values[l..r].to_vec();
+            sorted.sort();
+            let k = rng.gen_range(0, sorted.len() + 2);
+            assert_eq!(sorted.get(k), rk.kth_smallest(l..r, k));
+        }
+    }
+}
@@ -1,16 +1,21 @@
-use std::ops::{Add, Range, Sub};
+use super::algebra::{CommutativeMonoid, Group, Sum};
+use std::marker::PhantomData;
+use std::ops::Range;
 
-/// 1D prefix sum
-pub struct PrefixSum1D<T>
+/// 1D prefix sum over a group
+pub struct PrefixSum1D<T, G = Sum<T>>
 where
-    T: Copy + Add<Output = T> + Sub<Output = T>,
+    T: Clone,
+    G: Group<Item = T>,
 {
-    inner: Vec<<T as Add>::Output>,
+    inner: Vec<T>,
+    group: PhantomData<G>,
 }
 
-impl<T> PrefixSum1D<T>
+impl<T, G> PrefixSum1D<T, G>
 where
-    T: Copy + Add<Output = T> + Sub<Output = T>,
+    T: Clone,
+    G: Group<Item = T>,
 {
     /// Creates a PrefixSum1D from `values`
     ///
@@ -20,17 +25,20 @@ where
     /// use rsalgo::ds::PrefixSum1D;
     ///
     /// let source: Vec<usize> = (0..100).collect();
-    /// let ps = PrefixSum1D::new(&source, 0);
+    /// let ps: PrefixSum1D<usize> = PrefixSum1D::new(&source);
     ///
     /// assert_eq!(ps.sum(0..100), (0..100).sum());
     /// ```
-    pub fn new(values: &[T], zero: T) -> Self {
+    pub fn new(values: &[T]) -> Self {
         let mut inner = Vec::with_capacity(values.len() + 1);
-        inner.push(zero);
+        inner.push(G::identity());
         for value in values {
-            inner.push(*inner.last().unwrap() + *value);
+            inner.push(G::op(inner.last().unwrap(), value));
+        }
+        PrefixSum1D {
+            inner,
+            group: PhantomData,
         }
-        PrefixSum1D { inner }
     }
 
     /// Gets sum of the `range` in basic sequence.
@@ -41,28 +49,400 @@ where
     /// use rsalgo::ds::PrefixSum1D;
     ///
     /// let source: Vec<usize> = (0..100).collect();
-    /// let ps = PrefixSum1D::new(&source, 0);
+    /// let ps: PrefixSum1D<usize> = PrefixSum1D::new(&source);
     ///
     /// assert_eq!(ps.sum(1..1), (1..1).sum());
     /// assert_eq!(ps.sum(10..15), (10..15).sum());
     /// ```
     pub fn sum(&self, range: Range<usize>) -> T {
         // TODO use .is_empty when it stable
-        self.inner[range.end] - self.inner[range.start]
+        G::op(
+            &G::inverse(&self.inner[range.start]),
+            &self.inner[range.end],
+        )
+    }
+}
+
+/// 2D prefix sum over a commutative group
+pub struct PrefixSum2D<T, G = Sum<T>>
+where
+    T: Clone,
+    G: CommutativeMonoid<Item = T> + Group,
+{
+    cols: usize,
+    /// `(rows + 1) * (cols + 1)` sums of the top-left rectangles.
+    inner: Vec<T>,
+    group: PhantomData<G>,
+}
+
+impl<T, G> PrefixSum2D<T, G>
+where
+    T: Clone,
+    G: CommutativeMonoid<Item = T> + Group,
+{
+    /// Creates a PrefixSum2D from the rows of `grid`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rsalgo::ds::PrefixSum2D;
+    ///
+    /// let grid = vec![vec![1, 2], vec![3, 4]];
+    /// let ps: PrefixSum2D<i32> = PrefixSum2D::new(&grid);
+    ///
+    /// assert_eq!(10, ps.sum(0..2, 0..2));
+    /// ```
+    pub fn new(grid: &[Vec<T>]) -> Self {
+        let rows = grid.len();
+        let cols = grid.first().map_or(0, |row| row.len());
+        let mut inner = vec![G::identity(); (rows + 1) * (cols + 1)];
+        for (i, row) in grid.iter().enumerate() {
+            assert_eq!(cols, row.len(), "The grid is not rectangular.");
+            for (j, value) in row.iter().enumerate() {
+                let (up, left) = (i * (cols + 1) + j + 1, (i + 1) * (cols + 1) + j);
+                let corner = G::inv_op(&G::op(&inner[up], &inner[left]), &inner[up - 1]);
+                inner[left + 1] = G::op(&corner, value);
+            }
+        }
+        PrefixSum2D {
+            cols,
+            inner,
+            group: PhantomData,
+        }
+    }
+
+    fn prefix(&self, row: usize, col: usize) -> &T {
+        &self.inner[row * (self.cols + 1) + col]
+    }
+
+    /// Gets sum of the rectangle of `rows` and `cols`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rsalgo::ds::PrefixSum2D;
+    ///
+    /// let grid = vec![vec![1, 2, 3], vec![4, 5, 6], vec![7, 8, 9]];
+    /// let ps: PrefixSum2D<i32> = PrefixSum2D::new(&grid);
+    ///
+    /// assert_eq!(28, ps.sum(1..3, 1..3));
+    /// assert_eq!(0, ps.sum(1..1, 0..3));
+    /// ```
+    pub fn sum(&self, rows: Range<usize>, cols: Range<usize>) -> T {
+        let outer = G::inv_op(
+            self.prefix(rows.end, cols.end),
+            self.prefix(rows.start, cols.end),
+        );
+        let inner = G::inv_op(
+            self.prefix(rows.end, cols.start),
+            self.prefix(rows.start, cols.start),
+        );
+        G::inv_op(&outer, &inner)
+    }
+}
+
+/// N-dimensional prefix sum over a commutative group
+pub struct PrefixSumND<T, G = Sum<T>>
+where
+    T: Clone,
+    G: CommutativeMonoid<Item = T> + Group,
+{
+    /// Strides of each dimension in `inner`, whose lengths are one more than the original ones.
+    strides: Vec<usize>,
+    inner: Vec<T>,
+    group: PhantomData<G>,
+}
+
+impl<T, G> PrefixSumND<T, G>
+where
+    T: Clone,
+    G: CommutativeMonoid<Item = T> + Group,
+{
+    /// Creates a PrefixSumND from `values` in row-major order with the lengths of dimensions `dims`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rsalgo::ds::PrefixSumND;
+    ///
+    /// let values: Vec<i32> = (0..24).collect();
+    /// let ps: PrefixSumND<i32> = PrefixSumND::new(&[2, 3, 4], &values);
+    ///
+    /// assert_eq!((0..24).sum::<i32>(), ps.sum(&[0..2, 0..3, 0..4]));
+    /// ```
+    pub fn new(dims: &[usize], values: &[T]) -> Self {
+        assert_eq!(
+            dims.iter().product::<usize>(),
+            values.len(),
+            "The dimensions do not match the values."
+        );
+        let mut strides = vec![1; dims.len()];
+        for k in (1..dims.len()).rev() {
+            strides[k - 1] = strides[k] * (dims[k] + 1);
+        }
+        let size = dims.first().map_or(1, |d| (d + 1) * strides[0]);
+        let mut inner = vec![G::identity(); size];
+
+        let offset: usize = strides.iter().sum();
+        let mut index = vec![0; dims.len()];
+        for value in values {
+            let pos: usize = index.iter().zip(&strides).map(|(i, s)| i * s).sum();
+            inner[pos + offset] = value.clone();
+            for k in (0..dims.len()).rev() {
+                index[k] += 1;
+                if index[k] < dims[k] {
+                    break;
+                }
+                index[k] = 0;
+            }
+        }
+
+        for (k, &stride) in strides.iter().enumerate() {
+            for pos in 0..size {
+                if pos / stride % (dims[k] + 1) != 0 {
+                    inner[pos] = G::op(&inner[pos], &inner[pos - stride]);
+                }
+            }
+        }
+
+        PrefixSumND {
+            strides,
+            inner,
+            group: PhantomData,
+        }
+    }
+
+    /// Gets sum of the box with a range in each dimension, in O(2^d).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rsalgo::ds::PrefixSumND;
+    ///
+    /// let values = vec![1; 27];
+    /// let ps: PrefixSumND<i32> = PrefixSumND::new(&[3, 3, 3], &values);
+    ///
+    /// assert_eq!(8, ps.sum(&[1..3, 0..2, 1..3]));
+    /// ```
+    pub fn sum(&self, ranges: &[Range<usize>]) -> T {
+        assert_eq!(
+            self.strides.len(),
+            ranges.len(),
+            "The dimensions do not match."
+        );
+        let mut res = G::identity();
+        for mask in 0..1usize << ranges.len() {
+            let pos: usize = ranges
+                .iter()
+                .zip(&self.strides)
+                .enumerate()
+                .map(|(k, (r, s))| if mask >> k & 1 == 1 { r.start } else { r.end } * s)
+                .sum();
+            res = if mask.count_ones() % 2 == 0 {
+                G::op(&res, &self.inner[pos])
+            } else {
+                G::inv_op(&res, &self.inner[pos])
+            };
+        }
+        res
+    }
+}
+
+/// 2D difference array for batched rectangle additions over a commutative group
+pub struct DifferenceArray2D<T, G = Sum<T>>
+where
+    T: Clone,
+    G: CommutativeMonoid<Item = T> + Group,
+{
+    rows: usize,
+    cols: usize,
+    inner: Vec<T>,
+    group: PhantomData<G>,
+}
+
+impl<T, G> DifferenceArray2D<T, G>
+where
+    T: Clone,
+    G: CommutativeMonoid<Item = T> + Group,
+{
+    /// Creates a DifferenceArray2D of `rows * cols` identity elements
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rsalgo::ds::DifferenceArray2D;
+    ///
+    /// let d: DifferenceArray2D<i32> = DifferenceArray2D::new(3, 4);
+    /// ```
+    pub fn new(rows: usize, cols: usize) -> Self {
+        DifferenceArray2D {
+            rows,
+            cols,
+            inner: vec![G::identity(); (rows + 1) * (cols + 1)],
+            group: PhantomData,
+        }
+    }
+
+    fn update(&mut self, row: usize, col: usize, delta: &T) {
+        let pos = row * (self.cols + 1) + col;
+        self.inner[pos] = G::op(&self.inner[pos], delta);
+    }
+
+    /// Add `delta` to the rectangle of `rows` and `cols` in O(1).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rsalgo::ds::DifferenceArray2D;
+    ///
+    /// let mut d: DifferenceArray2D<i32> = DifferenceArray2D::new(2, 3);
+    /// d.add(0..2, 1..3, 5);
+    /// d.add(1..2, 0..2, 1);
+    /// assert_eq!(vec![vec![0, 5, 5], vec![1, 6, 5]], d.build());
+    /// ```
+    pub fn add(&mut self, rows: Range<usize>, cols: Range<usize>, delta: T) {
+        if rows.start >= rows.end || cols.start >= cols.end {
+            return;
+        }
+        let inverse = G::inverse(&delta);
+        self.update(rows.start, cols.start, &delta);
+        self.update(rows.start, cols.end, &inverse);
+        self.update(rows.end, cols.start, &inverse);
+        self.update(rows.end, cols.end, &delta);
+    }
+
+    /// Gets the grid after all additions in O(rows * cols).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rsalgo::ds::DifferenceArray2D;
+    ///
+    /// let mut d: DifferenceArray2D<i32> = DifferenceArray2D::new(3, 3);
+    /// d.add(0..2, 0..2, 1);
+    /// d.add(1..3, 1..3, 2);
+    /// assert_eq!(vec![vec![1, 1, 0], vec![1, 3, 2], vec![0, 2, 2]], d.build());
+    /// ```
+    pub fn build(&self) -> Vec<Vec<T>> {
+        let mut res: Vec<Vec<T>> = Vec::with_capacity(self.rows);
+        for i in 0..self.rows {
+            let mut row: Vec<T> = Vec::with_capacity(self.cols);
+            let mut acc = G::identity();
+            for j in 0..self.cols {
+                acc = G::op(&acc, &self.inner[i * (self.cols + 1) + j]);
+                row.push(match res.last() {
+                    Some(up) => G::op(&up[j], &acc),
+                    None => acc.clone(),
+                });
+            }
+            res.push(row);
+        }
+        res
     }
 }
 
 #[cfg(test)]
 mod tests {
-    use super::PrefixSum1D;
+    use super::{DifferenceArray2D, PrefixSum1D, PrefixSum2D, PrefixSumND};
+    use crate::ds::algebra::Xor;
+    use rand::Rng;
 
     #[test]
     fn d1() {
         let source: Vec<usize> = (0..100).collect();
-        let ps = PrefixSum1D::new(&source, 0);
+        let ps: PrefixSum1D<usize> = PrefixSum1D::new(&source);
 
         assert_eq!(ps.sum(1..1), (1..1).sum());
         assert_eq!(ps.sum(10..15), (10..15).sum());
         assert_eq!(ps.sum(0..100), (0..100).sum());
     }
+
+    #[test]
+    fn d1_xor() {
+        let source: Vec<u32> = (0..100).map(|x| x * 7919 % 256).collect();
+        let ps: PrefixSum1D<u32, Xor<u32>> = PrefixSum1D::new(&source);
+
+        assert_eq!(0, ps.sum(5..5));
+        assert_eq!(source[10..15].iter().fold(0, |a, b| a ^ b), ps.sum(10..15));
+    }
+
+    #[test]
+    fn d2() {
+        let mut rng = rand::thread_rng();
+        let (rows, cols) = (7, 9);
+        let grid: Vec<Vec<i64>> = (0..rows)
+            .map(|_| (0..cols).map(|_| rng.gen_range(-100, 100)).collect())
+            .collect();
+        let ps: PrefixSum2D<i64> = PrefixSum2D::new(&grid);
+        let flat: Vec<i64> = grid.iter().flatten().cloned().collect();
+        let nd: PrefixSumND<i64> = PrefixSumND::new(&[rows, cols], &flat);
+
+        for _ in 0..200 {
+            let r0 = rng.gen_range(0, rows + 1);
+            let r1 = rng.gen_range(r0, rows + 1);
+            let c0 = rng.gen_range(0, cols + 1);
+            let c1 = rng.gen_range(c0, cols + 1);
+            let expected: i64 = grid[r0..r1]
+                .iter()
+                .map(|row| row[c0..c1].iter().sum::<i64>())
+                .sum();
+            assert_eq!(expected, ps.sum(r0..r1, c0..c1));
+            assert_eq!(expected, nd.sum(&[r0..r1, c0..c1]));
+        }
+
+        let empty: PrefixSum2D<i64> = PrefixSum2D::new(&[]);
+        assert_eq!(0, empty.sum(0..0, 0..0));
+    }
+
+    #[test]
+    fn nd() {
+        let mut rng = rand::thread_rng();
+        let dims = [3, 4, 2, 3];
+        let values: Vec<u32> = (0..72).map(|_| rng.gen()).collect();
+        let ps: PrefixSumND<u32, Xor<u32>> = PrefixSumND::new(&dims, &values);
+
+        for _ in 0..200 {
+            let ranges: Vec<_> = dims
+                .iter()
+                .map(|&d| {
+                    let l = rng.gen_range(0, d + 1);
+                    l..rng.gen_range(l, d + 1)
+                })
+                .collect();
+            let mut expected = 0;
+            for (pos, value) in values.iter().enumerate() {
+                let mut rest = pos;
+                let mut inside = true;
+                for (k, &d) in dims.iter().enumerate().rev() {
+                    inside &= ranges[k].contains(&(rest % d));
+                    rest /= d;
+                }
+                if inside {
+                    expected ^= value;
+                }
+            }
+            assert_eq!(expected, ps.sum(&ranges));
+        }
+    }
+
+    #[test]
+    fn difference_2d() {
+        let mut rng = rand::thread_rng();
+        let (rows, cols) = (6, 5);
+        let mut grid = vec![vec![0i32; cols]; rows];
+        let mut d: DifferenceArray2D<i32> = DifferenceArray2D::new(rows, cols);
+
+        for _ in 0..50 {
+            let r0 = rng.gen_range(0, rows + 1);
+            let r1 = rng.gen_range(r0, rows + 1);
+            let c0 = rng.gen_range(0, cols + 1);
+            let c1 = rng.gen_range(c0, cols + 1);
+            let delta = rng.gen_range(-10, 10);
+            for row in &mut grid[r0..r1] {
+                row[c0..c1].iter_mut().for_each(|x| *x += delta);
+            }
+            d.add(r0..r1, c0..c1, delta);
+        }
+        assert_eq!(grid, d.build());
+    }
 }
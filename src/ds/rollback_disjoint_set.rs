@@ -0,0 +1,214 @@
+/// Disjoint set supporting rollback, union by size without path compression.
+pub struct RollbackDisjointSet {
+    size: usize,
+    parent: Vec<usize>,
+    count: Vec<usize>,
+    /// Roots attached to another root by each merge, in order.
+    history: Vec<usize>,
+}
+
+impl RollbackDisjointSet {
+    /// Create a rollback disjoint set with `capacity` elements.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let s = rsalgo::ds::RollbackDisjointSet::new(100);
+    /// ```
+    pub fn new(capacity: usize) -> Self {
+        RollbackDisjointSet {
+            size: capacity,
+            parent: (0..capacity).collect(),
+            count: vec![1; capacity],
+            history: Vec::new(),
+        }
+    }
+
+    /// Get the number of disjoint sets.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let s = rsalgo::ds::RollbackDisjointSet::new(100);
+    ///
+    /// assert_eq!(100, s.len());
+    /// ```
+    pub fn len(&self) -> usize {
+        self.size
+    }
+
+    /// Get the number of elements.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let s = rsalgo::ds::RollbackDisjointSet::new(100);
+    ///
+    /// assert_eq!(100, s.capacity());
+    /// ```
+    pub fn capacity(&self) -> usize {
+        self.parent.len()
+    }
+
+    /// Returns true if the set contains no elements.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let s = rsalgo::ds::RollbackDisjointSet::new(0);
+    ///
+    /// assert!(s.is_empty());
+    /// ```
+    pub fn is_empty(&self) -> bool {
+        self.parent.is_empty()
+    }
+
+    /// Get the representation element of `id`'s set in O(log n).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let s = rsalgo::ds::RollbackDisjointSet::new(100);
+    ///
+    /// assert_eq!(0, s.find(0));
+    /// ```
+    pub fn find(&self, id: usize) -> usize {
+        let mut id = id;
+        while self.parent[id] != id {
+            id = self.parent[id];
+        }
+        id
+    }
+
+    /// Return whether the two element is in the same set.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let s = rsalgo::ds::RollbackDisjointSet::new(100);
+    ///
+    /// assert_eq!(false, s.in_same(0, 1));
+    /// ```
+    pub fn in_same(&self, a: usize, b: usize) -> bool {
+        self.find(a) == self.find(b)
+    }
+
+    /// Get the size of `id`'s set.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let mut s = rsalgo::ds::RollbackDisjointSet::new(100);
+    /// s.unite(0, 1);
+    /// assert_eq!(2, s.size_of(1));
+    /// ```
+    pub fn size_of(&self, id: usize) -> usize {
+        self.count[self.find(id)]
+    }
+
+    /// Unite `a`'s set and `b`'s set, returns true if two sets are merged.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let mut s = rsalgo::ds::RollbackDisjointSet::new(100);
+    /// assert!(s.unite(0, 1));
+    /// assert!(!s.unite(1, 0));
+    /// ```
+    pub fn unite(&mut self, a: usize, b: usize) -> bool {
+        let (mut a, mut b) = (self.find(a), self.find(b));
+        if a == b {
+            return false;
+        }
+        if self.count[a] < self.count[b] {
+            std::mem::swap(&mut a, &mut b);
+        }
+        self.parent[b] = a;
+        self.count[a] += self.count[b];
+        self.size -= 1;
+        self.history.push(b);
+        true
+    }
+
+    /// Get a snapshot of the current state, which can be restored by `rollback`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let mut s = rsalgo::ds::RollbackDisjointSet::new(100);
+    /// let snapshot = s.snapshot();
+    /// s.unite(0, 1);
+    /// s.rollback(snapshot);
+    /// assert!(!s.in_same(0, 1));
+    /// ```
+    pub fn snapshot(&self) -> usize {
+        self.history.len()
+    }
+
+    /// Undo all merges after `snapshot` was taken.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let mut s = rsalgo::ds::RollbackDisjointSet::new(100);
+    /// s.unite(0, 1);
+    /// let snapshot = s.snapshot();
+    /// s.unite(1, 2);
+    /// s.rollback(snapshot);
+    /// assert!(s.in_same(0, 1));
+    /// assert!(!s.in_same(1, 2));
+    /// ```
+    pub fn rollback(&mut self, snapshot: usize) {
+        assert!(snapshot <= self.history.len(), "The snapshot is invalid.");
+        while self.history.len() > snapshot {
+            let b = self.history.pop().unwrap();
+            let a = self.parent[b];
+            self.parent[b] = b;
+            self.count[a] -= self.count[b];
+            self.size += 1;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::RollbackDisjointSet;
+    use crate::ds::DisjointSet;
+    use rand::Rng;
+
+    #[test]
+    fn rollback_disjoint_set() {
+        let mut rng = rand::thread_rng();
+        const SIZE: usize = 30;
+
+        let mut s = RollbackDisjointSet::new(SIZE);
+        let mut edges: Vec<(usize, usize)> = Vec::new();
+        let mut snapshots = Vec::new();
+
+        for _ in 0..500 {
+            match rng.gen_range(0, 3) {
+                0 => snapshots.push((s.snapshot(), edges.len())),
+                1 if !snapshots.is_empty() => {
+                    let (snapshot, len) = snapshots.pop().unwrap();
+                    s.rollback(snapshot);
+                    edges.truncate(len);
+                }
+                _ => {
+                    let (a, b) = (rng.gen_range(0, SIZE), rng.gen_range(0, SIZE));
+                    s.unite(a, b);
+                    edges.push((a, b));
+                }
+            }
+
+            let mut expected = DisjointSet::new(SIZE, true);
+            for &(a, b) in &edges {
+                expected.unite(a, b);
+            }
+            assert_eq!(expected.len(), s.len());
+            for _ in 0..10 {
+                let (a, b) = (rng.gen_range(0, SIZE), rng.gen_range(0, SIZE));
+                assert_eq!(expected.in_same(a, b), s.in_same(a, b));
+            }
+        }
+    }
+}
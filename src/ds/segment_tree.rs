@@ -0,0 +1,450 @@
+use super::algebra::{Action, Monoid};
+use std::ops::Range;
+
+/// Segment tree over a monoid `M` with lazy range actions of `A`
+pub struct LazySegmentTree<M: Monoid, A: Action<M>> {
+    n: usize,
+    size: usize,
+    log: u32,
+    data: Vec<M::Item>,
+    lazy: Vec<A::Item>,
+    /// Number of elements in each node, which is less than the width of nodes at the end.
+    count: Vec<usize>,
+}
+
+impl<M: Monoid, A: Action<M>> LazySegmentTree<M, A> {
+    /// Creates a LazySegmentTree of `n` identity elements.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rsalgo::ds::{algebra::Sum, LazySegmentTree};
+    ///
+    /// let mut st: LazySegmentTree<Sum<i64>, Sum<i64>> = LazySegmentTree::new(10);
+    /// assert_eq!(0, st.query(0..10));
+    /// ```
+    pub fn new(n: usize) -> Self {
+        Self::from_slice(&vec![M::identity(); n])
+    }
+
+    /// Creates a LazySegmentTree from `values` in O(n).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rsalgo::ds::{algebra::{Assign, Min}, LazySegmentTree};
+    ///
+    /// let mut st: LazySegmentTree<Min<i32>, Assign<i32>> = LazySegmentTree::from_slice(&[3, 1, 2]);
+    /// assert_eq!(1, st.query(0..3));
+    /// ```
+    pub fn from_slice(values: &[M::Item]) -> Self {
+        let n = values.len();
+        let size = n.next_power_of_two();
+        let log = size.trailing_zeros();
+        let mut data = vec![M::identity(); 2 * size];
+        let mut count = vec![0; 2 * size];
+        for (i, value) in values.iter().enumerate() {
+            data[size + i] = value.clone();
+            count[size + i] = 1;
+        }
+        let mut st = LazySegmentTree {
+            n,
+            size,
+            log,
+            data,
+            lazy: vec![A::identity(); size],
+            count,
+        };
+        for k in (1..size).rev() {
+            st.count[k] = st.count[2 * k] + st.count[2 * k + 1];
+            st.update(k);
+        }
+        st
+    }
+
+    /// Returns the number of elements.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rsalgo::ds::{algebra::Sum, LazySegmentTree};
+    ///
+    /// let st: LazySegmentTree<Sum<i64>, Sum<i64>> = LazySegmentTree::new(10);
+    /// assert_eq!(10, st.len());
+    /// ```
+    pub fn len(&self) -> usize {
+        self.n
+    }
+
+    /// Returns true if there are no elements.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rsalgo::ds::{algebra::Sum, LazySegmentTree};
+    ///
+    /// let st: LazySegmentTree<Sum<i64>, Sum<i64>> = LazySegmentTree::new(0);
+    /// assert!(st.is_empty());
+    /// ```
+    pub fn is_empty(&self) -> bool {
+        self.n == 0
+    }
+
+    fn update(&mut self, k: usize) {
+        self.data[k] = M::op(&self.data[2 * k], &self.data[2 * k + 1]);
+    }
+
+    fn apply_node(&mut self, k: usize, f: &A::Item) {
+        if self.count[k] > 0 {
+            self.data[k] = A::act(f, &self.data[k], self.count[k]);
+        }
+        if k < self.size {
+            self.lazy[k] = A::op(&self.lazy[k], f);
+        }
+    }
+
+    fn push(&mut self, k: usize) {
+        let f = std::mem::replace(&mut self.lazy[k], A::identity());
+        self.apply_node(2 * k, &f);
+        self.apply_node(2 * k + 1, &f);
+    }
+
+    /// Pushes down actions on the paths to the leaves at both ends of `range`.
+    fn push_bounds(&mut self, range: &Range<usize>) {
+        let (l, r) = (range.start + self.size, range.end + self.size);
+        for i in (1..=self.log).rev() {
+            if (l >> i) << i != l {
+                self.push(l >> i);
+            }
+            if (r >> i) << i != r {
+                self.push((r - 1) >> i);
+            }
+        }
+    }
+
+    /// Gets the value at the position `pos`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rsalgo::ds::{algebra::Sum, LazySegmentTree};
+    ///
+    /// let mut st: LazySegmentTree<Sum<i64>, Sum<i64>> = LazySegmentTree::from_slice(&[1, 2, 3]);
+    /// st.apply(1..3, 10);
+    /// assert_eq!(12, st.get(1));
+    /// ```
+    pub fn get(&mut self, pos: usize) -> M::Item {
+        assert!(pos < self.n, "The index is out of range.");
+        let p = pos + self.size;
+        for i in (1..=self.log).rev() {
+            self.push(p >> i);
+        }
+        self.data[p].clone()
+    }
+
+    /// Set the value at the position `pos` to `value`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rsalgo::ds::{algebra::Sum, LazySegmentTree};
+    ///
+    /// let mut st: LazySegmentTree<Sum<i64>, Sum<i64>> = LazySegmentTree::from_slice(&[1, 2, 3]);
+    /// st.set(1, 5);
+    /// assert_eq!(9, st.query(0..3));
+    /// ```
+    pub fn set(&mut self, pos: usize, value: M::Item) {
+        assert!(pos < self.n, "The index is out of range.");
+        let p = pos + self.size;
+        for i in (1..=self.log).rev() {
+            self.push(p >> i);
+        }
+        self.data[p] = value;
+        for i in 1..=self.log {
+            self.update(p >> i);
+        }
+    }
+
+    /// Gets the result of the values in `range`, the identity if `range` is empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rsalgo::ds::{algebra::{Affine, Sum}, LazySegmentTree};
+    ///
+    /// let mut st: LazySegmentTree<Sum<i64>, Affine<i64>> = LazySegmentTree::from_slice(&[1, 2, 3, 4]);
+    /// st.apply(0..2, (2, 1));
+    /// assert_eq!(8, st.query(0..2));
+    /// assert_eq!(0, st.query(2..2));
+    /// ```
+    pub fn query(&mut self, range: Range<usize>) -> M::Item {
+        assert!(
+            range.start <= range.end && range.end <= self.n,
+            "The range is invalid."
+        );
+        if range.start == range.end {
+            return M::identity();
+        }
+        self.push_bounds(&range);
+
+        let (mut l, mut r) = (range.start + self.size, range.end + self.size);
+        let (mut left, mut right) = (M::identity(), M::identity());
+        while l < r {
+            if l & 1 == 1 {
+                left = M::op(&left, &self.data[l]);
+                l += 1;
+            }
+            if r & 1 == 1 {
+                r -= 1;
+                right = M::op(&self.data[r], &right);
+            }
+            l >>= 1;
+            r >>= 1;
+        }
+        M::op(&left, &right)
+    }
+
+    /// Applies the action `f` to all values in `range`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rsalgo::ds::{algebra::{Assign, Sum}, LazySegmentTree};
+    ///
+    /// let mut st: LazySegmentTree<Sum<i64>, Assign<i64>> = LazySegmentTree::new(5);
+    /// st.apply(1..4, Some(3));
+    /// st.apply(2..5, Some(1));
+    /// assert_eq!(6, st.query(0..5));
+    /// ```
+    pub fn apply(&mut self, range: Range<usize>, f: A::Item) {
+        assert!(
+            range.start <= range.end && range.end <= self.n,
+            "The range is invalid."
+        );
+        if range.start == range.end {
+            return;
+        }
+        self.push_bounds(&range);
+
+        let (l0, r0) = (range.start + self.size, range.end + self.size);
+        let (mut l, mut r) = (l0, r0);
+        while l < r {
+            if l & 1 == 1 {
+                self.apply_node(l, &f);
+                l += 1;
+            }
+            if r & 1 == 1 {
+                r -= 1;
+                self.apply_node(r, &f);
+            }
+            l >>= 1;
+            r >>= 1;
+        }
+
+        for i in 1..=self.log {
+            if (l0 >> i) << i != l0 {
+                self.update(l0 >> i);
+            }
+            if (r0 >> i) << i != r0 {
+                self.update((r0 - 1) >> i);
+            }
+        }
+    }
+
+    /// Gets the largest `end` such that `pred(query(start..end))` is true, where `pred` must be
+    /// monotone and true for the identity.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rsalgo::ds::{algebra::Sum, LazySegmentTree};
+    ///
+    /// let mut st: LazySegmentTree<Sum<i64>, Sum<i64>> = LazySegmentTree::from_slice(&[1, 2, 3, 4]);
+    /// assert_eq!(3, st.max_right(0, |&sum| sum <= 6));
+    /// assert_eq!(4, st.max_right(1, |&sum| sum <= 100));
+    /// ```
+    pub fn max_right<P: Fn(&M::Item) -> bool>(&mut self, start: usize, pred: P) -> usize {
+        assert!(start <= self.n, "The index is out of range.");
+        assert!(
+            pred(&M::identity()),
+            "The predicate is false for the identity."
+        );
+        if start == self.n {
+            return self.n;
+        }
+        let mut l = start + self.size;
+        for i in (1..=self.log).rev() {
+            self.push(l >> i);
+        }
+        let mut acc = M::identity();
+        loop {
+            while l % 2 == 0 {
+                l >>= 1;
+            }
+            let next = M::op(&acc, &self.data[l]);
+            if !pred(&next) {
+                while l < self.size {
+                    self.push(l);
+                    l *= 2;
+                    let next = M::op(&acc, &self.data[l]);
+                    if pred(&next) {
+                        acc = next;
+                        l += 1;
+                    }
+                }
+                return l - self.size;
+            }
+            acc = next;
+            l += 1;
+            if l.is_power_of_two() {
+                return self.n;
+            }
+        }
+    }
+
+    /// Gets the smallest `start` such that `pred(query(start..end))` is true, where `pred` must be
+    /// monotone and true for the identity.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rsalgo::ds::{algebra::{Max, Sum}, LazySegmentTree};
+    ///
+    /// let mut st: LazySegmentTree<Max<i32>, Sum<i32>> = LazySegmentTree::from_slice(&[5, 1, 2, 3]);
+    /// assert_eq!(1, st.min_left(4, |&max| max < 5));
+    /// ```
+    pub fn min_left<P: Fn(&M::Item) -> bool>(&mut self, end: usize, pred: P) -> usize {
+        assert!(end <= self.n, "The index is out of range.");
+        assert!(
+            pred(&M::identity()),
+            "The predicate is false for the identity."
+        );
+        if end == 0 {
+            return 0;
+        }
+        let mut r = end + self.size;
+        for i in (1..=self.log).rev() {
+            self.push((r - 1) >> i);
+        }
+        let mut acc = M::identity();
+        loop {
+            r -= 1;
+            while r > 1 && r % 2 == 1 {
+                r >>= 1;
+            }
+            let next = M::op(&self.data[r], &acc);
+            if !pred(&next) {
+                while r < self.size {
+                    self.push(r);
+                    r = 2 * r + 1;
+                    let next = M::op(&self.data[r], &acc);
+                    if pred(&next) {
+                        acc = next;
+                        r -= 1;
+                    }
+                }
+                return r + 1 - self.size;
+            }
+            acc = next;
+            if r.is_power_of_two() {
+                return 0;
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::LazySegmentTree;
+    use crate::ds::algebra::{Affine, Assign, Max, Min, Sum};
+    use crate::ds::{RangeSumFenwickTree, SparseTable};
+    use rand::Rng;
+
+    #[test]
+    fn range_add_sum() {
+        let mut rng = rand::thread_rng();
+        for &len in &[1, 2, 7, 64, 100] {
+            let mut st: LazySegmentTree<Sum<i64>, Sum<i64>> = LazySegmentTree::new(len);
+            let mut ft: RangeSumFenwickTree<i64> = RangeSumFenwickTree::new(len);
+            for _ in 0..500 {
+                let l = rng.gen_range(0, len + 1);
+                let r = rng.gen_range(l, len + 1);
+                if rng.gen_ratio(1, 2) {
+                    let delta = rng.gen_range(0, 100);
+                    st.apply(l..r, delta);
+                    ft.add(l..r, delta);
+                } else {
+                    assert_eq!(ft.range_sum(l..r), st.query(l..r));
+                }
+
+                let limit = rng.gen_range(0, 2000);
+                let expected = (l..=len)
+                    .take_while(|&end| ft.range_sum(l..end) <= limit)
+                    .last()
+                    .unwrap();
+                assert_eq!(expected, st.max_right(l, |&sum| sum <= limit));
+                let expected = (0..=r)
+                    .rev()
+                    .take_while(|&start| ft.range_sum(start..r) <= limit)
+                    .last()
+                    .unwrap();
+                assert_eq!(expected, st.min_left(r, |&sum| sum <= limit));
+            }
+        }
+    }
+
+    #[test]
+    fn range_min() {
+        let mut rng = rand::thread_rng();
+        const LEN: usize = 50;
+        let source: Vec<i32> = (0..LEN).map(|_| rng.gen_range(-1000, 1000)).collect();
+        let table: SparseTable<i32> = SparseTable::new(&source);
+        let mut st: LazySegmentTree<Min<i32>, Sum<i32>> = LazySegmentTree::from_slice(&source);
+        st.apply(0..LEN, 7);
+        for _ in 0..500 {
+            let l = rng.gen_range(0, LEN);
+            let r = rng.gen_range(l + 1, LEN + 1);
+            assert_eq!(table.query(l..r) + 7, st.query(l..r));
+        }
+    }
+
+    #[test]
+    fn assign_affine() {
+        let mut rng = rand::thread_rng();
+        const LEN: usize = 37;
+        let mut source: Vec<i64> = (0..LEN).map(|_| rng.gen_range(-10, 10)).collect();
+        let mut affine: LazySegmentTree<Sum<i64>, Affine<i64>> =
+            LazySegmentTree::from_slice(&source);
+        let mut assign: LazySegmentTree<Max<i64>, Assign<i64>> =
+            LazySegmentTree::from_slice(&source);
+        let mut copy = source.clone();
+
+        for _ in 0..500 {
+            let l = rng.gen_range(0, LEN + 1);
+            let r = rng.gen_range(l, LEN + 1);
+            match rng.gen_range(0, 4) {
+                0 => {
+                    let f = (rng.gen_range(-1, 2), rng.gen_range(-5, 5));
+                    source[l..r].iter_mut().for_each(|x| *x = f.0 * *x + f.1);
+                    affine.apply(l..r, f);
+                }
+                1 => {
+                    let value = rng.gen_range(-100, 100);
+                    copy[l..r].iter_mut().for_each(|x| *x = value);
+                    assign.apply(l..r, Some(value));
+                }
+                2 if l < LEN => {
+                    let value = rng.gen_range(-100, 100);
+                    source[l] = value;
+                    affine.set(l, value);
+                    assert_eq!(value, affine.get(l));
+                }
+                _ => {
+                    assert_eq!(source[l..r].iter().sum::<i64>(), affine.query(l..r));
+                    let max = copy[l..r].iter().cloned().max().unwrap_or(i64::MIN);
+                    assert_eq!(max, assign.query(l..r));
+                }
+            }
+        }
+    }
+}
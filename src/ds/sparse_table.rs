@@ -1,72 +1,381 @@
+use super::algebra::{ArgMax, ArgMin, Idempotent, Min, Monoid, Sum};
+use std::marker::PhantomData;
 use std::ops::Range;
 
-pub struct SparseTable<'a, T: Ord> {
-    inner: Vec<Vec<&'a T>>,
+/// Sparse table for range queries of an idempotent monoid, such as minimum, maximum, gcd or
+/// bitwise and/or.
+pub struct SparseTable<T, M = Min<T>>
+where
+    T: Clone,
+    M: Idempotent<Item = T>,
+{
+    len: usize,
+    /// Floor of log2 of each length in `[1, len]`.
+    log: Vec<usize>,
+    /// Start of each level in `table`, level `k` keeps the results of length `2^k`.
+    offsets: Vec<usize>,
+    table: Vec<T>,
+    monoid: PhantomData<M>,
 }
 
-impl<'a, T: Ord> SparseTable<'a, T> {
-    pub fn new(values: &'a [T]) -> Self {
-        let n = values.len();
-        let pow2 = n.next_power_of_two().trailing_zeros() as usize;
-        let mut inner = vec![vec![]; n];
-
-        inner
-            .iter_mut()
-            .zip(values)
-            .for_each(|(inn, v)| inn.push(v));
-
-        for j in 1..=pow2 {
-            for i in 0..=(n as isize) - (1 << j) {
-                let left = inner[i as usize][j - 1];
-                let right = inner[i as usize + (1 << (j - 1))][j - 1];
-                inner[i as usize].push(std::cmp::min(left, right));
+impl<T, M> SparseTable<T, M>
+where
+    T: Clone,
+    M: Idempotent<Item = T>,
+{
+    /// Creates a SparseTable from `values` in O(n log n).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rsalgo::ds::{algebra::Max, SparseTable};
+    ///
+    /// let min: SparseTable<i32> = SparseTable::new(&[3, 1, 2]);
+    /// let max: SparseTable<i32, Max<i32>> = SparseTable::new(&[3, 1, 2]);
+    /// ```
+    pub fn new(values: &[T]) -> Self {
+        let len = values.len();
+        let mut log = vec![0; len + 1];
+        for i in 2..=len {
+            log[i] = log[i / 2] + 1;
+        }
+
+        let mut offsets = vec![0];
+        let mut table = values.to_vec();
+        let mut width = 1;
+        while 2 * width <= len {
+            let last = offsets[offsets.len() - 1];
+            offsets.push(table.len());
+            for i in 0..=len - 2 * width {
+                let value = M::op(&table[last + i], &table[last + i + width]);
+                table.push(value);
             }
+            width *= 2;
         }
 
-        SparseTable { inner }
+        SparseTable {
+            len,
+            log,
+            offsets,
+            table,
+            monoid: PhantomData,
+        }
     }
 
-    pub fn min(&self, range: Range<usize>) -> &'a T {
-        // TODO
-        let len = range.end - range.start;
-        if len.is_power_of_two() {
-            self.inner[range.start][len.trailing_zeros() as usize]
-        } else {
-            let k = ((len as f64).log2().floor()) as usize; // Error
-            std::cmp::min(
-                self.inner[range.start][k],
-                self.inner[range.end - (1 << k)][k],
-            )
+    /// Gets the result of the values in `range` in O(1), the identity for an empty range.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rsalgo::ds::SparseTable;
+    ///
+    /// let st: SparseTable<i32> = SparseTable::new(&[3, 1, 2, 5]);
+    /// assert_eq!(1, st.query(0..3));
+    /// assert_eq!(2, st.query(2..4));
+    /// assert_eq!(i32::MAX, st.query(2..2));
+    /// ```
+    pub fn query(&self, range: Range<usize>) -> T {
+        assert!(
+            range.start <= range.end && range.end <= self.len,
+            "The range is invalid."
+        );
+        if range.start == range.end {
+            return M::identity();
         }
+        let k = self.log[range.end - range.start];
+        let level = &self.table[self.offsets[k]..];
+        M::op(&level[range.start], &level[range.end - (1 << k)])
     }
 
+    /// Returns the number of values.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rsalgo::ds::SparseTable;
+    ///
+    /// let st: SparseTable<i32> = SparseTable::new(&[3, 1, 2, 5]);
+    /// assert_eq!(4, st.len());
+    /// ```
     pub fn len(&self) -> usize {
-        self.inner.len()
+        self.len
     }
 
+    /// Returns true if there are no values.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rsalgo::ds::SparseTable;
+    ///
+    /// let st: SparseTable<i32> = SparseTable::new(&[]);
+    /// assert!(st.is_empty());
+    /// ```
     pub fn is_empty(&self) -> bool {
-        self.inner.is_empty()
+        self.len == 0
+    }
+}
+
+impl<T, M> SparseTable<Option<(T, usize)>, M>
+where
+    T: Clone,
+    M: Idempotent<Item = Option<(T, usize)>>,
+{
+    /// Creates a SparseTable of `values` paired with their positions, used with `ArgMin` or `ArgMax`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rsalgo::ds::{algebra::ArgMin, SparseTable};
+    ///
+    /// let st: SparseTable<_, ArgMin<i32>> = SparseTable::indexed(&[3, 1, 2]);
+    /// assert_eq!(Some((1, 1)), st.query(0..3));
+    /// ```
+    pub fn indexed(values: &[T]) -> Self {
+        let values: Vec<Option<(T, usize)>> = values
+            .iter()
+            .cloned()
+            .enumerate()
+            .map(|(i, v)| Some((v, i)))
+            .collect();
+        Self::new(&values)
+    }
+}
+
+impl<T: Ord + Clone> SparseTable<Option<(T, usize)>, ArgMin<T>> {
+    /// Gets the leftmost position of the minimum in `range`, or None if `range` is empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rsalgo::ds::{algebra::ArgMin, SparseTable};
+    ///
+    /// let st: SparseTable<_, ArgMin<i32>> = SparseTable::indexed(&[3, 1, 2, 1]);
+    /// assert_eq!(Some(1), st.argmin(0..4));
+    /// assert_eq!(Some(2), st.argmin(2..3));
+    /// assert_eq!(None, st.argmin(2..2));
+    /// ```
+    pub fn argmin(&self, range: Range<usize>) -> Option<usize> {
+        self.query(range).map(|(_, i)| i)
+    }
+}
+
+impl<T: Ord + Clone> SparseTable<Option<(T, usize)>, ArgMax<T>> {
+    /// Gets the leftmost position of the maximum in `range`, or None if `range` is empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rsalgo::ds::{algebra::ArgMax, SparseTable};
+    ///
+    /// let st: SparseTable<_, ArgMax<i32>> = SparseTable::indexed(&[3, 1, 3, 1]);
+    /// assert_eq!(Some(0), st.argmax(0..4));
+    /// assert_eq!(Some(2), st.argmax(1..4));
+    /// ```
+    pub fn argmax(&self, range: Range<usize>) -> Option<usize> {
+        self.query(range).map(|(_, i)| i)
+    }
+}
+
+/// Disjoint sparse table for range queries of any monoid in O(1), such as sum or product.
+pub struct DisjointSparseTable<T, M = Sum<T>>
+where
+    T: Clone,
+    M: Monoid<Item = T>,
+{
+    len: usize,
+    /// Length of each level, the power of two not less than `len`.
+    size: usize,
+    /// Level `0` keeps the values, and level `h` keeps the results from each position to the
+    /// middle of its block of length `2^h`.
+    table: Vec<T>,
+    monoid: PhantomData<M>,
+}
+
+impl<T, M> DisjointSparseTable<T, M>
+where
+    T: Clone,
+    M: Monoid<Item = T>,
+{
+    /// Creates a DisjointSparseTable from `values` in O(n log n).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rsalgo::ds::{algebra::Product, DisjointSparseTable};
+    ///
+    /// let sum: DisjointSparseTable<i32> = DisjointSparseTable::new(&[3, 1, 2]);
+    /// let product: DisjointSparseTable<i32, Product<i32>> = DisjointSparseTable::new(&[3, 1, 2]);
+    /// ```
+    pub fn new(values: &[T]) -> Self {
+        let len = values.len();
+        let size = len.next_power_of_two().max(2);
+        let levels = size.trailing_zeros() as usize + 1;
+        let mut table = vec![M::identity(); size * levels];
+        table[..len].clone_from_slice(values);
+
+        for h in 1..levels {
+            let (values, level) = table.split_at_mut(h * size);
+            let (values, level) = (&values[..size], &mut level[..size]);
+            let half = 1 << (h - 1);
+            for mid in (half..size).step_by(2 * half) {
+                level[mid - 1] = values[mid - 1].clone();
+                for i in (mid - half..mid - 1).rev() {
+                    level[i] = M::op(&values[i], &level[i + 1]);
+                }
+                level[mid] = values[mid].clone();
+                for i in mid + 1..mid + half {
+                    level[i] = M::op(&level[i - 1], &values[i]);
+                }
+            }
+        }
+
+        DisjointSparseTable {
+            len,
+            size,
+            table,
+            monoid: PhantomData,
+        }
+    }
+
+    /// Gets the result of the values in `range` in O(1), the identity for an empty range.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rsalgo::ds::DisjointSparseTable;
+    ///
+    /// let st: DisjointSparseTable<i32> = DisjointSparseTable::new(&[3, 1, 2, 5]);
+    /// assert_eq!(6, st.query(0..3));
+    /// assert_eq!(7, st.query(2..4));
+    /// assert_eq!(5, st.query(3..4));
+    /// assert_eq!(0, st.query(2..2));
+    /// ```
+    pub fn query(&self, range: Range<usize>) -> T {
+        assert!(
+            range.start <= range.end && range.end <= self.len,
+            "The range is invalid."
+        );
+        let (l, r) = (range.start, range.end);
+        if l == r {
+            return M::identity();
+        }
+        if r - l == 1 {
+            return self.table[l].clone();
+        }
+        let h = (usize::BITS - (l ^ (r - 1)).leading_zeros()) as usize;
+        let level = &self.table[h * self.size..];
+        M::op(&level[l], &level[r - 1])
+    }
+
+    /// Returns the number of values.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rsalgo::ds::DisjointSparseTable;
+    ///
+    /// let st: DisjointSparseTable<i32> = DisjointSparseTable::new(&[3, 1, 2, 5]);
+    /// assert_eq!(4, st.len());
+    /// ```
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns true if there are no values.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rsalgo::ds::DisjointSparseTable;
+    ///
+    /// let st: DisjointSparseTable<i32> = DisjointSparseTable::new(&[]);
+    /// assert!(st.is_empty());
+    /// ```
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
     }
 }
 
 #[cfg(test)]
 mod tests {
-    use super::SparseTable;
+    use super::{DisjointSparseTable, SparseTable};
+    use crate::ds::algebra::{Affine, And, ArgMax, ArgMin, Gcd, Max, Monoid, Or};
     use rand::Rng;
 
     #[test]
     fn st() {
-        const LEN: usize = 50;
         let mut rng = rand::thread_rng();
-        let mut ori = [0; LEN];
-        rng.fill(&mut ori[..]);
-        let st = SparseTable::new(&ori[..]);
-
-        for _ in 0..1000 {
-            let l = rng.gen_range(0, LEN / 2);
-            let r = rng.gen_range(LEN / 2, LEN);
-            dbg!((l, r));
-            assert_eq!(ori[l..r].iter().min().unwrap(), st.min(l..r));
+        for &len in &[0, 1, 2, 3, 8, 50] {
+            let ori: Vec<i32> = (0..len).map(|_| rng.gen_range(-20, 20)).collect();
+            let st: SparseTable<i32> = SparseTable::new(&ori);
+            let max: SparseTable<i32, Max<i32>> = SparseTable::new(&ori);
+            let and: SparseTable<i32, And<i32>> = SparseTable::new(&ori);
+            let or: SparseTable<i32, Or<i32>> = SparseTable::new(&ori);
+            let argmin: SparseTable<_, ArgMin<i32>> = SparseTable::indexed(&ori);
+            let argmax: SparseTable<_, ArgMax<i32>> = SparseTable::indexed(&ori);
+
+            for _ in 0..200 {
+                let l = rng.gen_range(0, len + 1);
+                let r = rng.gen_range(l, len + 1);
+                let slice = &ori[l..r];
+                assert_eq!(
+                    slice.iter().cloned().min().unwrap_or(i32::MAX),
+                    st.query(l..r)
+                );
+                assert_eq!(
+                    slice.iter().cloned().max().unwrap_or(i32::MIN),
+                    max.query(l..r)
+                );
+                assert_eq!(slice.iter().fold(-1, |a, b| a & b), and.query(l..r));
+                assert_eq!(slice.iter().fold(0, |a, b| a | b), or.query(l..r));
+                let first_min = slice
+                    .iter()
+                    .min()
+                    .map(|m| l + slice.iter().position(|v| v == m).unwrap());
+                let first_max = slice
+                    .iter()
+                    .max()
+                    .map(|m| l + slice.iter().position(|v| v == m).unwrap());
+                assert_eq!(first_min, argmin.argmin(l..r));
+                assert_eq!(first_max, argmax.argmax(l..r));
+            }
+        }
+    }
+
+    #[test]
+    fn gcd() {
+        let values: Vec<u32> = vec![12, 18, 24, 9, 27, 7];
+        let st: SparseTable<u32, Gcd<u32>> = SparseTable::new(&values);
+        assert_eq!(6, st.query(0..3));
+        assert_eq!(3, st.query(0..5));
+        assert_eq!(1, st.query(3..6));
+    }
+
+    #[test]
+    #[should_panic]
+    fn out_of_range() {
+        let st: SparseTable<i32> = SparseTable::new(&[1, 2, 3]);
+        st.query(2..4);
+    }
+
+    #[test]
+    fn disjoint() {
+        let mut rng = rand::thread_rng();
+        for &len in &[0, 1, 2, 3, 8, 50] {
+            let ori: Vec<(i64, i64)> = (0..len)
+                .map(|_| (rng.gen_range(-2, 3), rng.gen_range(-5, 5)))
+                .collect();
+            let st: DisjointSparseTable<_, Affine<i64>> = DisjointSparseTable::new(&ori);
+
+            for _ in 0..200 {
+                let l = rng.gen_range(0, len + 1);
+                let r = rng.gen_range(l, len + 1);
+                let expected = ori[l..r]
+                    .iter()
+                    .fold(Affine::<i64>::identity(), |f, g| Affine::<i64>::op(&f, g));
+                assert_eq!(expected, st.query(l..r));
+            }
         }
     }
 }
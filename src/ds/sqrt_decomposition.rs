@@ -0,0 +1,271 @@
+use super::algebra::{Action, Monoid};
+use std::ops::Range;
+
+/// Square-root decomposition over a monoid `M` with lazy range actions of `A`, supporting range
+/// query and range action in O(sqrt n).
+pub struct SqrtDecomposition<M: Monoid, A: Action<M>> {
+    block: usize,
+    /// Values without the pending actions of their blocks.
+    values: Vec<M::Item>,
+    /// Combined value of each block, with its pending action applied.
+    sums: Vec<M::Item>,
+    lazy: Vec<A::Item>,
+}
+
+impl<M: Monoid, A: Action<M>> SqrtDecomposition<M, A> {
+    /// Creates a SqrtDecomposition of `n` identity elements.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rsalgo::ds::{algebra::Sum, SqrtDecomposition};
+    ///
+    /// let sd: SqrtDecomposition<Sum<i64>, Sum<i64>> = SqrtDecomposition::new(10);
+    /// assert_eq!(0, sd.query(0..10));
+    /// ```
+    pub fn new(n: usize) -> Self {
+        Self::from_slice(&vec![M::identity(); n])
+    }
+
+    /// Creates a SqrtDecomposition from `values` in O(n).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rsalgo::ds::{algebra::{Assign, Min}, SqrtDecomposition};
+    ///
+    /// let sd: SqrtDecomposition<Min<i32>, Assign<i32>> = SqrtDecomposition::from_slice(&[3, 1, 2]);
+    /// assert_eq!(1, sd.query(0..3));
+    /// ```
+    pub fn from_slice(values: &[M::Item]) -> Self {
+        let block = ((values.len() as f64).sqrt() as usize).max(1);
+        let count = values.len().div_ceil(block);
+        let mut sd = SqrtDecomposition {
+            block,
+            values: values.to_vec(),
+            sums: vec![M::identity(); count],
+            lazy: vec![A::identity(); count],
+        };
+        for b in 0..count {
+            sd.rebuild(b);
+        }
+        sd
+    }
+
+    /// Returns the number of elements.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rsalgo::ds::{algebra::Sum, SqrtDecomposition};
+    ///
+    /// let sd: SqrtDecomposition<Sum<i64>, Sum<i64>> = SqrtDecomposition::new(10);
+    /// assert_eq!(10, sd.len());
+    /// ```
+    pub fn len(&self) -> usize {
+        self.values.len()
+    }
+
+    /// Returns true if there are no elements.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rsalgo::ds::{algebra::Sum, SqrtDecomposition};
+    ///
+    /// let sd: SqrtDecomposition<Sum<i64>, Sum<i64>> = SqrtDecomposition::new(0);
+    /// assert!(sd.is_empty());
+    /// ```
+    pub fn is_empty(&self) -> bool {
+        self.values.is_empty()
+    }
+
+    fn bounds(&self, b: usize) -> Range<usize> {
+        b * self.block..((b + 1) * self.block).min(self.values.len())
+    }
+
+    fn rebuild(&mut self, b: usize) {
+        self.sums[b] = self.values[self.bounds(b)]
+            .iter()
+            .fold(M::identity(), |acc, v| M::op(&acc, v));
+    }
+
+    /// Applies the pending action of block `b` to its values.
+    fn push(&mut self, b: usize) {
+        let f = std::mem::replace(&mut self.lazy[b], A::identity());
+        let bounds = self.bounds(b);
+        for value in &mut self.values[bounds] {
+            *value = A::act(&f, value, 1);
+        }
+    }
+
+    /// Gets the value at the position `pos`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rsalgo::ds::{algebra::Sum, SqrtDecomposition};
+    ///
+    /// let mut sd: SqrtDecomposition<Sum<i64>, Sum<i64>> = SqrtDecomposition::from_slice(&[1, 2, 3]);
+    /// sd.apply(1..3, 10);
+    /// assert_eq!(12, sd.get(1));
+    /// ```
+    pub fn get(&self, pos: usize) -> M::Item {
+        assert!(pos < self.len(), "The index is out of range.");
+        A::act(&self.lazy[pos / self.block], &self.values[pos], 1)
+    }
+
+    /// Sets the value at the position `pos` to `value`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rsalgo::ds::{algebra::Sum, SqrtDecomposition};
+    ///
+    /// let mut sd: SqrtDecomposition<Sum<i64>, Sum<i64>> = SqrtDecomposition::from_slice(&[1, 2, 3]);
+    /// sd.apply(0..3, 10);
+    /// sd.set(1, 5);
+    /// assert_eq!(29, sd.query(0..3));
+    /// ```
+    pub fn set(&mut self, pos: usize, value: M::Item) {
+        assert!(pos < self.len(), "The index is out of range.");
+        let b = pos / self.block;
+        self.push(b);
+        self.values[pos] = value;
+        self.rebuild(b);
+    }
+
+    /// Combines the values in `range`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rsalgo::ds::{algebra::{Affine, Sum}, SqrtDecomposition};
+    ///
+    /// let mut sd: SqrtDecomposition<Sum<i64>, Affine<i64>> = SqrtDecomposition::from_slice(&[1, 2, 3, 4]);
+    /// sd.apply(0..2, (2, 1));
+    /// assert_eq!(8, sd.query(0..2));
+    /// assert_eq!(0, sd.query(2..2));
+    /// ```
+    pub fn query(&self, range: Range<usize>) -> M::Item {
+        assert!(
+            range.start <= range.end && range.end <= self.len(),
+            "The range is invalid."
+        );
+        let mut res = M::identity();
+        let mut pos = range.start;
+        while pos < range.end {
+            let b = pos / self.block;
+            let bounds = self.bounds(b);
+            if pos == bounds.start && bounds.end <= range.end {
+                res = M::op(&res, &self.sums[b]);
+                pos = bounds.end;
+            } else {
+                res = M::op(&res, &self.get(pos));
+                pos += 1;
+            }
+        }
+        res
+    }
+
+    /// Applies the action `f` to all values in `range`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rsalgo::ds::{algebra::{Assign, Sum}, SqrtDecomposition};
+    ///
+    /// let mut sd: SqrtDecomposition<Sum<i64>, Assign<i64>> = SqrtDecomposition::new(5);
+    /// sd.apply(1..4, Some(3));
+    /// sd.apply(2..5, Some(1));
+    /// assert_eq!(6, sd.query(0..5));
+    /// ```
+    pub fn apply(&mut self, range: Range<usize>, f: A::Item) {
+        assert!(
+            range.start <= range.end && range.end <= self.len(),
+            "The range is invalid."
+        );
+        let mut pos = range.start;
+        while pos < range.end {
+            let b = pos / self.block;
+            let bounds = self.bounds(b);
+            if pos == bounds.start && bounds.end <= range.end {
+                self.sums[b] = A::act(&f, &self.sums[b], bounds.len());
+                self.lazy[b] = A::op(&self.lazy[b], &f);
+            } else {
+                self.push(b);
+                let end = bounds.end.min(range.end);
+                for value in &mut self.values[pos..end] {
+                    *value = A::act(&f, value, 1);
+                }
+                self.rebuild(b);
+            }
+            pos = bounds.end;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::SqrtDecomposition;
+    use crate::ds::algebra::{Affine, Assign, Max, Sum};
+    use rand::Rng;
+
+    #[test]
+    fn affine_sum() {
+        let mut rng = rand::thread_rng();
+        for &len in &[0, 1, 2, 7, 64, 100] {
+            let mut source: Vec<i64> = (0..len).map(|_| rng.gen_range(-10, 10)).collect();
+            let mut sd: SqrtDecomposition<Sum<i64>, Affine<i64>> =
+                SqrtDecomposition::from_slice(&source);
+            for _ in 0..500 {
+                let l = rng.gen_range(0, len + 1);
+                let r = rng.gen_range(l, len + 1);
+                match rng.gen_range(0, 3) {
+                    0 => {
+                        let f = (rng.gen_range(-2, 3), rng.gen_range(-10, 10));
+                        sd.apply(l..r, f);
+                        for v in &mut source[l..r] {
+                            *v = v.wrapping_mul(f.0).wrapping_add(f.1);
+                        }
+                    }
+                    1 if l < len => {
+                        let value = rng.gen_range(-10, 10);
+                        sd.set(l, value);
+                        source[l] = value;
+                    }
+                    _ => {
+                        let expected = source[l..r].iter().fold(0i64, |a, b| a.wrapping_add(*b));
+                        assert_eq!(expected, sd.query(l..r));
+                    }
+                }
+            }
+            for (i, &v) in source.iter().enumerate() {
+                assert_eq!(v, sd.get(i));
+            }
+        }
+    }
+
+    #[test]
+    fn assign_max() {
+        let mut rng = rand::thread_rng();
+        const LEN: usize = 37;
+        let mut source: Vec<i32> = (0..LEN).map(|_| rng.gen_range(-100, 100)).collect();
+        let mut sd: SqrtDecomposition<Max<i32>, Assign<i32>> =
+            SqrtDecomposition::from_slice(&source);
+        for _ in 0..500 {
+            let l = rng.gen_range(0, LEN + 1);
+            let r = rng.gen_range(l, LEN + 1);
+            if rng.gen_ratio(1, 2) {
+                let value = rng.gen_range(-100, 100);
+                sd.apply(l..r, Some(value));
+                for v in &mut source[l..r] {
+                    *v = value;
+                }
+            } else {
+                let expected = source[l..r].iter().cloned().max().unwrap_or(i32::MIN);
+                assert_eq!(expected, sd.query(l..r));
+            }
+        }
+    }
+}
@@ -0,0 +1,778 @@
+use std::cmp::Ordering;
+use std::ops::{Bound, RangeBounds};
+
+type Link<K, V> = Option<Box<Node<K, V>>>;
+
+struct Node<K, V> {
+    key: K,
+    value: V,
+    priority: u64,
+    size: usize,
+    left: Link<K, V>,
+    right: Link<K, V>,
+}
+
+fn size<K, V>(link: &Link<K, V>) -> usize {
+    link.as_ref().map_or(0, |node| node.size)
+}
+
+fn update<K, V>(node: &mut Node<K, V>) {
+    node.size = size(&node.left) + 1 + size(&node.right);
+}
+
+/// Merges two treaps where all keys of `a` are less than that of `b`.
+fn merge<K, V>(a: Link<K, V>, b: Link<K, V>) -> Link<K, V> {
+    match (a, b) {
+        (None, b) => b,
+        (a, None) => a,
+        (Some(mut a), Some(mut b)) => {
+            if a.priority > b.priority {
+                a.right = merge(a.right.take(), Some(b));
+                update(&mut a);
+                Some(a)
+            } else {
+                b.left = merge(Some(a), b.left.take());
+                update(&mut b);
+                Some(b)
+            }
+        }
+    }
+}
+
+/// Splits a treap into the keys satisfying `before` and the others, where `before` holds for a
+/// prefix of the keys.
+fn split<K, V, F: Fn(&K) -> bool>(link: Link<K, V>, before: &F) -> (Link<K, V>, Link<K, V>) {
+    match link {
+        None => (None, None),
+        Some(mut node) => {
+            if before(&node.key) {
+                let (left, right) = split(node.right.take(), before);
+                node.right = left;
+                update(&mut node);
+                (Some(node), right)
+            } else {
+                let (left, right) = split(node.left.take(), before);
+                node.left = right;
+                update(&mut node);
+                (left, Some(node))
+            }
+        }
+    }
+}
+
+/// Splits a treap into the first `index` elements and the others.
+fn split_at<K, V>(link: Link<K, V>, index: usize) -> (Link<K, V>, Link<K, V>) {
+    match link {
+        None => (None, None),
+        Some(mut node) => {
+            let left_size = size(&node.left);
+            if index <= left_size {
+                let (left, right) = split_at(node.left.take(), index);
+                node.left = right;
+                update(&mut node);
+                (left, Some(node))
+            } else {
+                let (left, right) = split_at(node.right.take(), index - left_size - 1);
+                node.right = left;
+                update(&mut node);
+                (Some(node), right)
+            }
+        }
+    }
+}
+
+/// Ordered map on a treap, supporting rank and select in expected O(log n).
+pub struct TreapMap<K: Ord, V> {
+    root: Link<K, V>,
+}
+
+impl<K: Ord, V> Default for TreapMap<K, V> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<K: Ord, V> TreapMap<K, V> {
+    /// Creates an empty TreapMap.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rsalgo::ds::TreapMap;
+    ///
+    /// let map: TreapMap<u32, u32> = TreapMap::new();
+    /// ```
+    pub fn new() -> Self {
+        TreapMap { root: None }
+    }
+
+    /// Returns the number of elements.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rsalgo::ds::TreapMap;
+    ///
+    /// let mut map = TreapMap::new();
+    /// map.insert(1, "a");
+    /// map.insert(1, "b");
+    /// assert_eq!(1, map.len());
+    /// ```
+    pub fn len(&self) -> usize {
+        size(&self.root)
+    }
+
+    /// Returns true if the TreapMap contains no elements.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rsalgo::ds::TreapMap;
+    ///
+    /// let map: TreapMap<u32, u32> = TreapMap::new();
+    /// assert!(map.is_empty());
+    /// ```
+    pub fn is_empty(&self) -> bool {
+        self.root.is_none()
+    }
+
+    fn find(&self, key: &K) -> Option<&Node<K, V>> {
+        let mut link = &self.root;
+        while let Some(node) = link {
+            link = match key.cmp(&node.key) {
+                Ordering::Less => &node.left,
+                Ordering::Greater => &node.right,
+                Ordering::Equal => return Some(node),
+            };
+        }
+        None
+    }
+
+    /// Returns a reference to the value of `key`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rsalgo::ds::TreapMap;
+    ///
+    /// let mut map = TreapMap::new();
+    /// map.insert(1, "a");
+    /// assert_eq!(Some(&"a"), map.get(&1));
+    /// assert_eq!(None, map.get(&2));
+    /// ```
+    pub fn get(&self, key: &K) -> Option<&V> {
+        self.find(key).map(|node| &node.value)
+    }
+
+    /// Returns a mutable reference to the value of `key`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rsalgo::ds::TreapMap;
+    ///
+    /// let mut map = TreapMap::new();
+    /// map.insert(1, 10);
+    /// *map.get_mut(&1).unwrap() += 1;
+    /// assert_eq!(Some(&11), map.get(&1));
+    /// ```
+    pub fn get_mut(&mut self, key: &K) -> Option<&mut V> {
+        let mut link = &mut self.root;
+        while let Some(node) = link {
+            link = match key.cmp(&node.key) {
+                Ordering::Less => &mut node.left,
+                Ordering::Greater => &mut node.right,
+                Ordering::Equal => return Some(&mut node.value),
+            };
+        }
+        None
+    }
+
+    /// Returns true if the TreapMap contains `key`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rsalgo::ds::TreapMap;
+    ///
+    /// let mut map = TreapMap::new();
+    /// map.insert(1, "a");
+    /// assert!(map.contains_key(&1));
+    /// ```
+    pub fn contains_key(&self, key: &K) -> bool {
+        self.find(key).is_some()
+    }
+
+    /// Inserts `key` with `value`, returns the old value if `key` exists.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rsalgo::ds::TreapMap;
+    ///
+    /// let mut map = TreapMap::new();
+    /// assert_eq!(None, map.insert(1, "a"));
+    /// assert_eq!(Some("a"), map.insert(1, "b"));
+    /// ```
+    pub fn insert(&mut self, key: K, value: V) -> Option<V> {
+        if let Some(old) = self.get_mut(&key) {
+            return Some(std::mem::replace(old, value));
+        }
+        let (left, right) = split(self.root.take(), &|k: &K| *k < key);
+        let node = Some(Box::new(Node {
+            key,
+            value,
+            priority: rand::random(),
+            size: 1,
+            left: None,
+            right: None,
+        }));
+        self.root = merge(merge(left, node), right);
+        None
+    }
+
+    /// Removes `key`, returns its value if it exists.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rsalgo::ds::TreapMap;
+    ///
+    /// let mut map = TreapMap::new();
+    /// map.insert(1, "a");
+    /// assert_eq!(Some("a"), map.remove(&1));
+    /// assert_eq!(None, map.remove(&1));
+    /// ```
+    pub fn remove(&mut self, key: &K) -> Option<V> {
+        let (left, right) = split(self.root.take(), &|k: &K| k < key);
+        let (mid, right) = split(right, &|k: &K| k <= key);
+        self.root = merge(left, right);
+        mid.map(|node| node.value)
+    }
+
+    /// Gets the number of keys less than `key`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rsalgo::ds::TreapMap;
+    ///
+    /// let mut map = TreapMap::new();
+    /// map.insert(10, "a");
+    /// map.insert(20, "b");
+    /// assert_eq!(1, map.rank(&20));
+    /// assert_eq!(2, map.rank(&25));
+    /// ```
+    pub fn rank(&self, key: &K) -> usize {
+        self.count_before(|k| k < key)
+    }
+
+    fn count_before<F: Fn(&K) -> bool>(&self, before: F) -> usize {
+        let (mut link, mut res) = (&self.root, 0);
+        while let Some(node) = link {
+            if before(&node.key) {
+                res += size(&node.left) + 1;
+                link = &node.right;
+            } else {
+                link = &node.left;
+            }
+        }
+        res
+    }
+
+    /// Gets the element with the `index`-th (0-indexed) smallest key.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rsalgo::ds::TreapMap;
+    ///
+    /// let mut map = TreapMap::new();
+    /// map.insert(20, "b");
+    /// map.insert(10, "a");
+    /// assert_eq!(Some((&20, &"b")), map.select(1));
+    /// assert_eq!(None, map.select(2));
+    /// ```
+    pub fn select(&self, index: usize) -> Option<(&K, &V)> {
+        let (mut link, mut index) = (&self.root, index);
+        while let Some(node) = link {
+            let left_size = size(&node.left);
+            match index.cmp(&left_size) {
+                Ordering::Less => link = &node.left,
+                Ordering::Equal => return Some((&node.key, &node.value)),
+                Ordering::Greater => {
+                    index -= left_size + 1;
+                    link = &node.right;
+                }
+            }
+        }
+        None
+    }
+
+    /// Splits the TreapMap at `key`, returns the elements with keys not less than `key`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rsalgo::ds::TreapMap;
+    ///
+    /// let mut map = TreapMap::new();
+    /// map.insert(1, "a");
+    /// map.insert(2, "b");
+    /// map.insert(3, "c");
+    /// let right = map.split_off(&2);
+    /// assert_eq!(1, map.len());
+    /// assert_eq!(Some((&2, &"b")), right.select(0));
+    /// ```
+    pub fn split_off(&mut self, key: &K) -> Self {
+        let (left, right) = split(self.root.take(), &|k: &K| k < key);
+        self.root = left;
+        TreapMap { root: right }
+    }
+
+    /// Splits the TreapMap at position `index`, returns the elements from the `index`-th one.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rsalgo::ds::TreapMap;
+    ///
+    /// let mut map = TreapMap::new();
+    /// map.insert(1, "a");
+    /// map.insert(2, "b");
+    /// map.insert(3, "c");
+    /// let right = map.split_off_index(1);
+    /// assert_eq!(1, map.len());
+    /// assert_eq!(Some((&2, &"b")), right.select(0));
+    /// ```
+    pub fn split_off_index(&mut self, index: usize) -> Self {
+        let (left, right) = split_at(self.root.take(), index);
+        self.root = left;
+        TreapMap { root: right }
+    }
+
+    /// Moves all elements of `other` into the TreapMap, the values of `other` win for equal keys.
+    ///
+    /// It takes expected O(log n) if the keys of `other` are all greater, such as the result of
+    /// `split_off`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rsalgo::ds::TreapMap;
+    ///
+    /// let mut map = TreapMap::new();
+    /// map.insert(1, "a");
+    /// let mut other = TreapMap::new();
+    /// other.insert(1, "b");
+    /// other.insert(2, "c");
+    /// map.append(&mut other);
+    /// assert!(other.is_empty());
+    /// assert_eq!(Some(&"b"), map.get(&1));
+    /// assert_eq!(2, map.len());
+    /// ```
+    pub fn append(&mut self, other: &mut Self) {
+        let last = self.len().checked_sub(1).and_then(|i| self.select(i));
+        let ordered = match (last, other.select(0)) {
+            (Some((last, _)), Some((first, _))) => last < first,
+            _ => true,
+        };
+        if ordered {
+            self.root = merge(self.root.take(), other.root.take());
+        } else {
+            let mut stack: Vec<Box<Node<K, V>>> = other.root.take().into_iter().collect();
+            while let Some(mut node) = stack.pop() {
+                stack.extend(node.left.take());
+                stack.extend(node.right.take());
+                self.insert(node.key, node.value);
+            }
+        }
+    }
+
+    /// Gets an iterator over the elements in ascending order of keys.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rsalgo::ds::TreapMap;
+    ///
+    /// let mut map = TreapMap::new();
+    /// map.insert(2, "b");
+    /// map.insert(1, "a");
+    /// assert_eq!(vec![(&1, &"a"), (&2, &"b")], map.iter().collect::<Vec<_>>());
+    /// ```
+    pub fn iter(&self) -> Iter<'_, K, V> {
+        self.range(..)
+    }
+
+    /// Gets an iterator over the elements with keys in `range` in ascending order of keys.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rsalgo::ds::TreapMap;
+    ///
+    /// let mut map = TreapMap::new();
+    /// for i in 0..10 {
+    ///     map.insert(i, i * i);
+    /// }
+    /// let keys: Vec<_> = map.range(3..6).map(|(k, _)| *k).collect();
+    /// assert_eq!(vec![3, 4, 5], keys);
+    /// ```
+    pub fn range<R: RangeBounds<K>>(&self, range: R) -> Iter<'_, K, V> {
+        let start = match range.start_bound() {
+            Bound::Included(start) => self.count_before(|k| k < start),
+            Bound::Excluded(start) => self.count_before(|k| k <= start),
+            Bound::Unbounded => 0,
+        };
+        let end = match range.end_bound() {
+            Bound::Included(end) => self.count_before(|k| k <= end),
+            Bound::Excluded(end) => self.count_before(|k| k < end),
+            Bound::Unbounded => self.len(),
+        };
+
+        let (mut link, mut index, mut stack) = (&self.root, start, Vec::new());
+        while let Some(node) = link {
+            let left_size = size(&node.left);
+            if index <= left_size {
+                stack.push(node.as_ref());
+                link = &node.left;
+            } else {
+                index -= left_size + 1;
+                link = &node.right;
+            }
+        }
+        Iter {
+            stack,
+            remaining: end.saturating_sub(start),
+        }
+    }
+}
+
+/// Iterator over the elements of a TreapMap in ascending order of keys.
+pub struct Iter<'a, K, V> {
+    stack: Vec<&'a Node<K, V>>,
+    remaining: usize,
+}
+
+impl<'a, K, V> Iterator for Iter<'a, K, V> {
+    type Item = (&'a K, &'a V);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.remaining == 0 {
+            return None;
+        }
+        let node = self.stack.pop()?;
+        let mut link = &node.right;
+        while let Some(child) = link {
+            self.stack.push(child);
+            link = &child.left;
+        }
+        self.remaining -= 1;
+        Some((&node.key, &node.value))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.remaining, Some(self.remaining))
+    }
+}
+
+/// Ordered set on a treap, supporting rank and select in expected O(log n).
+pub struct TreapSet<K: Ord> {
+    inner: TreapMap<K, ()>,
+}
+
+impl<K: Ord> Default for TreapSet<K> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<K: Ord> TreapSet<K> {
+    /// Creates an empty TreapSet.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rsalgo::ds::TreapSet;
+    ///
+    /// let set: TreapSet<u32> = TreapSet::new();
+    /// ```
+    pub fn new() -> Self {
+        TreapSet {
+            inner: TreapMap::new(),
+        }
+    }
+
+    /// Returns the number of keys.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rsalgo::ds::TreapSet;
+    ///
+    /// let mut set = TreapSet::new();
+    /// set.insert(1);
+    /// set.insert(1);
+    /// assert_eq!(1, set.len());
+    /// ```
+    pub fn len(&self) -> usize {
+        self.inner.len()
+    }
+
+    /// Returns true if the TreapSet contains no keys.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rsalgo::ds::TreapSet;
+    ///
+    /// let set: TreapSet<u32> = TreapSet::new();
+    /// assert!(set.is_empty());
+    /// ```
+    pub fn is_empty(&self) -> bool {
+        self.inner.is_empty()
+    }
+
+    /// Returns true if the TreapSet contains `key`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rsalgo::ds::TreapSet;
+    ///
+    /// let mut set = TreapSet::new();
+    /// set.insert(1);
+    /// assert!(set.contains(&1));
+    /// ```
+    pub fn contains(&self, key: &K) -> bool {
+        self.inner.contains_key(key)
+    }
+
+    /// Inserts `key`, returns true if it did not exist.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rsalgo::ds::TreapSet;
+    ///
+    /// let mut set = TreapSet::new();
+    /// assert!(set.insert(1));
+    /// assert!(!set.insert(1));
+    /// ```
+    pub fn insert(&mut self, key: K) -> bool {
+        self.inner.insert(key, ()).is_none()
+    }
+
+    /// Removes `key`, returns true if it existed.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rsalgo::ds::TreapSet;
+    ///
+    /// let mut set = TreapSet::new();
+    /// set.insert(1);
+    /// assert!(set.remove(&1));
+    /// assert!(!set.remove(&1));
+    /// ```
+    pub fn remove(&mut self, key: &K) -> bool {
+        self.inner.remove(key).is_some()
+    }
+
+    /// Gets the number of keys less than `key`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rsalgo::ds::TreapSet;
+    ///
+    /// let mut set = TreapSet::new();
+    /// set.insert(10);
+    /// set.insert(20);
+    /// assert_eq!(1, set.rank(&15));
+    /// ```
+    pub fn rank(&self, key: &K) -> usize {
+        self.inner.rank(key)
+    }
+
+    /// Gets the `index`-th (0-indexed) smallest key.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rsalgo::ds::TreapSet;
+    ///
+    /// let mut set = TreapSet::new();
+    /// set.insert(20);
+    /// set.insert(10);
+    /// assert_eq!(Some(&10), set.select(0));
+    /// ```
+    pub fn select(&self, index: usize) -> Option<&K> {
+        self.inner.select(index).map(|(k, _)| k)
+    }
+
+    /// Splits the TreapSet at `key`, returns the keys not less than `key`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rsalgo::ds::TreapSet;
+    ///
+    /// let mut set = TreapSet::new();
+    /// set.insert(1);
+    /// set.insert(2);
+    /// let right = set.split_off(&2);
+    /// assert!(set.contains(&1) && right.contains(&2));
+    /// ```
+    pub fn split_off(&mut self, key: &K) -> Self {
+        TreapSet {
+            inner: self.inner.split_off(key),
+        }
+    }
+
+    /// Splits the TreapSet at position `index`, returns the keys from the `index`-th one.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rsalgo::ds::TreapSet;
+    ///
+    /// let mut set = TreapSet::new();
+    /// set.insert(1);
+    /// set.insert(2);
+    /// let right = set.split_off_index(1);
+    /// assert!(set.contains(&1) && right.contains(&2));
+    /// ```
+    pub fn split_off_index(&mut self, index: usize) -> Self {
+        TreapSet {
+            inner: self.inner.split_off_index(index),
+        }
+    }
+
+    /// Moves all keys of `other` into the TreapSet.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rsalgo::ds::TreapSet;
+    ///
+    /// let mut set = TreapSet::new();
+    /// set.insert(1);
+    /// let mut other = TreapSet::new();
+    /// other.insert(2);
+    /// set.append(&mut other);
+    /// assert_eq!(2, set.len());
+    /// ```
+    pub fn append(&mut self, other: &mut Self) {
+        self.inner.append(&mut other.inner)
+    }
+
+    /// Gets an iterator over the keys in ascending order.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rsalgo::ds::TreapSet;
+    ///
+    /// let mut set = TreapSet::new();
+    /// set.insert(2);
+    /// set.insert(1);
+    /// assert_eq!(vec![&1, &2], set.iter().collect::<Vec<_>>());
+    /// ```
+    pub fn iter(&self) -> impl Iterator<Item = &K> {
+        self.inner.iter().map(|(k, _)| k)
+    }
+
+    /// Gets an iterator over the keys in `range` in ascending order.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rsalgo::ds::TreapSet;
+    ///
+    /// let mut set = TreapSet::new();
+    /// for i in 0..10 {
+    ///     set.insert(i);
+    /// }
+    /// assert_eq!(vec![&8, &9], set.range(8..).collect::<Vec<_>>());
+    /// ```
+    pub fn range<R: RangeBounds<K>>(&self, range: R) -> impl Iterator<Item = &K> {
+        self.inner.range(range).map(|(k, _)| k)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{TreapMap, TreapSet};
+    use rand::Rng;
+    use std::collections::{BTreeMap, BTreeSet};
+
+    #[test]
+    fn treap_map() {
+        let mut rng = rand::thread_rng();
+        let mut map = TreapMap::new();
+        let mut expected = BTreeMap::new();
+
+        for _ in 0..2000 {
+            let key = rng.gen_range(0, 100);
+            match rng.gen_range(0, 4) {
+                0 | 1 => {
+                    let value = rng.gen_range(0, 1000);
+                    assert_eq!(expected.insert(key, value), map.insert(key, value));
+                }
+                2 => assert_eq!(expected.remove(&key), map.remove(&key)),
+                _ => {
+                    assert_eq!(expected.get(&key), map.get(&key));
+                    assert_eq!(expected.range(..key).count(), map.rank(&key));
+                    let index = rng.gen_range(0, expected.len() + 1);
+                    assert_eq!(expected.iter().nth(index), map.select(index));
+                    let end = rng.gen_range(key, 101);
+                    assert!(expected.range(key..=end).eq(map.range(key..=end)));
+                }
+            }
+            assert_eq!(expected.len(), map.len());
+        }
+        assert!(expected.iter().eq(map.iter()));
+    }
+
+    #[test]
+    fn treap_set_split_append() {
+        let mut rng = rand::thread_rng();
+        for _ in 0..50 {
+            let mut set = TreapSet::new();
+            let mut expected = BTreeSet::new();
+            for _ in 0..rng.gen_range(0, 50) {
+                let key = rng.gen_range(0, 100);
+                set.insert(key);
+                expected.insert(key);
+            }
+
+            let key = rng.gen_range(0, 100);
+            let mut right = set.split_off(&key);
+            let mut expected_right = expected.split_off(&key);
+            assert!(expected.iter().eq(set.iter()));
+            assert!(expected_right.iter().eq(right.iter()));
+
+            let index = rng.gen_range(0, right.len() + 1);
+            let mut tail = right.split_off_index(index);
+            assert!(expected_right.iter().take(index).eq(right.iter()));
+            assert!(expected_right.iter().skip(index).eq(tail.iter()));
+
+            right.append(&mut tail);
+            set.append(&mut right);
+            expected.append(&mut expected_right);
+            assert!(expected.iter().eq(set.iter()));
+
+            let mut other = TreapSet::new();
+            for _ in 0..rng.gen_range(0, 20) {
+                let key = rng.gen_range(0, 100);
+                other.insert(key);
+                expected.insert(key);
+            }
+            set.append(&mut other);
+            assert!(other.is_empty());
+            assert!(expected.iter().eq(set.iter()));
+        }
+    }
+}
@@ -0,0 +1,344 @@
+use std::collections::BTreeMap;
+
+struct Node<K> {
+    children: BTreeMap<K, usize>,
+    terminal: bool,
+    count: usize,
+}
+
+impl<K> Node<K> {
+    fn new() -> Self {
+        Node {
+            children: BTreeMap::new(),
+            terminal: false,
+            count: 0,
+        }
+    }
+}
+
+/// Trie of sequences, iterated in lexicographic order
+pub struct Trie<K: Ord + Clone> {
+    nodes: Vec<Node<K>>,
+    free: Vec<usize>,
+}
+
+impl<K: Ord + Clone> Default for Trie<K> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<K: Ord + Clone> Trie<K> {
+    /// Creates an empty Trie.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rsalgo::ds::Trie;
+    ///
+    /// let t: Trie<u8> = Trie::new();
+    /// ```
+    pub fn new() -> Self {
+        Trie {
+            nodes: vec![Node::new()],
+            free: Vec::new(),
+        }
+    }
+
+    /// Returns the number of keys.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rsalgo::ds::Trie;
+    ///
+    /// let mut t = Trie::new();
+    /// t.insert("ab".as_bytes());
+    /// t.insert("ab".as_bytes());
+    /// assert_eq!(1, t.len());
+    /// ```
+    pub fn len(&self) -> usize {
+        self.nodes[0].count
+    }
+
+    /// Returns true if the Trie contains no keys.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rsalgo::ds::Trie;
+    ///
+    /// let t: Trie<u8> = Trie::new();
+    /// assert!(t.is_empty());
+    /// ```
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Inserts `key`, returns true if it was not present.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rsalgo::ds::Trie;
+    ///
+    /// let mut t = Trie::new();
+    /// assert!(t.insert("ab".as_bytes()));
+    /// assert!(!t.insert("ab".as_bytes()));
+    /// ```
+    pub fn insert(&mut self, key: &[K]) -> bool {
+        if self.contains(key) {
+            return false;
+        }
+        let mut v = 0;
+        self.nodes[v].count += 1;
+        for symbol in key {
+            v = match self.nodes[v].children.get(symbol) {
+                Some(&u) => u,
+                None => {
+                    let u = self.alloc();
+                    self.nodes[v].children.insert(symbol.clone(), u);
+                    u
+                }
+            };
+            self.nodes[v].count += 1;
+        }
+        self.nodes[v].terminal = true;
+        true
+    }
+
+    fn alloc(&mut self) -> usize {
+        match self.free.pop() {
+            Some(u) => {
+                self.nodes[u] = Node::new();
+                u
+            }
+            None => {
+                self.nodes.push(Node::new());
+                self.nodes.len() - 1
+            }
+        }
+    }
+
+    /// Removes `key`, returns true if it was present.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rsalgo::ds::Trie;
+    ///
+    /// let mut t = Trie::new();
+    /// t.insert("ab".as_bytes());
+    /// assert!(t.remove("ab".as_bytes()));
+    /// assert!(!t.remove("ab".as_bytes()));
+    /// ```
+    pub fn remove(&mut self, key: &[K]) -> bool {
+        if !self.contains(key) {
+            return false;
+        }
+        let mut v = 0;
+        self.nodes[v].count -= 1;
+        for symbol in key {
+            let u = self.nodes[v].children[symbol];
+            self.nodes[u].count -= 1;
+            if self.nodes[u].count == 0 {
+                self.nodes[v].children.remove(symbol);
+                self.release(u);
+                return true;
+            }
+            v = u;
+        }
+        self.nodes[v].terminal = false;
+        true
+    }
+
+    fn release(&mut self, v: usize) {
+        let children: Vec<usize> = self.nodes[v].children.values().cloned().collect();
+        self.nodes[v].children.clear();
+        self.free.push(v);
+        for u in children {
+            self.release(u);
+        }
+    }
+
+    /// Get the node of `key`.
+    pub(crate) fn node(&self, key: &[K]) -> Option<usize> {
+        let mut v = 0;
+        for symbol in key {
+            v = *self.nodes[v].children.get(symbol)?;
+        }
+        Some(v)
+    }
+
+    /// Get the child of node `v` by `symbol`.
+    pub(crate) fn child(&self, v: usize, symbol: &K) -> Option<usize> {
+        self.nodes[v].children.get(symbol).cloned()
+    }
+
+    /// Get the children of node `v` in ascending order.
+    pub(crate) fn children(&self, v: usize) -> impl Iterator<Item = (&K, usize)> {
+        self.nodes[v].children.iter().map(|(k, &u)| (k, u))
+    }
+
+    /// Get the number of allocated nodes, node ids are less than it.
+    pub(crate) fn node_capacity(&self) -> usize {
+        self.nodes.len()
+    }
+
+    /// Returns true if the Trie contains `key`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rsalgo::ds::Trie;
+    ///
+    /// let mut t = Trie::new();
+    /// t.insert("abc".as_bytes());
+    /// assert!(t.contains("abc".as_bytes()));
+    /// assert!(!t.contains("ab".as_bytes()));
+    /// ```
+    pub fn contains(&self, key: &[K]) -> bool {
+        self.node(key).is_some_and(|v| self.nodes[v].terminal)
+    }
+
+    /// Get the number of keys starting with `prefix`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rsalgo::ds::Trie;
+    ///
+    /// let mut t = Trie::new();
+    /// t.insert("app".as_bytes());
+    /// t.insert("apple".as_bytes());
+    /// t.insert("bat".as_bytes());
+    /// assert_eq!(2, t.prefix_count("ap".as_bytes()));
+    /// ```
+    pub fn prefix_count(&self, prefix: &[K]) -> usize {
+        self.node(prefix).map_or(0, |v| self.nodes[v].count)
+    }
+
+    /// Get the length of the longest key which is a prefix of `query`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rsalgo::ds::Trie;
+    ///
+    /// let mut t = Trie::new();
+    /// t.insert("ab".as_bytes());
+    /// t.insert("abcd".as_bytes());
+    /// assert_eq!(Some(2), t.longest_prefix("abcx".as_bytes()));
+    /// assert_eq!(None, t.longest_prefix("b".as_bytes()));
+    /// ```
+    pub fn longest_prefix(&self, query: &[K]) -> Option<usize> {
+        let mut v = 0;
+        let mut res = if self.nodes[v].terminal {
+            Some(0)
+        } else {
+            None
+        };
+        for (i, symbol) in query.iter().enumerate() {
+            v = match self.child(v, symbol) {
+                Some(u) => u,
+                None => break,
+            };
+            if self.nodes[v].terminal {
+                res = Some(i + 1);
+            }
+        }
+        res
+    }
+
+    /// Gets an iterator over the keys in lexicographic order.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rsalgo::ds::Trie;
+    ///
+    /// let mut t = Trie::new();
+    /// t.insert(&[2, 1]);
+    /// t.insert(&[1]);
+    /// t.insert(&[1, 3]);
+    /// assert_eq!(vec![vec![1], vec![1, 3], vec![2, 1]], t.iter().collect::<Vec<_>>());
+    /// ```
+    pub fn iter(&self) -> Iter<'_, K> {
+        Iter {
+            trie: self,
+            stack: vec![(0, Vec::new())],
+        }
+    }
+}
+
+/// Iterator over the keys of a Trie in lexicographic order.
+pub struct Iter<'a, K: Ord + Clone> {
+    trie: &'a Trie<K>,
+    stack: Vec<(usize, Vec<K>)>,
+}
+
+impl<'a, K: Ord + Clone> Iterator for Iter<'a, K> {
+    type Item = Vec<K>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while let Some((v, key)) = self.stack.pop() {
+            for (symbol, u) in self.trie.children(v).collect::<Vec<_>>().into_iter().rev() {
+                let mut next = key.clone();
+                next.push(symbol.clone());
+                self.stack.push((u, next));
+            }
+            if self.trie.nodes[v].terminal {
+                return Some(key);
+            }
+        }
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Trie;
+    use rand::Rng;
+    use std::collections::BTreeSet;
+
+    #[test]
+    fn trie() {
+        let mut rng = rand::thread_rng();
+        let mut t = Trie::new();
+        let mut set = BTreeSet::new();
+
+        for _ in 0..2000 {
+            let key: Vec<u8> = (0..rng.gen_range(0, 5))
+                .map(|_| rng.gen_range(0, 3))
+                .collect();
+            if rng.gen_ratio(2, 3) {
+                assert_eq!(set.insert(key.clone()), t.insert(&key));
+            } else {
+                assert_eq!(set.remove(&key), t.remove(&key));
+            }
+            assert_eq!(set.len(), t.len());
+
+            let query: Vec<u8> = (0..rng.gen_range(0, 5))
+                .map(|_| rng.gen_range(0, 3))
+                .collect();
+            assert_eq!(set.contains(&query), t.contains(&query));
+            assert_eq!(
+                set.iter().filter(|k| k.starts_with(&query)).count(),
+                t.prefix_count(&query)
+            );
+            assert_eq!(
+                set.iter()
+                    .filter(|k| query.starts_with(k))
+                    .map(|k| k.len())
+                    .max(),
+                t.longest_prefix(&query)
+            );
+        }
+
+        assert_eq!(
+            set.into_iter().collect::<Vec<_>>(),
+            t.iter().collect::<Vec<_>>()
+        );
+        assert!(t.nodes.len() - t.free.len() <= 1 + 3 + 9 + 27 + 81);
+    }
+}
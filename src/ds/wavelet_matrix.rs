@@ -0,0 +1,320 @@
+use super::BitVector;
+use std::ops::Range;
+
+/// Wavelet matrix over non-negative integers, such as the ranks from a `Discretizer`, supporting
+/// rank, select and range quantile queries in O(log σ).
+pub struct WaveletMatrix {
+    len: usize,
+    /// Bit vectors from the highest bit to the lowest bit.
+    levels: Vec<BitVector>,
+    /// Number of zeros in each level.
+    zeros: Vec<usize>,
+}
+
+impl WaveletMatrix {
+    /// Creates a WaveletMatrix of `values` in O(n log σ).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rsalgo::base::Discretizer;
+    /// use rsalgo::ds::WaveletMatrix;
+    ///
+    /// let values = [30, 10, 20, 10];
+    /// let d = Discretizer::new(&values);
+    /// let wm = WaveletMatrix::new(&d.compress(&values));
+    /// assert_eq!(Some(&20), d.value(wm.kth_smallest(0..3, 1).unwrap()));
+    /// ```
+    pub fn new(values: &[usize]) -> Self {
+        let max = values.iter().cloned().max().unwrap_or(0);
+        let bits = (usize::BITS - max.leading_zeros()) as usize;
+        let mut current = values.to_vec();
+        let (mut levels, mut zeros) = (Vec::with_capacity(bits), Vec::with_capacity(bits));
+        for level in (0..bits).rev() {
+            let bits: Vec<bool> = current.iter().map(|v| v >> level & 1 == 1).collect();
+            let (mut next, ones): (Vec<usize>, Vec<usize>) =
+                current.iter().partition(|&&v| v >> level & 1 == 0);
+            zeros.push(next.len());
+            next.extend(ones);
+            levels.push(BitVector::new(&bits));
+            current = next;
+        }
+        WaveletMatrix {
+            len: values.len(),
+            levels,
+            zeros,
+        }
+    }
+
+    /// Returns the number of values.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rsalgo::ds::WaveletMatrix;
+    ///
+    /// let wm = WaveletMatrix::new(&[3, 1, 2]);
+    /// assert_eq!(3, wm.len());
+    /// ```
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns true if there are no values.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rsalgo::ds::WaveletMatrix;
+    ///
+    /// let wm = WaveletMatrix::new(&[]);
+    /// assert!(wm.is_empty());
+    /// ```
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Maps the position `index` in a level to the next level by its bit.
+    fn next(&self, level: usize, bit: bool, index: usize) -> usize {
+        let rank = self.levels[level].rank(bit, index);
+        if bit {
+            self.zeros[level] + rank
+        } else {
+            rank
+        }
+    }
+
+    /// Returns true if `value` fits in the bits of the levels.
+    fn contains_bits(&self, value: usize) -> bool {
+        value.checked_shr(self.levels.len() as u32).unwrap_or(0) == 0
+    }
+
+    fn bit(&self, level: usize, value: usize) -> bool {
+        value >> (self.levels.len() - 1 - level) & 1 == 1
+    }
+
+    /// Gets the value at `index`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rsalgo::ds::WaveletMatrix;
+    ///
+    /// let wm = WaveletMatrix::new(&[3, 1, 2]);
+    /// assert_eq!(1, wm.access(1));
+    /// ```
+    pub fn access(&self, index: usize) -> usize {
+        assert!(index < self.len, "The index is out of range.");
+        let (mut index, mut res) = (index, 0);
+        for level in 0..self.levels.len() {
+            let bit = self.levels[level].get(index);
+            res = res << 1 | bit as usize;
+            index = self.next(level, bit, index);
+        }
+        res
+    }
+
+    /// Gets the number of `value` in `[0, end)`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rsalgo::ds::WaveletMatrix;
+    ///
+    /// let wm = WaveletMatrix::new(&[3, 1, 3, 2]);
+    /// assert_eq!(1, wm.rank(3, 2));
+    /// assert_eq!(2, wm.rank(3, 4));
+    /// assert_eq!(0, wm.rank(7, 4));
+    /// ```
+    pub fn rank(&self, value: usize, end: usize) -> usize {
+        assert!(end <= self.len, "The index is out of range.");
+        if !self.contains_bits(value) {
+            return 0;
+        }
+        let (mut start, mut end) = (0, end);
+        for level in 0..self.levels.len() {
+            let bit = self.bit(level, value);
+            start = self.next(level, bit, start);
+            end = self.next(level, bit, end);
+        }
+        end - start
+    }
+
+    /// Gets the position of the `k`-th (0-indexed) `value`, or None if there are not so many.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rsalgo::ds::WaveletMatrix;
+    ///
+    /// let wm = WaveletMatrix::new(&[3, 1, 3, 2]);
+    /// assert_eq!(Some(2), wm.select(3, 1));
+    /// assert_eq!(None, wm.select(3, 2));
+    /// ```
+    pub fn select(&self, value: usize, k: usize) -> Option<usize> {
+        if k >= self.rank(value, self.len) {
+            return None;
+        }
+        let mut start = 0;
+        for level in 0..self.levels.len() {
+            start = self.next(level, self.bit(level, value), start);
+        }
+        let mut index = start + k;
+        for level in (0..self.levels.len()).rev() {
+            let bit = self.bit(level, value);
+            let offset = if bit { self.zeros[level] } else { 0 };
+            index = self.levels[level].select(bit, index - offset).unwrap();
+        }
+        Some(index)
+    }
+
+    /// Gets the `k`-th (0-indexed) smallest value in `range`, or None if `k` is not less than the
+    /// length of `range`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rsalgo::ds::WaveletMatrix;
+    ///
+    /// let wm = WaveletMatrix::new(&[5, 1, 4, 2, 3]);
+    /// assert_eq!(Some(2), wm.kth_smallest(1..4, 1));
+    /// assert_eq!(None, wm.kth_smallest(1..4, 3));
+    /// ```
+    pub fn kth_smallest(&self, range: Range<usize>, k: usize) -> Option<usize> {
+        assert!(
+            range.start <= range.end && range.end <= self.len,
+            "The range is invalid."
+        );
+        if k >= range.end - range.start {
+            return None;
+        }
+        let (mut start, mut end, mut k, mut res) = (range.start, range.end, k, 0);
+        for level in 0..self.levels.len() {
+            let zeros = self.levels[level].rank(false, end) - self.levels[level].rank(false, start);
+            let bit = k >= zeros;
+            if bit {
+                k -= zeros;
+            }
+            res = res << 1 | bit as usize;
+            start = self.next(level, bit, start);
+            end = self.next(level, bit, end);
+        }
+        Some(res)
+    }
+
+    /// Gets the number of values less than `value` in `range`.
+    fn count_less(&self, range: Range<usize>, value: usize) -> usize {
+        if !self.contains_bits(value) {
+            return range.end - range.start;
+        }
+        let (mut start, mut end, mut res) = (range.start, range.end, 0);
+        for level in 0..self.levels.len() {
+            let bit = self.bit(level, value);
+            if bit {
+                res += self.levels[level].rank(false, end) - self.levels[level].rank(false, start);
+            }
+            start = self.next(level, bit, start);
+            end = self.next(level, bit, end);
+        }
+        res
+    }
+
+    /// Gets the number of values in `values` within `range`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rsalgo::ds::WaveletMatrix;
+    ///
+    /// let wm = WaveletMatrix::new(&[5, 1, 4, 2, 3]);
+    /// assert_eq!(2, wm.range_freq(0..4, 2..5));
+    /// ```
+    pub fn range_freq(&self, range: Range<usize>, values: Range<usize>) -> usize {
+        assert!(
+            range.start <= range.end && range.end <= self.len,
+            "The range is invalid."
+        );
+        if values.start >= values.end {
+            return 0;
+        }
+        self.count_less(range.clone(), values.end) - self.count_less(range, values.start)
+    }
+
+    /// Gets the largest value less than `upper` in `range`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rsalgo::ds::WaveletMatrix;
+    ///
+    /// let wm = WaveletMatrix::new(&[5, 1, 4, 2, 3]);
+    /// assert_eq!(Some(4), wm.prev_value(0..3, 5));
+    /// assert_eq!(None, wm.prev_value(0..3, 1));
+    /// ```
+    pub fn prev_value(&self, range: Range<usize>, upper: usize) -> Option<usize> {
+        let count = self.range_freq(range.clone(), 0..upper);
+        count
+            .checked_sub(1)
+            .and_then(|k| self.kth_smallest(range, k))
+    }
+
+    /// Gets the smallest value not less than `lower` in `range`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rsalgo::ds::WaveletMatrix;
+    ///
+    /// let wm = WaveletMatrix::new(&[5, 1, 4, 2, 3]);
+    /// assert_eq!(Some(4), wm.next_value(0..3, 2));
+    /// assert_eq!(None, wm.next_value(0..3, 6));
+    /// ```
+    pub fn next_value(&self, range: Range<usize>, lower: usize) -> Option<usize> {
+        let count = self.range_freq(range.clone(), 0..lower);
+        self.kth_smallest(range, count)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::WaveletMatrix;
+    use rand::Rng;
+
+    #[test]
+    fn wavelet_matrix() {
+        let mut rng = rand::thread_rng();
+        for &(len, sigma) in &[(0, 1), (1, 1), (50, 1), (50, 8), (100, 37)] {
+            let values: Vec<usize> = (0..len).map(|_| rng.gen_range(0, sigma)).collect();
+            let wm = WaveletMatrix::new(&values);
+
+            for (i, &v) in values.iter().enumerate() {
+                assert_eq!(v, wm.access(i));
+            }
+            for _ in 0..200 {
+                let value = rng.gen_range(0, sigma + 2);
+                let end = rng.gen_range(0, len + 1);
+                let count = values[..end].iter().filter(|&&v| v == value).count();
+                assert_eq!(count, wm.rank(value, end));
+                let positions: Vec<usize> = (0..len).filter(|&i| values[i] == value).collect();
+                let k = rng.gen_range(0, positions.len() + 2);
+                assert_eq!(positions.get(k).cloned(), wm.select(value, k));
+
+                let l = rng.gen_range(0, len + 1);
+                let r = rng.gen_range(l, len + 1);
+                let mut sorted = values[l..r].to_vec();
+                sorted.sort();
+                let k = rng.gen_range(0, sorted.len() + 2);
+                assert_eq!(sorted.get(k).cloned(), wm.kth_smallest(l..r, k));
+
+                let lo = rng.gen_range(0, sigma + 2);
+                let hi = rng.gen_range(0, sigma + 2);
+                let freq = sorted.iter().filter(|&&v| lo <= v && v < hi).count();
+                assert_eq!(freq, wm.range_freq(l..r, lo..hi));
+                let prev = sorted.iter().rev().find(|&&v| v < hi).cloned();
+                assert_eq!(prev, wm.prev_value(l..r, hi));
+                let next = sorted.iter().find(|&&v| v >= lo).cloned();
+                assert_eq!(next, wm.next_value(l..r, lo));
+            }
+        }
+    }
+}
@@ -0,0 +1,259 @@
+use super::algebra::{CommutativeMonoid, Group, Sum};
+use std::marker::PhantomData;
+
+/// Error of contradicting constraints in a WeightedDisjointSet.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PotentialConflict<T> {
+    /// The difference already implied by earlier constraints.
+    pub existing: T,
+    /// The difference required by the new constraint.
+    pub required: T,
+}
+
+/// Disjoint set with a potential per element, keeping the differences of potentials in each set.
+///
+/// Potentials are values of an abelian group, such as `Sum<i64>` or `Xor<bool>` for parities.
+pub struct WeightedDisjointSet<T, G = Sum<T>>
+where
+    T: Clone + PartialEq,
+    G: Group<Item = T> + CommutativeMonoid,
+{
+    size: usize,
+    parent: Vec<usize>,
+    count: Vec<usize>,
+    /// Potential of each element minus the potential of its parent.
+    weight: Vec<T>,
+    group: PhantomData<G>,
+}
+
+impl<T, G> WeightedDisjointSet<T, G>
+where
+    T: Clone + PartialEq,
+    G: Group<Item = T> + CommutativeMonoid,
+{
+    /// Create a weighted disjoint set with `capacity` elements, whose potentials are the identity.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let s = rsalgo::ds::WeightedDisjointSet::<i64>::new(100);
+    /// ```
+    pub fn new(capacity: usize) -> Self {
+        WeightedDisjointSet {
+            size: capacity,
+            parent: (0..capacity).collect(),
+            count: vec![1; capacity],
+            weight: vec![G::identity(); capacity],
+            group: PhantomData,
+        }
+    }
+
+    /// Get the number of disjoint sets.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let s = rsalgo::ds::WeightedDisjointSet::<i64>::new(100);
+    ///
+    /// assert_eq!(100, s.len());
+    /// ```
+    pub fn len(&self) -> usize {
+        self.size
+    }
+
+    /// Get the number of elements.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let s = rsalgo::ds::WeightedDisjointSet::<i64>::new(100);
+    ///
+    /// assert_eq!(100, s.capacity());
+    /// ```
+    pub fn capacity(&self) -> usize {
+        self.parent.len()
+    }
+
+    /// Returns true if the set contains no elements.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let s = rsalgo::ds::WeightedDisjointSet::<i64>::new(0);
+    ///
+    /// assert!(s.is_empty());
+    /// ```
+    pub fn is_empty(&self) -> bool {
+        self.parent.is_empty()
+    }
+
+    /// Get the representation element of `id`'s set.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let mut s = rsalgo::ds::WeightedDisjointSet::<i64>::new(100);
+    ///
+    /// assert_eq!(0, s.find(0));
+    /// ```
+    pub fn find(&mut self, id: usize) -> usize {
+        let p = self.parent[id];
+        if p == id {
+            return id;
+        }
+        let root = self.find(p);
+        self.weight[id] = G::op(&self.weight[id], &self.weight[p]);
+        self.parent[id] = root;
+        root
+    }
+
+    /// Get the potential of `id` minus the potential of its representation element.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let mut s = rsalgo::ds::WeightedDisjointSet::<i64>::new(100);
+    /// s.unite(0, 1, 5).unwrap();
+    /// let root = s.find(1);
+    /// assert_eq!(5, s.potential(1) - s.potential(0));
+    /// assert_eq!(0, s.potential(root));
+    /// ```
+    pub fn potential(&mut self, id: usize) -> T {
+        self.find(id);
+        self.weight[id].clone()
+    }
+
+    /// Return whether the two element is in the same set.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let mut s = rsalgo::ds::WeightedDisjointSet::<i64>::new(100);
+    ///
+    /// assert_eq!(false, s.in_same(0, 1));
+    /// ```
+    pub fn in_same(&mut self, a: usize, b: usize) -> bool {
+        self.find(a) == self.find(b)
+    }
+
+    /// Get `pot(b) - pot(a)` if the two element is in the same set.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let mut s = rsalgo::ds::WeightedDisjointSet::<i64>::new(100);
+    /// s.unite(0, 1, 3).unwrap();
+    /// s.unite(1, 2, 4).unwrap();
+    /// assert_eq!(Some(7), s.diff(0, 2));
+    /// assert_eq!(Some(-4), s.diff(2, 1));
+    /// assert_eq!(None, s.diff(0, 3));
+    /// ```
+    pub fn diff(&mut self, a: usize, b: usize) -> Option<T> {
+        if self.in_same(a, b) {
+            Some(G::inv_op(&self.weight[b], &self.weight[a]))
+        } else {
+            None
+        }
+    }
+
+    /// Unite `a`'s set and `b`'s set with the constraint `pot(b) - pot(a) = w`.
+    ///
+    /// Returns true if two sets are merged, or an error if the constraint contradicts earlier ones.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rsalgo::ds::{PotentialConflict, WeightedDisjointSet};
+    ///
+    /// let mut s = WeightedDisjointSet::<i64>::new(3);
+    /// assert_eq!(Ok(true), s.unite(0, 1, 2));
+    /// assert_eq!(Ok(true), s.unite(1, 2, 2));
+    /// assert_eq!(Ok(false), s.unite(0, 2, 4));
+    /// assert_eq!(
+    ///     Err(PotentialConflict { existing: 4, required: 3 }),
+    ///     s.unite(0, 2, 3)
+    /// );
+    /// ```
+    pub fn unite(&mut self, a: usize, b: usize, w: T) -> Result<bool, PotentialConflict<T>> {
+        let (ra, rb) = (self.find(a), self.find(b));
+        let (pa, pb) = (&self.weight[a], &self.weight[b]);
+        if ra == rb {
+            let existing = G::inv_op(pb, pa);
+            return if existing == w {
+                Ok(false)
+            } else {
+                Err(PotentialConflict {
+                    existing,
+                    required: w,
+                })
+            };
+        }
+
+        // pot(rb) - pot(ra) = w + pa - pb
+        let offset = G::inv_op(&G::op(&w, pa), pb);
+        if self.count[ra] < self.count[rb] {
+            self.parent[ra] = rb;
+            self.weight[ra] = G::inverse(&offset);
+            self.count[rb] += self.count[ra];
+        } else {
+            self.parent[rb] = ra;
+            self.weight[rb] = offset;
+            self.count[ra] += self.count[rb];
+        }
+        self.size -= 1;
+        Ok(true)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::WeightedDisjointSet;
+    use crate::ds::algebra::Xor;
+    use rand::Rng;
+
+    #[test]
+    fn weighted_disjoint_set() {
+        let mut rng = rand::thread_rng();
+        const SIZE: usize = 50;
+
+        let mut s = WeightedDisjointSet::<i64>::new(SIZE);
+        let truth: Vec<i64> = (0..SIZE).map(|_| rng.gen_range(-100, 100)).collect();
+        let mut group: Vec<usize> = (0..SIZE).collect();
+
+        for _ in 0..200 {
+            let a = rng.gen_range(0, SIZE);
+            let b = rng.gen_range(0, SIZE);
+            let w = truth[b] - truth[a];
+            let (ga, gb) = (group[a], group[b]);
+
+            if ga == gb {
+                assert_eq!(Some(w), s.diff(a, b));
+                assert_eq!(Ok(false), s.unite(a, b, w));
+                assert!(s.unite(a, b, w + 1).is_err());
+            } else {
+                assert_eq!(None, s.diff(a, b));
+                assert_eq!(Ok(true), s.unite(a, b, w));
+                for g in group.iter_mut() {
+                    if *g == gb {
+                        *g = ga;
+                    }
+                }
+            }
+        }
+
+        let mut groups = group.clone();
+        groups.sort();
+        groups.dedup();
+        assert_eq!(groups.len(), s.len());
+    }
+
+    #[test]
+    fn bipartite() {
+        let mut s = WeightedDisjointSet::<bool, Xor<bool>>::new(4);
+        for &(a, b) in &[(0, 1), (1, 2), (2, 3), (3, 0)] {
+            assert!(s.unite(a, b, true).is_ok());
+        }
+        assert_eq!(Some(false), s.diff(0, 2));
+        assert!(s.unite(0, 2, true).is_err());
+    }
+}
@@ -12,10 +12,10 @@ where
     edges_len: usize,
 }
 
-impl<'a, V, E> DirectedGraph<V, E>
+impl<V, E> DirectedGraph<V, E>
 where
-    V: IdVertex + 'a,
-    E: IdEdge + 'a,
+    V: IdVertex,
+    E: IdEdge,
 {
     pub fn new() -> Self {
         DirectedGraph {
@@ -25,6 +25,7 @@ where
         }
     }
 
+    #[allow(clippy::result_unit_err)]
     pub fn from(vertices: Vec<V>, edges: Vec<E>) -> Result<Self, ()> {
         let mut g = DirectedGraph::new();
 
@@ -88,7 +89,7 @@ where
     fn insert_edge(&mut self, edge: Self::TEdge) -> Result<(), ()> {
         let (from, to) = (edge.from(), edge.to());
         if self.vertices.contains_key(&from) && self.vertices.contains_key(&to) {
-            let te = self.edges.entry(edge.from()).or_insert_with(Vec::new);
+            let te = self.edges.entry(edge.from()).or_default();
             te.push(edge);
             self.edges_len += 1;
             Ok(())
@@ -110,10 +111,7 @@ where
             std::collections::hash_map::Entry::Vacant(_) => return None,
         };
         let te = ten.get_mut();
-        let pos = match te.iter().position(|e| e == edge) {
-            Some(val) => val,
-            None => return None,
-        };
+        let pos = te.iter().position(|e| e == edge)?;
         let res = te.swap_remove(pos);
 
         self.edges_len -= 1;
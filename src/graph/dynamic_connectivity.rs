@@ -0,0 +1,150 @@
+use crate::ds::RollbackDisjointSet;
+use std::collections::HashMap;
+
+/// Operation on an undirected graph for `offline_dynamic_connectivity`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ConnectivityOperation {
+    /// Inserts an edge, parallel edges are allowed.
+    Insert(usize, usize),
+    /// Removes one copy of an existing edge.
+    Remove(usize, usize),
+    /// Queries whether two vertices are connected.
+    Connected(usize, usize),
+}
+
+/// Answers connectivity queries of a graph with `n` vertices under edge insertions and removals
+/// offline in O(q log q log n), by divide and conquer on a segment tree over time.
+///
+/// Returns the answers of `Connected` operations in order.
+///
+/// # Examples
+///
+/// ```
+/// use rsalgo::graph::{offline_dynamic_connectivity, ConnectivityOperation::*};
+///
+/// let ops = [Insert(0, 1), Insert(1, 2), Connected(0, 2), Remove(0, 1), Connected(0, 2)];
+/// assert_eq!(vec![true, false], offline_dynamic_connectivity(3, &ops));
+/// ```
+pub fn offline_dynamic_connectivity(n: usize, operations: &[ConnectivityOperation]) -> Vec<bool> {
+    let q = operations.len();
+    let mut tree = vec![Vec::new(); 4 * q.max(1)];
+    let mut alive: HashMap<(usize, usize), Vec<usize>> = HashMap::new();
+
+    for (time, op) in operations.iter().enumerate() {
+        match *op {
+            ConnectivityOperation::Insert(u, v) => {
+                alive.entry((u.min(v), u.max(v))).or_default().push(time);
+            }
+            ConnectivityOperation::Remove(u, v) => {
+                let edge = (u.min(v), u.max(v));
+                let start = alive
+                    .get_mut(&edge)
+                    .and_then(|starts| starts.pop())
+                    .expect("The edge is not present.");
+                add_interval(&mut tree, 1, 0, q, start, time, edge);
+            }
+            ConnectivityOperation::Connected(..) => (),
+        }
+    }
+    for (edge, starts) in alive {
+        for start in starts {
+            add_interval(&mut tree, 1, 0, q, start, q, edge);
+        }
+    }
+
+    let mut ds = RollbackDisjointSet::new(n);
+    let mut res = Vec::new();
+    if q > 0 {
+        solve(&tree, 1, 0, q, operations, &mut ds, &mut res);
+    }
+    res
+}
+
+/// Adds `edge` to the nodes covering the time interval `start..end`.
+fn add_interval(
+    tree: &mut [Vec<(usize, usize)>],
+    node: usize,
+    lo: usize,
+    hi: usize,
+    start: usize,
+    end: usize,
+    edge: (usize, usize),
+) {
+    if end <= lo || hi <= start {
+        return;
+    }
+    if start <= lo && hi <= end {
+        tree[node].push(edge);
+        return;
+    }
+    let mid = (lo + hi) / 2;
+    add_interval(tree, node * 2, lo, mid, start, end, edge);
+    add_interval(tree, node * 2 + 1, mid, hi, start, end, edge);
+}
+
+fn solve(
+    tree: &[Vec<(usize, usize)>],
+    node: usize,
+    lo: usize,
+    hi: usize,
+    operations: &[ConnectivityOperation],
+    ds: &mut RollbackDisjointSet,
+    res: &mut Vec<bool>,
+) {
+    let snapshot = ds.snapshot();
+    for &(u, v) in &tree[node] {
+        ds.unite(u, v);
+    }
+    if hi - lo == 1 {
+        if let ConnectivityOperation::Connected(u, v) = operations[lo] {
+            res.push(ds.in_same(u, v));
+        }
+    } else {
+        let mid = (lo + hi) / 2;
+        solve(tree, node * 2, lo, mid, operations, ds, res);
+        solve(tree, node * 2 + 1, mid, hi, operations, ds, res);
+    }
+    ds.rollback(snapshot);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{offline_dynamic_connectivity, ConnectivityOperation};
+    use crate::ds::DisjointSet;
+    use rand::Rng;
+
+    #[test]
+    fn dynamic_connectivity() {
+        let mut rng = rand::thread_rng();
+        const SIZE: usize = 10;
+
+        let mut edges: Vec<(usize, usize)> = Vec::new();
+        let mut operations = Vec::new();
+        let mut expected = Vec::new();
+
+        for _ in 0..300 {
+            let (u, v) = (rng.gen_range(0, SIZE), rng.gen_range(0, SIZE));
+            match rng.gen_range(0, 3) {
+                0 => {
+                    edges.push((u, v));
+                    operations.push(ConnectivityOperation::Insert(u, v));
+                }
+                1 if !edges.is_empty() => {
+                    let (a, b) = edges.swap_remove(rng.gen_range(0, edges.len()));
+                    operations.push(ConnectivityOperation::Remove(b, a));
+                }
+                _ => {
+                    let mut ds = DisjointSet::new(SIZE, true);
+                    for &(a, b) in &edges {
+                        ds.unite(a, b);
+                    }
+                    expected.push(ds.in_same(u, v));
+                    operations.push(ConnectivityOperation::Connected(u, v));
+                }
+            }
+        }
+
+        assert_eq!(expected, offline_dynamic_connectivity(SIZE, &operations));
+        assert!(offline_dynamic_connectivity(SIZE, &[]).is_empty());
+    }
+}
@@ -6,6 +6,12 @@ pub use directed_graph::DirectedGraph;
 mod mst;
 pub use mst::*;
 
+mod dynamic_connectivity;
+pub use dynamic_connectivity::*;
+
+mod transitive_closure;
+pub use transitive_closure::*;
+
 pub trait Vertex {}
 
 impl<T> Vertex for T {}
@@ -36,6 +42,7 @@ pub trait Graph<'a> {
     fn out_edges(&'a self, vertex: &Self::TVertex) -> Box<GenericRefIter<'a, Self::TEdge>>;
 
     /// Inserts an edge into the graph.
+    #[allow(clippy::result_unit_err)]
     fn insert_edge(&mut self, edge: Self::TEdge) -> Result<(), ()>;
 
     /// Insert vertex
@@ -1,8 +1,7 @@
 use super::{IdEdge, IdGraph, IdVertex, LengthEdge};
-use crate::ds::DisjointSet;
-use std::collections::HashMap;
+use crate::ds::KeyedDisjointSet;
 
-pub fn minimum_spanning_tree<'a, V, E>(graph: &'a IdGraph<'a, V, E>) -> Option<isize>
+pub fn minimum_spanning_tree<'a, V, E>(graph: &'a dyn IdGraph<'a, V, E>) -> Option<isize>
 where
     V: IdVertex,
     E: IdEdge + LengthEdge,
@@ -11,22 +10,23 @@ where
         return Some(0);
     }
 
-    let mut map = HashMap::new();
-    graph.vertices().enumerate().for_each(|(k, v)| {
-        map.insert(v.id(), k);
+    let mut ds = KeyedDisjointSet::new();
+    graph.vertices().for_each(|v| {
+        ds.insert(v.id());
     });
 
     let mut sorted: Vec<_> = graph.edges().collect();
-    sorted.sort_by(|&x, &y| x.length().cmp(&y.length()));
-    let mut ds = DisjointSet::new(map.len(), true);
+    sorted.sort_by_key(|x| x.length());
     let mut res = 0;
 
     for edge in sorted {
-        let (from, to) = (map[&edge.from()], map[&edge.to()]);
-        if ds.in_same(from, to) {
+        assert!(
+            ds.contains(&edge.from()) && ds.contains(&edge.to()),
+            "The edge has an endpoint out of the graph."
+        );
+        if ds.unite(edge.from(), edge.to()).is_none() {
             continue;
         }
-        ds.unite(from, to);
         res += edge.length();
         if ds.is_one() {
             break;
@@ -0,0 +1,90 @@
+use crate::ds::BitSet;
+
+/// Computes the reachability of a directed graph with `n` vertices in O(n^3 / 64) by
+/// Floyd-Warshall on bit sets.
+///
+/// The `u`-th row has the bit `v` set if `v` is reachable from `u`, every vertex reaches itself.
+///
+/// # Examples
+///
+/// ```
+/// use rsalgo::graph::transitive_closure;
+///
+/// let reach = transitive_closure(3, &[(0, 1), (1, 2)]);
+/// assert!(reach[0].get(2));
+/// assert!(!reach[2].get(0));
+/// ```
+pub fn transitive_closure(n: usize, edges: &[(usize, usize)]) -> Vec<BitSet> {
+    let mut reach = vec![BitSet::new(n); n];
+    for (u, row) in reach.iter_mut().enumerate() {
+        row.set(u, true);
+    }
+    for &(u, v) in edges {
+        reach[u].set(v, true);
+    }
+    for k in 0..n {
+        let through = reach[k].clone();
+        for row in reach.iter_mut() {
+            if row.get(k) {
+                *row |= &through;
+            }
+        }
+    }
+    reach
+}
+
+/// Gets the vertices reachable from `source` in a directed graph with `n` vertices, including
+/// `source` itself, by a depth-first search in O(n + m).
+///
+/// # Examples
+///
+/// ```
+/// use rsalgo::graph::reachable;
+///
+/// let reach = reachable(3, &[(0, 1), (2, 0)], 0);
+/// assert_eq!(vec![0, 1], reach.ones().collect::<Vec<_>>());
+/// ```
+pub fn reachable(n: usize, edges: &[(usize, usize)], source: usize) -> BitSet {
+    let mut adj = vec![Vec::new(); n];
+    for &(u, v) in edges {
+        adj[u].push(v);
+    }
+    let mut visited = BitSet::new(n);
+    visited.set(source, true);
+    let mut queue = vec![source];
+    while let Some(u) = queue.pop() {
+        for &v in &adj[u] {
+            if !visited.get(v) {
+                visited.set(v, true);
+                queue.push(v);
+            }
+        }
+    }
+    visited
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{reachable, transitive_closure};
+    use rand::Rng;
+
+    #[test]
+    fn closure() {
+        let mut rng = rand::thread_rng();
+        for &n in &[0, 1, 10, 70, 130] {
+            let m = rng.gen_range(0, 2 * n + 1);
+            let edges: Vec<(usize, usize)> = (0..m)
+                .map(|_| (rng.gen_range(0, n), rng.gen_range(0, n)))
+                .collect();
+            let reach = transitive_closure(n, &edges);
+            for (u, row) in reach.iter().enumerate() {
+                assert_eq!(&reachable(n, &edges, u), row);
+                for &(a, b) in &edges {
+                    if row.get(a) {
+                        assert!(row.get(b));
+                    }
+                }
+            }
+        }
+    }
+}
@@ -4,3 +4,4 @@ pub mod base;
 pub mod ds;
 pub mod graph;
 pub mod math;
+pub mod string;
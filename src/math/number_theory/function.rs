@@ -68,10 +68,7 @@ pub fn log(base: Uint, value: Uint, modulus: Uint) -> Option<Uint> {
         e = e * base % modulus;
         map.entry(e).or_insert(i);
     }
-    let v = match inverse(super::quick_pow(base, m, modulus), modulus) {
-        Some(x) => x,
-        None => return None,
-    };
+    let v = inverse(super::quick_pow(base, m, modulus), modulus)?;
     let mut vt = value;
     for i in 0..m {
         match map.entry(vt) {
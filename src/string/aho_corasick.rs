@@ -0,0 +1,255 @@
+use crate::ds::Trie;
+use std::borrow::Borrow;
+use std::collections::VecDeque;
+
+/// An occurrence of a pattern.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Match {
+    /// The index of the pattern.
+    pub pattern: usize,
+    /// The start position of the occurrence.
+    pub start: usize,
+    /// The end position (exclusive) of the occurrence.
+    pub end: usize,
+}
+
+/// Aho-Corasick automaton for matching multiple patterns at once, built on a Trie.
+pub struct AhoCorasick<K: Ord + Clone> {
+    trie: Trie<K>,
+    fail: Vec<usize>,
+    /// The nearest node on the failure chain which is the end of some patterns.
+    output: Vec<Option<usize>>,
+    /// The patterns ending exactly at each node.
+    patterns: Vec<Vec<usize>>,
+    lengths: Vec<usize>,
+}
+
+impl<K: Ord + Clone> AhoCorasick<K> {
+    /// Builds the automaton of `patterns`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rsalgo::string::AhoCorasick;
+    ///
+    /// let ac = AhoCorasick::new(&["he".as_bytes(), "she".as_bytes()]);
+    /// assert_eq!(2, ac.len());
+    /// ```
+    pub fn new<P: AsRef<[K]>>(patterns: &[P]) -> Self {
+        let mut trie = Trie::new();
+        for p in patterns {
+            trie.insert(p.as_ref());
+        }
+
+        let n = trie.node_capacity();
+        let mut at = vec![Vec::new(); n];
+        for (i, p) in patterns.iter().enumerate() {
+            at[trie.node(p.as_ref()).unwrap()].push(i);
+        }
+
+        let mut fail = vec![0; n];
+        let mut output = vec![None; n];
+        let mut queue = VecDeque::new();
+        queue.push_back(0);
+        while let Some(v) = queue.pop_front() {
+            for (symbol, u) in trie.children(v) {
+                if v != 0 {
+                    let mut f = fail[v];
+                    fail[u] = loop {
+                        if let Some(w) = trie.child(f, symbol) {
+                            break w;
+                        }
+                        if f == 0 {
+                            break 0;
+                        }
+                        f = fail[f];
+                    };
+                }
+                let f = fail[u];
+                output[u] = if at[f].is_empty() { output[f] } else { Some(f) };
+                queue.push_back(u);
+            }
+        }
+
+        AhoCorasick {
+            trie,
+            fail,
+            output,
+            patterns: at,
+            lengths: patterns.iter().map(|p| p.as_ref().len()).collect(),
+        }
+    }
+
+    /// Returns the number of patterns.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rsalgo::string::AhoCorasick;
+    ///
+    /// let ac = AhoCorasick::new(&["a".as_bytes(), "a".as_bytes()]);
+    /// assert_eq!(2, ac.len());
+    /// ```
+    pub fn len(&self) -> usize {
+        self.lengths.len()
+    }
+
+    /// Returns true if there is no pattern.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rsalgo::string::AhoCorasick;
+    ///
+    /// let ac: AhoCorasick<u8> = AhoCorasick::new::<&[u8]>(&[]);
+    /// assert!(ac.is_empty());
+    /// ```
+    pub fn is_empty(&self) -> bool {
+        self.lengths.is_empty()
+    }
+
+    fn step(&self, state: usize, symbol: &K) -> usize {
+        let mut v = state;
+        loop {
+            if let Some(u) = self.trie.child(v, symbol) {
+                return u;
+            }
+            if v == 0 {
+                return 0;
+            }
+            v = self.fail[v];
+        }
+    }
+
+    /// Gets an iterator over all occurrences in a stream of symbols, ordered by their end positions.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rsalgo::string::{AhoCorasick, Match};
+    ///
+    /// let ac = AhoCorasick::new(&["he".as_bytes(), "she".as_bytes(), "hers".as_bytes()]);
+    /// let found: Vec<Match> = ac.find_iter("ushers".bytes()).collect();
+    /// assert_eq!(
+    ///     vec![
+    ///         Match { pattern: 1, start: 1, end: 4 },
+    ///         Match { pattern: 0, start: 2, end: 4 },
+    ///         Match { pattern: 2, start: 2, end: 6 },
+    ///     ],
+    ///     found
+    /// );
+    /// ```
+    pub fn find_iter<I>(&self, stream: I) -> FindIter<'_, K, I::IntoIter>
+    where
+        I: IntoIterator,
+        I::Item: Borrow<K>,
+    {
+        FindIter {
+            automaton: self,
+            stream: stream.into_iter(),
+            state: 0,
+            position: 0,
+            pending: Some((0, 0)),
+        }
+    }
+
+    /// Gets all occurrences in `text`, ordered by their end positions.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rsalgo::string::AhoCorasick;
+    ///
+    /// let ac = AhoCorasick::new(&["aa".as_bytes()]);
+    /// assert_eq!(2, ac.find_all("aaa".as_bytes()).len());
+    /// ```
+    pub fn find_all(&self, text: &[K]) -> Vec<Match> {
+        self.find_iter(text).collect()
+    }
+}
+
+/// Iterator over the occurrences of patterns in a stream.
+pub struct FindIter<'a, K: Ord + Clone, I> {
+    automaton: &'a AhoCorasick<K>,
+    stream: I,
+    state: usize,
+    position: usize,
+    pending: Option<(usize, usize)>,
+}
+
+impl<'a, K, I> Iterator for FindIter<'a, K, I>
+where
+    K: Ord + Clone,
+    I: Iterator,
+    I::Item: Borrow<K>,
+{
+    type Item = Match;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let ac = self.automaton;
+        loop {
+            if let Some((v, i)) = self.pending {
+                if let Some(&pattern) = ac.patterns[v].get(i) {
+                    self.pending = Some((v, i + 1));
+                    return Some(Match {
+                        pattern,
+                        start: self.position - ac.lengths[pattern],
+                        end: self.position,
+                    });
+                }
+                self.pending = ac.output[v].map(|u| (u, 0));
+                continue;
+            }
+
+            let symbol = self.stream.next()?;
+            self.state = ac.step(self.state, symbol.borrow());
+            self.position += 1;
+            self.pending = Some((self.state, 0));
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{AhoCorasick, Match};
+    use rand::Rng;
+
+    #[test]
+    fn aho_corasick() {
+        let mut rng = rand::thread_rng();
+        for _ in 0..30 {
+            let patterns: Vec<Vec<u8>> = (0..8)
+                .map(|_| {
+                    (0..rng.gen_range(0, 4))
+                        .map(|_| rng.gen_range(0, 3))
+                        .collect()
+                })
+                .collect();
+            let text: Vec<u8> = (0..100).map(|_| rng.gen_range(0, 3)).collect();
+            let ac = AhoCorasick::new(&patterns);
+
+            let mut found = ac.find_all(&text);
+            for w in found.windows(2) {
+                assert!(w[0].end <= w[1].end);
+            }
+
+            let mut expected = Vec::new();
+            for (pattern, p) in patterns.iter().enumerate() {
+                for start in 0..=text.len() - p.len() {
+                    if text[start..start + p.len()] == p[..] {
+                        expected.push(Match {
+                            pattern,
+                            start,
+                            end: start + p.len(),
+                        });
+                    }
+                }
+            }
+
+            let key = |m: &Match| (m.end, m.pattern);
+            found.sort_by_key(key);
+            expected.sort_by_key(key);
+            assert_eq!(expected, found);
+        }
+    }
+}
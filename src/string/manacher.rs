@@ -0,0 +1,109 @@
+use std::ops::Range;
+
+/// Get the palindrome radii by Manacher's algorithm.
+///
+/// Return `(odd, even)`, where `odd[i]` is the number of odd palindromes centered at `i`,
+/// and `even[i]` is the number of even palindromes centered between `i - 1` and `i`.
+///
+/// # Examples
+///
+/// ```
+/// let (odd, even) = rsalgo::string::manacher("abaab".as_bytes());
+///
+/// assert_eq!(vec![1, 2, 1, 1, 1], odd);
+/// assert_eq!(vec![0, 0, 0, 2, 0], even);
+/// ```
+pub fn manacher<T: Eq>(s: &[T]) -> (Vec<usize>, Vec<usize>) {
+    let n = s.len();
+
+    let mut odd = vec![0; n];
+    let (mut l, mut r) = (0, 0);
+    for i in 0..n {
+        let mut k = if i < r {
+            odd[l + r - 1 - i].min(r - i)
+        } else {
+            1
+        };
+        while i + k < n && i >= k && s[i - k] == s[i + k] {
+            k += 1;
+        }
+        odd[i] = k;
+        if i + k > r {
+            l = i + 1 - k;
+            r = i + k;
+        }
+    }
+
+    let mut even = vec![0; n];
+    let (mut l, mut r) = (0, 0);
+    for i in 0..n {
+        let mut k = if i < r { even[l + r - i].min(r - i) } else { 0 };
+        while i + k < n && i > k && s[i - k - 1] == s[i + k] {
+            k += 1;
+        }
+        even[i] = k;
+        if i + k > r {
+            l = i - k;
+            r = i + k;
+        }
+    }
+
+    (odd, even)
+}
+
+/// Get the range of the first longest palindrome substring.
+///
+/// # Examples
+///
+/// ```
+/// assert_eq!(1..5, rsalgo::string::longest_palindrome("cabbad".as_bytes()));
+/// ```
+pub fn longest_palindrome<T: Eq>(s: &[T]) -> Range<usize> {
+    let (odd, even) = manacher(s);
+    let mut res = 0..0;
+    for i in 0..s.len() {
+        let (ol, el) = (2 * odd[i] - 1, 2 * even[i]);
+        if el > res.len() {
+            res = i - even[i]..i + even[i];
+        }
+        if ol > res.len() {
+            res = i + 1 - odd[i]..i + odd[i];
+        }
+    }
+    res
+}
+
+#[cfg(test)]
+mod tests {
+    use rand::Rng;
+
+    fn is_palindrome(s: &[u8]) -> bool {
+        s.iter().eq(s.iter().rev())
+    }
+
+    #[test]
+    fn manacher() {
+        let mut rng = rand::thread_rng();
+        for _ in 0..50 {
+            let s: Vec<u8> = (0..50).map(|_| rng.gen_range(0, 2)).collect();
+            let (odd, even) = super::manacher(&s);
+            for i in 0..s.len() {
+                let exp_odd = (1..=i + 1)
+                    .take_while(|&k| i + k <= s.len() && is_palindrome(&s[i + 1 - k..i + k]))
+                    .count();
+                let exp_even = (1..=i)
+                    .take_while(|&k| i + k <= s.len() && is_palindrome(&s[i - k..i + k]))
+                    .count();
+                assert_eq!(exp_odd, odd[i]);
+                assert_eq!(exp_even, even[i]);
+            }
+
+            let best = super::longest_palindrome(&s);
+            assert!(is_palindrome(&s[best.clone()]));
+            for len in best.len() + 1..=s.len() {
+                assert!(s.windows(len).all(|w| !is_palindrome(w)));
+            }
+        }
+        assert_eq!(0..0, super::longest_palindrome::<u8>(&[]));
+    }
+}
@@ -0,0 +1,24 @@
+//! String algorithms
+//!
+//! All algorithms work over slices of symbols, use `str::as_bytes` for strings.
+
+mod prefix_function;
+pub use prefix_function::*;
+
+mod z_function;
+pub use z_function::*;
+
+mod manacher;
+pub use manacher::*;
+
+mod rolling_hash;
+pub use rolling_hash::RollingHash;
+
+mod suffix_array;
+pub use suffix_array::{LcpQuery, SuffixArray};
+
+mod suffix_automaton;
+pub use suffix_automaton::SuffixAutomaton;
+
+mod aho_corasick;
+pub use aho_corasick::{AhoCorasick, FindIter, Match};
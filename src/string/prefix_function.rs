@@ -0,0 +1,180 @@
+/// Get the prefix function, the length of the longest proper border of each prefix.
+///
+/// # Examples
+///
+/// ```
+/// let pi = rsalgo::string::prefix_function("abcabcd".as_bytes());
+///
+/// assert_eq!(vec![0, 0, 0, 1, 2, 3, 0], pi);
+/// ```
+pub fn prefix_function<T: Eq>(s: &[T]) -> Vec<usize> {
+    let mut pi = vec![0; s.len()];
+    for i in 1..s.len() {
+        let mut k = pi[i - 1];
+        while k > 0 && s[i] != s[k] {
+            k = pi[k - 1];
+        }
+        if s[i] == s[k] {
+            k += 1;
+        }
+        pi[i] = k;
+    }
+    pi
+}
+
+/// Knuth-Morris-Pratt matcher for a pattern.
+pub struct KmpMatcher<'a, T: Eq> {
+    pattern: &'a [T],
+    pi: Vec<usize>,
+}
+
+impl<'a, T: Eq> KmpMatcher<'a, T> {
+    /// Creates a KmpMatcher for `pattern`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rsalgo::string::KmpMatcher;
+    ///
+    /// let m = KmpMatcher::new("aba".as_bytes());
+    /// assert_eq!(3, m.len());
+    /// ```
+    pub fn new(pattern: &'a [T]) -> Self {
+        KmpMatcher {
+            pattern,
+            pi: prefix_function(pattern),
+        }
+    }
+
+    /// Returns the length of the pattern.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rsalgo::string::KmpMatcher;
+    ///
+    /// let m = KmpMatcher::new("ab".as_bytes());
+    /// assert_eq!(2, m.len());
+    /// ```
+    pub fn len(&self) -> usize {
+        self.pattern.len()
+    }
+
+    /// Returns true if the pattern is empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rsalgo::string::KmpMatcher;
+    ///
+    /// let m = KmpMatcher::new("".as_bytes());
+    /// assert!(m.is_empty());
+    /// ```
+    pub fn is_empty(&self) -> bool {
+        self.pattern.is_empty()
+    }
+
+    /// Get the length of the longest prefix of the pattern matched after feeding `symbol`
+    /// to a state which has matched `state` symbols.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rsalgo::string::KmpMatcher;
+    ///
+    /// let m = KmpMatcher::new("aab".as_bytes());
+    /// assert_eq!(2, m.step(2, &b'a'));
+    /// assert_eq!(3, m.step(2, &b'b'));
+    /// ```
+    pub fn step(&self, state: usize, symbol: &T) -> usize {
+        let mut k = state;
+        if k == self.pattern.len() {
+            k = match k {
+                0 => return 0,
+                _ => self.pi[k - 1],
+            };
+        }
+        while k > 0 && &self.pattern[k] != symbol {
+            k = self.pi[k - 1];
+        }
+        if k < self.pattern.len() && &self.pattern[k] == symbol {
+            k += 1;
+        }
+        k
+    }
+
+    /// Get the start positions of all occurrences of the pattern in `text`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rsalgo::string::KmpMatcher;
+    ///
+    /// let m = KmpMatcher::new("aba".as_bytes());
+    /// assert_eq!(vec![0, 2, 6], m.find_all("ababacaba".as_bytes()));
+    /// ```
+    pub fn find_all(&self, text: &[T]) -> Vec<usize> {
+        if self.pattern.is_empty() {
+            return (0..=text.len()).collect();
+        }
+        let mut res = Vec::new();
+        let mut state = 0;
+        for (i, symbol) in text.iter().enumerate() {
+            state = self.step(state, symbol);
+            if state == self.pattern.len() {
+                res.push(i + 1 - state);
+            }
+        }
+        res
+    }
+}
+
+/// Get the start positions of all occurrences of `pattern` in `text` by KMP.
+///
+/// # Examples
+///
+/// ```
+/// let pos = rsalgo::string::kmp_search("aaaa".as_bytes(), "aa".as_bytes());
+///
+/// assert_eq!(vec![0, 1, 2], pos);
+/// ```
+pub fn kmp_search<T: Eq>(text: &[T], pattern: &[T]) -> Vec<usize> {
+    KmpMatcher::new(pattern).find_all(text)
+}
+
+#[cfg(test)]
+mod tests {
+    use rand::Rng;
+
+    fn random(len: usize, alphabet: u8) -> Vec<u8> {
+        let mut rng = rand::thread_rng();
+        (0..len).map(|_| rng.gen_range(0, alphabet)).collect()
+    }
+
+    #[test]
+    fn prefix_function() {
+        for _ in 0..50 {
+            let s = random(40, 2);
+            let pi = super::prefix_function(&s);
+            for i in 0..s.len() {
+                let exp = (0..=i).rev().find(|&k| s[..k] == s[i + 1 - k..=i]).unwrap();
+                assert_eq!(exp, pi[i]);
+            }
+        }
+    }
+
+    #[test]
+    fn kmp_search() {
+        for _ in 0..50 {
+            let text = random(200, 3);
+            let pattern = random(rand::thread_rng().gen_range(0, 5), 3);
+            let exp: Vec<usize> = (0..=text.len() - pattern.len())
+                .filter(|&i| text[i..i + pattern.len()] == pattern[..])
+                .collect();
+            assert_eq!(exp, super::kmp_search(&text, &pattern));
+        }
+
+        let words = ["a", "b"];
+        assert_eq!(vec![0, 1], super::kmp_search(&["a", "a", "b"], &words[..1]));
+    }
+}
@@ -0,0 +1,187 @@
+use crate::math::number_theory::{inverse, quick_pow, Uint};
+use std::collections::hash_map::DefaultHasher;
+use std::hash::{Hash, Hasher};
+use std::ops::Range;
+
+const MODULUS: [Uint; 2] = [1_000_000_007, 998_244_353];
+const BASE: [Uint; 2] = [911_382_323, 972_663_749];
+
+/// Polynomial rolling hash with double modulus, supporting hash of any substring in O(1).
+///
+/// Symbols are mapped into numbers by `DefaultHasher`, so hashes are comparable between
+/// RollingHash instances in the same program.
+pub struct RollingHash {
+    prefix: [Vec<Uint>; 2],
+    inverse_power: [Vec<Uint>; 2],
+}
+
+impl RollingHash {
+    /// Creates a RollingHash of `s`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rsalgo::string::RollingHash;
+    ///
+    /// let h = RollingHash::new("abcab".as_bytes());
+    /// assert_eq!(h.hash(0..2), h.hash(3..5));
+    /// ```
+    pub fn new<T: Hash>(s: &[T]) -> Self {
+        let mut prefix = [
+            Vec::with_capacity(s.len() + 1),
+            Vec::with_capacity(s.len() + 1),
+        ];
+        let mut inverse_power = [
+            Vec::with_capacity(s.len() + 1),
+            Vec::with_capacity(s.len() + 1),
+        ];
+
+        for k in 0..2 {
+            let (modulus, base) = (MODULUS[k], BASE[k]);
+            let base_inverse = inverse(base, modulus).unwrap();
+            let mut power = 1;
+            prefix[k].push(0);
+            inverse_power[k].push(1);
+            for (i, item) in s.iter().enumerate() {
+                let symbol = Self::symbol(item, modulus);
+                prefix[k].push((prefix[k][i] + symbol * power) % modulus);
+                inverse_power[k].push(inverse_power[k][i] * base_inverse % modulus);
+                power = power * base % modulus;
+            }
+        }
+
+        RollingHash {
+            prefix,
+            inverse_power,
+        }
+    }
+
+    fn symbol<T: Hash>(item: &T, modulus: Uint) -> Uint {
+        let mut hasher = DefaultHasher::new();
+        item.hash(&mut hasher);
+        (hasher.finish() % (modulus as u64 - 1)) as Uint + 1
+    }
+
+    /// Returns the length of the string.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rsalgo::string::RollingHash;
+    ///
+    /// let h = RollingHash::new("abc".as_bytes());
+    /// assert_eq!(3, h.len());
+    /// ```
+    pub fn len(&self) -> usize {
+        self.prefix[0].len() - 1
+    }
+
+    /// Returns true if the string is empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rsalgo::string::RollingHash;
+    ///
+    /// let h = RollingHash::new("".as_bytes());
+    /// assert!(h.is_empty());
+    /// ```
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Gets the hash of the substring in `range`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rsalgo::string::RollingHash;
+    ///
+    /// let a = RollingHash::new("xabcx".as_bytes());
+    /// let b = RollingHash::new("abc".as_bytes());
+    /// assert_eq!(a.hash(1..4), b.hash(0..3));
+    /// assert_ne!(a.hash(0..3), b.hash(0..3));
+    /// ```
+    pub fn hash(&self, range: Range<usize>) -> (Uint, Uint) {
+        assert!(range.start <= range.end, "The range is invalid.");
+        let mut res = [0; 2];
+        for (k, value) in res.iter_mut().enumerate() {
+            let diff = self.prefix[k][range.end] + MODULUS[k] - self.prefix[k][range.start];
+            *value = diff % MODULUS[k] * self.inverse_power[k][range.start] % MODULUS[k];
+        }
+        (res[0], res[1])
+    }
+
+    /// Gets the hash of the concatenation of two strings by their hashes, `len` is the length of the first one.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rsalgo::string::RollingHash;
+    ///
+    /// let h = RollingHash::new("abcde".as_bytes());
+    /// assert_eq!(h.hash(0..5), RollingHash::concat(h.hash(0..2), h.hash(2..5), 2));
+    /// ```
+    pub fn concat(first: (Uint, Uint), second: (Uint, Uint), len: usize) -> (Uint, Uint) {
+        let shift =
+            |value: Uint, k: usize| value * quick_pow(BASE[k], len, MODULUS[k]) % MODULUS[k];
+        (
+            (first.0 + shift(second.0, 0)) % MODULUS[0],
+            (first.1 + shift(second.1, 1)) % MODULUS[1],
+        )
+    }
+
+    /// Gets the length of the longest common prefix of the suffixes starting at `a` and `b`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rsalgo::string::RollingHash;
+    ///
+    /// let h = RollingHash::new("abcabd".as_bytes());
+    /// assert_eq!(2, h.lcp(0, 3));
+    /// ```
+    pub fn lcp(&self, a: usize, b: usize) -> usize {
+        let limit = self.len() - a.max(b);
+        crate::base::dichotomy_last(0, limit + 1, |len| {
+            self.hash(a..a + len) == self.hash(b..b + len)
+        })
+        .unwrap()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::RollingHash;
+    use rand::Rng;
+
+    #[test]
+    fn rolling_hash() {
+        let mut rng = rand::thread_rng();
+        let s: Vec<u8> = (0..80).map(|_| rng.gen_range(0, 2)).collect();
+        let h = RollingHash::new(&s);
+
+        for _ in 0..2000 {
+            let len = rng.gen_range(0, 6);
+            let a = rng.gen_range(0, s.len() - len + 1);
+            let b = rng.gen_range(0, s.len() - len + 1);
+            assert_eq!(
+                s[a..a + len] == s[b..b + len],
+                h.hash(a..a + len) == h.hash(b..b + len)
+            );
+
+            let lcp = (0..s.len() - a.max(b))
+                .take_while(|&k| s[a + k] == s[b + k])
+                .count();
+            assert_eq!(lcp, h.lcp(a, b));
+        }
+
+        let words = ["x", "y", "x", "y"];
+        let w = RollingHash::new(&words);
+        assert_eq!(w.hash(0..2), w.hash(2..4));
+        assert_eq!(
+            w.hash(0..4),
+            RollingHash::concat(w.hash(0..2), w.hash(2..4), 2)
+        );
+    }
+}
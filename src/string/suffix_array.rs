@@ -0,0 +1,232 @@
+use crate::base::{discretization, sort::counting_sort_by_key};
+use crate::ds::SparseTable;
+
+/// Suffix array with its inverse and the LCP array.
+pub struct SuffixArray {
+    sa: Vec<usize>,
+    rank: Vec<usize>,
+    lcp: Vec<usize>,
+}
+
+impl SuffixArray {
+    /// Builds the suffix array of `s` by prefix doubling with radix sort steps in O(n log n).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rsalgo::string::SuffixArray;
+    ///
+    /// let sa = SuffixArray::new("banana".as_bytes());
+    /// assert_eq!([5, 3, 1, 0, 4, 2], sa.suffix_array());
+    /// ```
+    pub fn new<T: Ord + Clone>(s: &[T]) -> Self {
+        let n = s.len();
+        let mut rank = discretization(s);
+        let mut sa: Vec<usize> = (0..n).collect();
+        let mut temp = vec![0; n];
+        let mut k = 1;
+
+        loop {
+            let second = |i: usize| if i + k < n { rank[i + k] + 1 } else { 0 };
+            counting_sort_by_key(&mut sa, |&i| second(i));
+            counting_sort_by_key(&mut sa, |&i| rank[i]);
+
+            if let Some(&first) = sa.first() {
+                temp[first] = 0;
+            }
+            for w in 1..n {
+                let (a, b) = (sa[w - 1], sa[w]);
+                let same = rank[a] == rank[b] && second(a) == second(b);
+                temp[b] = temp[a] + if same { 0 } else { 1 };
+            }
+            std::mem::swap(&mut rank, &mut temp);
+
+            if n == 0 || rank[sa[n - 1]] == n - 1 {
+                break;
+            }
+            k *= 2;
+        }
+
+        let lcp = Self::kasai(s, &sa, &rank);
+        SuffixArray { sa, rank, lcp }
+    }
+
+    /// Kasai's algorithm, `lcp[i]` is the LCP of the suffixes `sa[i - 1]` and `sa[i]`.
+    fn kasai<T: Eq>(s: &[T], sa: &[usize], rank: &[usize]) -> Vec<usize> {
+        let n = s.len();
+        let mut lcp = vec![0; n];
+        let mut h: usize = 0;
+        for i in 0..n {
+            if rank[i] == 0 {
+                h = 0;
+                continue;
+            }
+            let j = sa[rank[i] - 1];
+            while i + h < n && j + h < n && s[i + h] == s[j + h] {
+                h += 1;
+            }
+            lcp[rank[i]] = h;
+            h = h.saturating_sub(1);
+        }
+        lcp
+    }
+
+    /// Returns the length of the string.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rsalgo::string::SuffixArray;
+    ///
+    /// let sa = SuffixArray::new("abc".as_bytes());
+    /// assert_eq!(3, sa.len());
+    /// ```
+    pub fn len(&self) -> usize {
+        self.sa.len()
+    }
+
+    /// Returns true if the string is empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rsalgo::string::SuffixArray;
+    ///
+    /// let sa = SuffixArray::new("".as_bytes());
+    /// assert!(sa.is_empty());
+    /// ```
+    pub fn is_empty(&self) -> bool {
+        self.sa.is_empty()
+    }
+
+    /// Gets the start positions of the suffixes in ascending order.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rsalgo::string::SuffixArray;
+    ///
+    /// let sa = SuffixArray::new("aab".as_bytes());
+    /// assert_eq!([0, 1, 2], sa.suffix_array());
+    /// ```
+    pub fn suffix_array(&self) -> &[usize] {
+        &self.sa
+    }
+
+    /// Gets the rank of the suffix starting at each position.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rsalgo::string::SuffixArray;
+    ///
+    /// let sa = SuffixArray::new("ba".as_bytes());
+    /// assert_eq!([1, 0], sa.rank());
+    /// ```
+    pub fn rank(&self) -> &[usize] {
+        &self.rank
+    }
+
+    /// Gets the LCP array, the `i`-th value is the length of the longest common prefix of the
+    /// `(i - 1)`-th and the `i`-th suffixes in ascending order, and the first value is `0`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rsalgo::string::SuffixArray;
+    ///
+    /// let sa = SuffixArray::new("banana".as_bytes());
+    /// assert_eq!([0, 1, 3, 0, 0, 2], sa.lcp_array());
+    /// ```
+    pub fn lcp_array(&self) -> &[usize] {
+        &self.lcp
+    }
+
+    /// Builds a LcpQuery for the longest common prefix of any two suffixes.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rsalgo::string::SuffixArray;
+    ///
+    /// let sa = SuffixArray::new("banana".as_bytes());
+    /// let q = sa.lcp_query();
+    /// assert_eq!(3, q.lcp(1, 3));
+    /// ```
+    pub fn lcp_query(&self) -> LcpQuery<'_> {
+        LcpQuery {
+            rank: &self.rank,
+            table: SparseTable::new(&self.lcp),
+        }
+    }
+}
+
+/// Longest common prefix queries of suffixes in O(1), backed by a sparse table over the LCP array.
+pub struct LcpQuery<'a> {
+    rank: &'a [usize],
+    table: SparseTable<usize>,
+}
+
+impl<'a> LcpQuery<'a> {
+    /// Gets the length of the longest common prefix of the suffixes starting at `a` and `b`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rsalgo::string::SuffixArray;
+    ///
+    /// let sa = SuffixArray::new("abcabd".as_bytes());
+    /// assert_eq!(2, sa.lcp_query().lcp(0, 3));
+    /// ```
+    pub fn lcp(&self, a: usize, b: usize) -> usize {
+        if a == b {
+            return self.rank.len() - a;
+        }
+        let (ra, rb) = (self.rank[a], self.rank[b]);
+        self.table.query(ra.min(rb) + 1..ra.max(rb) + 1)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::SuffixArray;
+    use rand::Rng;
+
+    #[test]
+    fn suffix_array() {
+        let mut rng = rand::thread_rng();
+        for &len in &[0, 1, 2, 7, 100] {
+            let s: Vec<u8> = (0..len).map(|_| rng.gen_range(0, 3)).collect();
+            let sa = SuffixArray::new(&s);
+
+            let mut expected: Vec<usize> = (0..len).collect();
+            expected.sort_by(|&a, &b| s[a..].cmp(&s[b..]));
+            assert_eq!(&expected[..], sa.suffix_array());
+
+            for (r, &i) in expected.iter().enumerate() {
+                assert_eq!(r, sa.rank()[i]);
+            }
+
+            let common = |a: usize, b: usize| {
+                s[a..]
+                    .iter()
+                    .zip(&s[b..])
+                    .take_while(|(x, y)| x == y)
+                    .count()
+            };
+            for r in 1..len {
+                assert_eq!(common(expected[r - 1], expected[r]), sa.lcp_array()[r]);
+            }
+
+            let q = sa.lcp_query();
+            for _ in 0..200 {
+                if len == 0 {
+                    break;
+                }
+                let a = rng.gen_range(0, len);
+                let b = rng.gen_range(0, len);
+                assert_eq!(common(a, b), q.lcp(a, b));
+            }
+        }
+    }
+}
@@ -0,0 +1,243 @@
+use std::collections::BTreeMap;
+use std::ops::Range;
+
+struct State<T> {
+    len: usize,
+    link: Option<usize>,
+    next: BTreeMap<T, usize>,
+}
+
+/// Suffix automaton, the minimal automaton accepting all substrings of a string.
+pub struct SuffixAutomaton<T: Ord + Clone> {
+    states: Vec<State<T>>,
+    last: usize,
+}
+
+impl<T: Ord + Clone> SuffixAutomaton<T> {
+    /// Builds the suffix automaton of `s` in O(n log |alphabet|).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rsalgo::string::SuffixAutomaton;
+    ///
+    /// let sam = SuffixAutomaton::new("abcbc".as_bytes());
+    /// assert!(sam.contains("cbc".as_bytes()));
+    /// assert!(!sam.contains("cc".as_bytes()));
+    /// ```
+    pub fn new(s: &[T]) -> Self {
+        let mut sam = SuffixAutomaton {
+            states: vec![State {
+                len: 0,
+                link: None,
+                next: BTreeMap::new(),
+            }],
+            last: 0,
+        };
+        for symbol in s {
+            sam.push(symbol.clone());
+        }
+        sam
+    }
+
+    /// Appends `symbol` to the end of the string.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rsalgo::string::SuffixAutomaton;
+    ///
+    /// let mut sam = SuffixAutomaton::new("ab".as_bytes());
+    /// sam.push(b'c');
+    /// assert!(sam.contains("bc".as_bytes()));
+    /// ```
+    pub fn push(&mut self, symbol: T) {
+        let cur = self.states.len();
+        self.states.push(State {
+            len: self.states[self.last].len + 1,
+            link: None,
+            next: BTreeMap::new(),
+        });
+
+        let mut p = Some(self.last);
+        while let Some(v) = p {
+            if self.states[v].next.contains_key(&symbol) {
+                break;
+            }
+            self.states[v].next.insert(symbol.clone(), cur);
+            p = self.states[v].link;
+        }
+
+        self.states[cur].link = Some(match p {
+            None => 0,
+            Some(v) => {
+                let q = self.states[v].next[&symbol];
+                if self.states[v].len + 1 == self.states[q].len {
+                    q
+                } else {
+                    let clone = self.states.len();
+                    self.states.push(State {
+                        len: self.states[v].len + 1,
+                        link: self.states[q].link,
+                        next: self.states[q].next.clone(),
+                    });
+                    let mut p = Some(v);
+                    while let Some(u) = p {
+                        match self.states[u].next.get_mut(&symbol) {
+                            Some(target) if *target == q => *target = clone,
+                            _ => break,
+                        }
+                        p = self.states[u].link;
+                    }
+                    self.states[q].link = Some(clone);
+                    clone
+                }
+            }
+        });
+        self.last = cur;
+    }
+
+    /// Returns the number of states.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rsalgo::string::SuffixAutomaton;
+    ///
+    /// let sam = SuffixAutomaton::new("aa".as_bytes());
+    /// assert_eq!(3, sam.len());
+    /// ```
+    pub fn len(&self) -> usize {
+        self.states.len()
+    }
+
+    /// Returns true if there is only the initial state, which means the string is empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rsalgo::string::SuffixAutomaton;
+    ///
+    /// let sam: SuffixAutomaton<u8> = SuffixAutomaton::new(&[]);
+    /// assert!(sam.is_empty());
+    /// ```
+    pub fn is_empty(&self) -> bool {
+        self.states.len() == 1
+    }
+
+    /// Returns true if `pattern` is a substring.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rsalgo::string::SuffixAutomaton;
+    ///
+    /// let sam = SuffixAutomaton::new("abc".as_bytes());
+    /// assert!(sam.contains("".as_bytes()));
+    /// assert!(!sam.contains("ac".as_bytes()));
+    /// ```
+    pub fn contains(&self, pattern: &[T]) -> bool {
+        let mut v = 0;
+        for symbol in pattern {
+            v = match self.states[v].next.get(symbol) {
+                Some(&u) => u,
+                None => return false,
+            };
+        }
+        true
+    }
+
+    /// Gets the number of distinct non-empty substrings.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rsalgo::string::SuffixAutomaton;
+    ///
+    /// let sam = SuffixAutomaton::new("aba".as_bytes());
+    /// assert_eq!(5, sam.distinct_substrings());
+    /// ```
+    pub fn distinct_substrings(&self) -> usize {
+        self.states
+            .iter()
+            .skip(1)
+            .map(|s| s.len - self.states[s.link.unwrap()].len)
+            .sum()
+    }
+
+    /// Gets the range in `other` of the first longest common substring with `other`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rsalgo::string::SuffixAutomaton;
+    ///
+    /// let sam = SuffixAutomaton::new("xabcdy".as_bytes());
+    /// assert_eq!(2..5, sam.longest_common_substring("zzabcz".as_bytes()));
+    /// ```
+    pub fn longest_common_substring(&self, other: &[T]) -> Range<usize> {
+        let (mut v, mut len) = (0, 0);
+        let mut res = 0..0;
+        for (i, symbol) in other.iter().enumerate() {
+            loop {
+                if let Some(&u) = self.states[v].next.get(symbol) {
+                    v = u;
+                    len += 1;
+                    break;
+                }
+                match self.states[v].link {
+                    Some(link) => {
+                        v = link;
+                        len = self.states[v].len;
+                    }
+                    None => {
+                        len = 0;
+                        break;
+                    }
+                }
+            }
+            if len > res.len() {
+                res = i + 1 - len..i + 1;
+            }
+        }
+        res
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::SuffixAutomaton;
+    use rand::Rng;
+    use std::collections::HashSet;
+
+    #[test]
+    fn suffix_automaton() {
+        let mut rng = rand::thread_rng();
+        for _ in 0..30 {
+            let s: Vec<u8> = (0..40).map(|_| rng.gen_range(0, 3)).collect();
+            let sam = SuffixAutomaton::new(&s);
+            assert!(sam.len() < 2 * s.len());
+
+            let mut all = HashSet::new();
+            for i in 0..s.len() {
+                for j in i + 1..=s.len() {
+                    all.insert(&s[i..j]);
+                }
+            }
+            assert_eq!(all.len(), sam.distinct_substrings());
+
+            for _ in 0..50 {
+                let p: Vec<u8> = (0..rng.gen_range(0, 6))
+                    .map(|_| rng.gen_range(0, 3))
+                    .collect();
+                assert_eq!(p.is_empty() || all.contains(&p[..]), sam.contains(&p));
+            }
+
+            let other: Vec<u8> = (0..30).map(|_| rng.gen_range(0, 3)).collect();
+            let best = sam.longest_common_substring(&other);
+            assert!(sam.contains(&other[best.clone()]));
+            let longer = best.len() + 1;
+            assert!(longer > other.len() || other.windows(longer).all(|w| !sam.contains(w)));
+        }
+    }
+}
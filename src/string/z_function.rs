@@ -0,0 +1,52 @@
+/// Get the Z-function, the length of the longest common prefix of the string and each suffix.
+///
+/// The first value is the length of the string.
+///
+/// # Examples
+///
+/// ```
+/// let z = rsalgo::string::z_function("aabxaab".as_bytes());
+///
+/// assert_eq!(vec![7, 1, 0, 0, 3, 1, 0], z);
+/// ```
+pub fn z_function<T: Eq>(s: &[T]) -> Vec<usize> {
+    let n = s.len();
+    let mut z = vec![0; n];
+    if n == 0 {
+        return z;
+    }
+    z[0] = n;
+    let (mut l, mut r) = (0, 0);
+    for i in 1..n {
+        if i < r {
+            z[i] = z[i - l].min(r - i);
+        }
+        while i + z[i] < n && s[z[i]] == s[i + z[i]] {
+            z[i] += 1;
+        }
+        if i + z[i] > r {
+            l = i;
+            r = i + z[i];
+        }
+    }
+    z
+}
+
+#[cfg(test)]
+mod tests {
+    use rand::Rng;
+
+    #[test]
+    fn z_function() {
+        let mut rng = rand::thread_rng();
+        for _ in 0..50 {
+            let s: Vec<u8> = (0..60).map(|_| rng.gen_range(0, 2)).collect();
+            let z = super::z_function(&s);
+            for i in 0..s.len() {
+                let exp = (0..s.len() - i).take_while(|&k| s[k] == s[i + k]).count();
+                assert_eq!(exp, z[i]);
+            }
+        }
+        assert!(super::z_function::<u8>(&[]).is_empty());
+    }
+}
@@ -1,5 +1,3 @@
-use rsalgo;
-
 #[test]
 fn base() {
     let mut gen = rsalgo::base::SubsetGenerator::new(1, 1);